@@ -1,11 +1,17 @@
 /// Config structs used by Fakelake during YAML parsing
 ///
 use log::warn;
+use std::collections::HashMap;
 use yaml_rust::{Yaml, YamlLoader};
 
 use crate::errors::FakeLakeError;
 use crate::options::presence;
-use crate::providers::provider::{CorruptedProvider, Provider, ProviderBuilder};
+use crate::providers::address;
+use crate::providers::parameters::locale::{Locale, AVAILABLE_LOCALES};
+use crate::providers::provider::{ColumnType, CorruptedProvider, Provider, ProviderBuilder, Value};
+use crate::providers::random::number::correlated;
+use crate::providers::reference::registry;
+use crate::row;
 
 #[derive(Debug)]
 pub struct Config {
@@ -52,10 +58,128 @@ impl Config {
         self.info
             .as_ref()
             .and_then(|i| i.seed)
-            // Cap random seeds to i64::MAX so the value round-trips through
-            // YAML (yaml-rust stores integers as i64).
-            .unwrap_or_else(|| fastrand::Rng::new().u64(0..=i64::MAX as u64))
+            .unwrap_or_else(random_root_seed)
     }
+
+    /// Groups of columns to generate one after the other. The columns of a group only
+    /// read columns of the previous groups. Without dependencies, there is one group
+    /// with every column in order.
+    pub fn evaluation_levels(&self) -> Result<Vec<Vec<usize>>, FakeLakeError> {
+        get_evaluation_levels(&self.columns)
+    }
+
+    /// Values of the row at index. The columns are generated in the given order, and the
+    /// values read by other columns are kept in the row context.
+    pub fn next_row(
+        &self,
+        index: u32,
+        order: &[usize],
+        read_columns: &[bool],
+    ) -> Vec<Option<Value>> {
+        let mut values: Vec<Option<Value>> = vec![None; self.columns.len()];
+        for &position in order {
+            let column = &self.columns[position];
+            let value = column.next_value(index);
+            if read_columns[position] {
+                row::set(&column.name, value.clone());
+            }
+            values[position] = value;
+        }
+        values
+    }
+
+    /// For each column, whether another column of the row reads it
+    pub fn get_read_columns(&self) -> Vec<bool> {
+        let dependencies: Vec<String> = self
+            .columns
+            .iter()
+            .flat_map(|column| column.provider.dependencies())
+            .collect();
+        self.columns
+            .iter()
+            .map(|column| dependencies.contains(&column.name))
+            .collect()
+    }
+}
+
+fn get_evaluation_levels(columns: &[Column]) -> Result<Vec<Vec<usize>>, FakeLakeError> {
+    let names: Vec<&str> = columns.iter().map(|column| column.name.as_str()).collect();
+    let dependencies: Vec<Vec<String>> = columns
+        .iter()
+        .map(|column| column.provider.dependencies())
+        .collect();
+
+    for (column, column_dependencies) in columns.iter().zip(&dependencies) {
+        for dependency in column_dependencies {
+            if !names.contains(&dependency.as_str()) {
+                return Err(FakeLakeError::BadYAMLFormat(format!(
+                    "Column {} reads the column {} which does not exist.",
+                    column.name, dependency
+                )));
+            }
+        }
+    }
+
+    let mut generated: Vec<&str> = Vec::new();
+    let mut remaining: Vec<usize> = (0..columns.len()).collect();
+    let mut levels = Vec::new();
+    while !remaining.is_empty() {
+        let (level, next): (Vec<usize>, Vec<usize>) = remaining.iter().partition(|&&index| {
+            dependencies[index]
+                .iter()
+                .all(|dependency| generated.contains(&dependency.as_str()))
+        });
+
+        if level.is_empty() {
+            let cycle: Vec<&str> = remaining.iter().map(|&index| names[index]).collect();
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Columns {} read each other in a cycle.",
+                cycle.join(", ")
+            )));
+        }
+
+        generated.extend(level.iter().map(|&index| names[index]));
+        levels.push(level);
+        remaining = next;
+    }
+
+    Ok(levels)
+}
+
+/// Random root seed for a run without seed, printed so that the run can be replayed
+fn random_root_seed() -> u64 {
+    // Cap random seeds to i64::MAX so the value round-trips through
+    // YAML (yaml-rust stores integers as i64).
+    let seed = fastrand::Rng::new().u64(0..=i64::MAX as u64);
+    println!(
+        "No seed specified — using random seed: {} (add 'seed: {}' to your config to reproduce this run)",
+        seed, seed
+    );
+    seed
+}
+
+/// Give to each provider the types of the columns it reads, in evaluation order
+fn bind_columns(columns: &mut [Column]) -> Result<(), FakeLakeError> {
+    let mut types: HashMap<String, ColumnType> = HashMap::new();
+    for level in get_evaluation_levels(columns)? {
+        for index in level {
+            let column = &mut columns[index];
+            if let Err(e) = column.provider.bind(&types) {
+                return Err(FakeLakeError::BadYAMLFormat(format!(
+                    "Column {}: {}",
+                    column.name, e
+                )));
+            }
+            types.insert(
+                column.name.clone(),
+                ColumnType {
+                    sample: column.provider.sample_value(),
+                    nullable: column.can_be_null(),
+                },
+            );
+        }
+    }
+    Ok(())
 }
 
 #[derive(Debug)]
@@ -80,10 +204,26 @@ impl Column {
         self.presence.is_next_present()
     }
     pub fn can_be_null(&self) -> bool {
-        self.presence.can_be_null()
+        self.presence.can_be_null() || self.provider.can_be_null()
+    }
+
+    /// Value of the next row, None if it is null
+    pub fn next_value(&self, index: u32) -> Option<Value> {
+        match self.is_next_present() {
+            true => match self.provider.value(index) {
+                Value::Null => None,
+                value => Some(value),
+            },
+            false => None,
+        }
     }
 
-    pub fn generate_columns(parsed_yaml: &[Yaml]) -> Result<Vec<Column>, FakeLakeError> {
+    /// Columns of the yaml file. The root seed of the table is given to the providers
+    /// whose setup draws random values, so that it is replayed with the seed.
+    pub fn generate_columns(
+        parsed_yaml: &[Yaml],
+        root_seed: u64,
+    ) -> Result<Vec<Column>, FakeLakeError> {
         let mut columns = Vec::new();
 
         let yaml_columns = match parsed_yaml.first() {
@@ -102,8 +242,18 @@ impl Column {
             }
         };
 
+        let default_locale = get_default_locale(&parsed_yaml[0]["info"])?;
+        let yaml_columns: Vec<Yaml> = yaml_columns
+            .iter()
+            .map(|column| with_default_locale(column, &default_locale))
+            .map(|column| with_table_seed(&column, root_seed))
+            .collect();
+
+        let mut linked_providers = correlated::new_from_yaml(&parsed_yaml[0])?;
+        linked_providers.extend(address::group::new_from_yaml(&yaml_columns));
+
         // iter over columns
-        for column in yaml_columns {
+        for column in &yaml_columns {
             let name = match column["name"].as_str() {
                 Some(value) => value,
                 None => {
@@ -123,11 +273,13 @@ impl Column {
 
             let presence = presence::new_from_yaml(column);
 
-            let provider: Box<dyn Provider> =
-                match ProviderBuilder::get_corresponding_provider(provider, column) {
+            let provider: Box<dyn Provider> = match linked_providers.remove(name) {
+                Some(value) => CorruptedProvider::new_from_yaml(column, value),
+                None => match ProviderBuilder::get_corresponding_provider(provider, column) {
                     Ok(value) => CorruptedProvider::new_from_yaml(column, value),
                     Err(e) => return Err(e),
-                };
+                },
+            };
 
             let column = Column {
                 name: name.to_string(),
@@ -141,6 +293,44 @@ impl Column {
     }
 }
 
+/// Locale given in the info section, used by the columns without a locale parameter
+fn get_default_locale(section_info: &Yaml) -> Result<Yaml, FakeLakeError> {
+    match &section_info["locale"] {
+        Yaml::BadValue => Ok(Yaml::BadValue),
+        Yaml::String(code) if Locale::from_code(code).is_some() => Ok(Yaml::String(code.clone())),
+        _ => Err(FakeLakeError::BadYAMLFormat(format!(
+            "info.locale should be one of {}",
+            AVAILABLE_LOCALES.join(", ")
+        ))),
+    }
+}
+
+fn with_default_locale(column: &Yaml, default_locale: &Yaml) -> Yaml {
+    match (column, default_locale) {
+        (Yaml::Hash(value), Yaml::String(_)) if column["locale"].is_badvalue() => {
+            let mut value = value.clone();
+            value.insert(Yaml::String("locale".to_string()), default_locale.clone());
+            Yaml::Hash(value)
+        }
+        _ => column.clone(),
+    }
+}
+
+/// Root seed of the table, read by the providers as the table_seed param
+fn with_table_seed(column: &Yaml, root_seed: u64) -> Yaml {
+    match column {
+        Yaml::Hash(value) => {
+            let mut value = value.clone();
+            value.insert(
+                Yaml::String("table_seed".to_string()),
+                Yaml::Integer(root_seed as i64),
+            );
+            Yaml::Hash(value)
+        }
+        _ => column.clone(),
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub enum OutputType {
     Parquet(),
@@ -272,23 +462,208 @@ impl Info {
     }
 }
 
+/// A table to generate. Its config is only parsed when the table is generated,
+/// once the tables it references have been generated.
+#[derive(Debug, Clone)]
+pub struct Table {
+    pub name: String,
+    pub definition: Yaml,
+    /// Used when the table does not specify info.output_name
+    pub default_output_name: Option<String>,
+}
+
+impl Table {
+    pub fn get_config(&self) -> Result<Config, FakeLakeError> {
+        let mut config = get_config_from_yaml(std::slice::from_ref(&self.definition))?;
+
+        if let Some(info) = config.info.as_mut() {
+            if info.output_name.is_none() {
+                info.output_name.clone_from(&self.default_output_name);
+            }
+        }
+
+        Ok(config)
+    }
+
+    pub fn referenced_columns(&self) -> Vec<(String, String)> {
+        registry::referenced_columns(&self.definition)
+    }
+
+    /// Names of the other tables this table takes values from
+    pub fn referenced_tables(&self) -> Vec<String> {
+        let mut tables: Vec<String> = Vec::new();
+        for (table, _) in self.referenced_columns() {
+            if table != self.name && !tables.contains(&table) {
+                tables.push(table);
+            }
+        }
+        tables
+    }
+}
+
+/// Read the tables of a yaml file. A file with a `tables` list gives one table per entry,
+/// otherwise the whole file is one table named file_name. A file with several documents
+/// reads each document like a file, a document without tables list being named by its
+/// name or by file_name followed by its position.
+pub fn get_tables_from_string(
+    file_name: &str,
+    file_content: &str,
+) -> Result<Vec<Table>, FakeLakeError> {
+    let parsed_yaml = match YamlLoader::load_from_str(file_content) {
+        Ok(docs) => docs,
+        Err(e) => return Err(FakeLakeError::BadYAMLFormat(e.to_string())),
+    };
+
+    if parsed_yaml.is_empty() {
+        return Err(FakeLakeError::BadYAMLFormat(
+            "The yaml file is empty".to_string(),
+        ));
+    }
+
+    let mut tables: Vec<Table> = Vec::new();
+    for (position, document) in parsed_yaml.iter().enumerate() {
+        let document_tables = match parsed_yaml.len() {
+            1 => get_tables_from_document(file_name, None, document)?,
+            // an empty document between separators holds no table
+            _ if document.is_null() => continue,
+            _ => {
+                let name = match document["name"].as_str() {
+                    Some(value) => value.to_string(),
+                    None => format!("{}_{}", file_name, position + 1),
+                };
+                get_tables_from_document(&name, Some(name.clone()), document)?
+            }
+        };
+
+        for table in document_tables {
+            if tables.iter().any(|other| other.name == table.name) {
+                return Err(FakeLakeError::BadYAMLFormat(format!(
+                    "The table {} is specified more than once.",
+                    table.name
+                )));
+            }
+            tables.push(table);
+        }
+    }
+
+    Ok(tables)
+}
+
+/// Tables of one yaml document, the document being one table named name when it has
+/// no tables list
+fn get_tables_from_document(
+    name: &str,
+    default_output_name: Option<String>,
+    document: &Yaml,
+) -> Result<Vec<Table>, FakeLakeError> {
+    let yaml_tables = match &document["tables"] {
+        Yaml::BadValue => {
+            return Ok(vec![Table {
+                name: name.to_string(),
+                definition: document.clone(),
+                default_output_name,
+            }])
+        }
+        Yaml::Array(value) if !value.is_empty() => value,
+        _ => {
+            return Err(FakeLakeError::BadYAMLFormat(
+                "The tables section should be a non empty list.".to_string(),
+            ))
+        }
+    };
+
+    if !document["columns"].is_badvalue() {
+        return Err(FakeLakeError::BadYAMLFormat(
+            "The yaml file should contain either a columns or a tables section, not both."
+                .to_string(),
+        ));
+    }
+
+    let mut tables: Vec<Table> = Vec::new();
+    for yaml_table in yaml_tables {
+        let name = match yaml_table["name"].as_str() {
+            Some(value) => value.to_string(),
+            None => {
+                return Err(FakeLakeError::BadYAMLFormat(
+                    "One table in the yaml has no name specified.".to_string(),
+                ))
+            }
+        };
+
+        tables.push(Table {
+            default_output_name: Some(name.clone()),
+            name,
+            definition: yaml_table.clone(),
+        });
+    }
+
+    Ok(tables)
+}
+
+/// Rows of the table when a column is made for a number of rows, like a reference with a
+/// cardinality. Other numbers of rows are kept but break the cardinality.
+fn size_from_columns(info: &mut Info, columns: &[Column]) {
+    for column in columns {
+        let expected_rows = match column.provider.expected_rows() {
+            Some(value) => value,
+            None => continue,
+        };
+        match info.rows {
+            None => info.rows = Some(expected_rows),
+            Some(rows) if rows != expected_rows => warn!(
+                "Column {} is made for {} rows but {} rows are generated, so its cardinality is not respected.",
+                column.name, expected_rows, rows
+            ),
+            Some(_) => (),
+        }
+    }
+}
+
+/// Error if a column can't give values for all the rows of a file
+fn check_max_rows(config: &Config) -> Result<(), FakeLakeError> {
+    let rows = config.get_number_of_rows();
+    for column in &config.columns {
+        match column.provider.max_rows() {
+            Some(max_rows) if rows > max_rows => {
+                return Err(FakeLakeError::BadYAMLFormat(format!(
+                    "Column {} can give only {} values, but {} rows are generated.",
+                    column.name, max_rows, rows
+                )))
+            }
+            _ => (),
+        }
+    }
+    Ok(())
+}
+
+/// Config of a yaml file containing one table
+#[cfg(test)]
 pub fn get_config_from_string(file_content: String) -> Result<Config, FakeLakeError> {
     let parsed_yaml = match YamlLoader::load_from_str(&file_content) {
         Ok(docs) => docs,
         Err(e) => return Err(FakeLakeError::BadYAMLFormat(e.to_string())),
     };
 
-    let columns = match Column::generate_columns(&parsed_yaml) {
+    get_config_from_yaml(&parsed_yaml)
+}
+
+fn get_config_from_yaml(parsed_yaml: &[Yaml]) -> Result<Config, FakeLakeError> {
+    let mut info = Info::parse_info_section(parsed_yaml)?;
+    // resolved before the columns, so that their setup is replayed with the printed seed
+    let root_seed = *info.seed.get_or_insert_with(random_root_seed);
+
+    let mut columns = match Column::generate_columns(parsed_yaml, root_seed) {
         Ok(value) => value,
         Err(e) => return Err(FakeLakeError::BadYAMLFormat(e.to_string())),
     };
-
-    let info = Info::parse_info_section(&parsed_yaml).unwrap();
+    bind_columns(&mut columns)?;
+    size_from_columns(&mut info, &columns);
 
     let config = Config {
         columns,
         info: Some(info),
     };
+    check_max_rows(&config)?;
 
     Ok(config)
 }
@@ -389,7 +764,7 @@ mod tests {
     fn generate_columns_from_yaml(yaml_str: &str) -> Result<Vec<Column>, FakeLakeError> {
         let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
 
-        Column::generate_columns(&yaml)
+        Column::generate_columns(&yaml, 0)
     }
 
     // Generate Columns
@@ -854,6 +1229,102 @@ mod tests {
         assert_eq!(config.get_number_of_rows(), 1_000);
     }
 
+    #[test]
+    fn given_reference_cardinality_without_rows_should_size_table_from_it() {
+        registry::publish(
+            "config_cardinality_parent",
+            "id",
+            (0..50).map(Value::Int32).collect(),
+        );
+        let file_content = "
+        columns:
+            - name: parent_id
+              provider: Reference.column
+              table: config_cardinality_parent
+              column: id
+              cardinality: 1..5
+        info:
+            seed: 7
+        "
+        .to_string();
+        let config = get_config_from_string(file_content.clone()).unwrap();
+        let rows = config.get_number_of_rows();
+        assert_eq!(config.columns[0].provider.expected_rows(), Some(rows));
+        assert!((50..250).contains(&rows));
+
+        // the sequence is replayed with the seed of the table
+        let replayed = get_config_from_string(file_content.clone()).unwrap();
+        let values: Vec<Value> = (0..rows)
+            .map(|i| config.columns[0].provider.value(i))
+            .collect();
+        let replayed_values: Vec<Value> = (0..rows)
+            .map(|i| replayed.columns[0].provider.value(i))
+            .collect();
+        assert_eq!(values, replayed_values);
+
+        // each parent has between 1 and 4 children
+        let mut counts: HashMap<i32, u32> = HashMap::new();
+        for value in values.iter() {
+            match value {
+                Value::Int32(value) => *counts.entry(*value).or_default() += 1,
+                _ => panic!("Wrong type"),
+            }
+        }
+        assert_eq!(counts.len(), 50);
+        assert!(counts.values().all(|count| (1..5).contains(count)));
+
+        // another seed gives another sequence
+        let other = get_config_from_string(file_content.replace("seed: 7", "seed: 8")).unwrap();
+        let other_values: Vec<Value> = (0..rows.min(other.get_number_of_rows()))
+            .map(|i| other.columns[0].provider.value(i))
+            .collect();
+        assert_ne!(values[..other_values.len()], other_values[..]);
+    }
+
+    #[test]
+    fn given_reference_cardinality_with_other_rows_should_keep_rows() {
+        registry::publish(
+            "config_cardinality_rows",
+            "id",
+            (0..50).map(Value::Int32).collect(),
+        );
+        let file_content = "
+        columns:
+            - name: parent_id
+              provider: Reference.column
+              table: config_cardinality_rows
+              column: id
+              cardinality: 1..5
+        info:
+            rows: 10
+            seed: 7
+        "
+        .to_string();
+        let config = get_config_from_string(file_content).unwrap();
+        assert_eq!(config.get_number_of_rows(), 10);
+    }
+
+    #[test]
+    fn given_increment_out_of_range_should_return_err() {
+        let file_content = |params: &str, rows: u32| {
+            format!(
+                "
+        columns:
+            - name: id
+              provider: Increment.integer
+              start: 2147483640{}
+        info:
+            rows: {}
+        ",
+                params, rows
+            )
+        };
+
+        assert!(get_config_from_string(file_content("", 8)).is_ok());
+        assert!(get_config_from_string(file_content("", 9)).is_err());
+        assert!(get_config_from_string(file_content("\n              cycle_after: 4", 9)).is_ok());
+    }
+
     #[test]
     fn given_no_files_should_default_to_one() {
         let file_content = "
@@ -884,4 +1355,282 @@ mod tests {
         let config = get_config_from_string(file_content).unwrap();
         assert_eq!(config.get_number_of_generated_files(), 3);
     }
+
+    // get_tables_from_string
+    #[test]
+    fn given_columns_should_return_one_table_named_after_file() {
+        let file_content = "
+        columns:
+            - name: id
+              provider: Increment.integer
+        ";
+        let tables = get_tables_from_string("customers", file_content).unwrap();
+
+        assert_eq!(tables.len(), 1);
+        assert_eq!(tables[0].name, "customers");
+        assert_eq!(tables[0].default_output_name, None);
+    }
+
+    #[test]
+    fn given_tables_should_return_every_table() {
+        let file_content = "
+        tables:
+            - name: customers
+              columns:
+                - name: id
+                  provider: Increment.integer
+            - name: orders
+              columns:
+                - name: customer_id
+                  provider: Reference.column
+                  table: customers
+                  column: id
+              info:
+                output_name: target/orders
+        ";
+        let tables = get_tables_from_string("star_schema", file_content).unwrap();
+
+        assert_eq!(tables.len(), 2);
+        assert_eq!(tables[0].name, "customers");
+        assert_eq!(tables[1].name, "orders");
+        assert_eq!(tables[1].referenced_tables(), vec!["customers".to_string()]);
+
+        let config = tables[0].get_config().unwrap();
+        assert_eq!(config.get_output_file_name(".parquet"), "customers.parquet");
+    }
+
+    #[test]
+    fn given_wrong_tables_should_return_err() {
+        let file_contents = [
+            "",
+            "{{::,..,@#|\"",
+            "tables: customers",
+            "tables: []",
+            "tables:\n  - columns:\n    - name: id\n      provider: Increment.integer",
+            "tables:\n  - name: a\n  - name: a",
+            "columns:\n  - name: id\n    provider: Increment.integer\ntables:\n  - name: a",
+        ];
+        for file_content in file_contents {
+            expecting_err(&get_tables_from_string("file", file_content));
+        }
+    }
+
+    #[test]
+    fn given_several_documents_should_return_table_of_each_document() {
+        let file_content = "
+columns:
+    - name: id
+      provider: Increment.integer
+---
+name: orders
+columns:
+    - name: customer_id
+      provider: Reference.column
+      table: schema_1
+      column: id
+---
+tables:
+    - name: products
+      columns:
+        - name: id
+          provider: Increment.integer
+";
+        let tables = get_tables_from_string("schema", file_content).unwrap();
+
+        let names: Vec<&str> = tables.iter().map(|table| table.name.as_str()).collect();
+        assert_eq!(names, ["schema_1", "orders", "products"]);
+        assert_eq!(tables[0].default_output_name, Some("schema_1".to_string()));
+        assert_eq!(tables[1].referenced_tables(), vec!["schema_1".to_string()]);
+    }
+
+    #[test]
+    fn given_several_documents_with_same_table_should_return_err() {
+        let file_content = "
+name: customers
+columns:
+    - name: id
+      provider: Increment.integer
+---
+tables:
+    - name: customers
+      columns:
+        - name: id
+          provider: Increment.integer
+";
+        expecting_err(&get_tables_from_string("file", file_content));
+    }
+
+    #[test]
+    fn given_table_should_keep_its_output_name() {
+        let file_content = "
+        tables:
+            - name: customers
+              columns:
+                - name: id
+                  provider: Increment.integer
+              info:
+                output_name: target/clients
+        ";
+        let tables = get_tables_from_string("file", file_content).unwrap();
+
+        let config = tables[0].get_config().unwrap();
+        assert_eq!(
+            config.get_output_file_name(".parquet"),
+            "target/clients.parquet"
+        );
+    }
+
+    #[test]
+    fn given_self_reference_should_not_be_referenced_table() {
+        let file_content = "
+        columns:
+            - name: parent_id
+              provider: Reference.column
+              table: employees
+              column: id
+        ";
+        let tables = get_tables_from_string("employees", file_content).unwrap();
+
+        assert!(tables[0].referenced_tables().is_empty());
+    }
+
+    // evaluation_levels
+    fn get_levels(file_content: &str) -> Result<Vec<Vec<usize>>, FakeLakeError> {
+        let parsed_yaml = YamlLoader::load_from_str(file_content).unwrap();
+        let columns = Column::generate_columns(&parsed_yaml, 0).unwrap();
+        get_evaluation_levels(&columns)
+    }
+
+    #[test]
+    fn given_no_dependencies_should_return_one_level_in_order() {
+        let levels = get_levels(
+            "
+        columns:
+            - name: id
+              provider: Increment.integer
+            - name: name
+              provider: Person.fname
+        ",
+        );
+        assert_eq!(levels.unwrap(), vec![vec![0, 1]]);
+    }
+
+    #[test]
+    fn given_dependencies_should_generate_read_columns_first() {
+        let levels = get_levels(
+            "
+        columns:
+            - name: total
+              provider: Expression
+              expression: price * quantity
+            - name: price
+              provider: Random.Number.f64
+            - name: with_tax
+              provider: Expression
+              expression: total * 1.2
+            - name: quantity
+              provider: Random.Number.i32
+        ",
+        );
+        assert_eq!(levels.unwrap(), vec![vec![1, 3], vec![0], vec![2]]);
+    }
+
+    #[test]
+    fn given_cycle_or_unknown_column_should_return_err() {
+        expecting_err(&get_levels(
+            "
+        columns:
+            - name: a
+              provider: Expression
+              expression: b + 1
+            - name: b
+              provider: Expression
+              expression: a + 1
+        ",
+        ));
+        expecting_err(&get_levels(
+            "
+        columns:
+            - name: a
+              provider: Expression
+              expression: unknown + 1
+        ",
+        ));
+    }
+
+    #[test]
+    fn given_wrong_types_should_config_return_err() {
+        let file_content = "
+        columns:
+            - name: name
+              provider: Person.fname
+            - name: double
+              provider: Expression
+              expression: name * 2
+        "
+        .to_string();
+        expecting_err(&get_config_from_string(file_content));
+    }
+
+    #[test]
+    fn given_dependencies_should_config_keep_read_values_in_row() {
+        let file_content = "
+        columns:
+            - name: total
+              provider: Expression
+              expression: quantity * 2
+            - name: quantity
+              provider: Increment.integer
+              start: 10
+        "
+        .to_string();
+        let config = get_config_from_string(file_content).unwrap();
+        let order = config.evaluation_levels().unwrap().concat();
+        let read_columns = config.get_read_columns();
+        assert_eq!(read_columns, vec![false, true]);
+
+        let values = config.next_row(3, &order, &read_columns);
+        assert_eq!(values, vec![Some(Value::Int32(26)), Some(Value::Int32(13))]);
+    }
+
+    #[test]
+    fn given_info_locale_should_be_default_of_columns() {
+        let file_content = "
+        columns:
+            - name: last_name
+              provider: Person.lname
+            - name: french_name
+              provider: Person.lname
+              locale: fr_FR
+        info:
+            locale: ja_JP
+        "
+        .to_string();
+        let config = get_config_from_string(file_content).unwrap();
+        let japanese = crate::providers::person::names::last_names(Locale::JaJP);
+        let french = crate::providers::person::names::last_names(Locale::FrFR);
+        for i in 0..20 {
+            match config.columns[0].provider.value(i) {
+                Value::String(value) => assert!(japanese.contains(&value)),
+                _ => panic!("Wrong type"),
+            }
+            match config.columns[1].provider.value(i) {
+                Value::String(value) => assert!(french.contains(&value)),
+                _ => panic!("Wrong type"),
+            }
+        }
+    }
+
+    #[test]
+    fn given_wrong_info_locale_should_return_err() {
+        let file_content = "
+        columns:
+            - name: last_name
+              provider: Person.lname
+        info:
+            locale: xx_XX
+        "
+        .to_string();
+        expecting_err(&get_config_from_string(file_content));
+    }
 }
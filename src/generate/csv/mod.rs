@@ -1,7 +1,11 @@
 use crate::config::Config;
 use crate::errors::FakeLakeError;
 use crate::generate::output_format::OutputFormat;
+use crate::providers::geo::point;
+use crate::providers::parameters::duration::{self, Format};
+use crate::providers::parameters::encoding;
 use crate::providers::provider::Value;
+use crate::providers::random::uuid;
 use crate::rng;
 
 use csv::WriterBuilder;
@@ -51,22 +55,33 @@ impl OutputFormat for OutputCsv {
             return Err(FakeLakeError::CSVError(e));
         }
 
+        let order: Vec<usize> = config.evaluation_levels()?.concat();
+        let read_columns = config.get_read_columns();
+
         for i in 0..rows {
+            let values = config.next_row(i, &order, &read_columns);
+
             let mut row: Vec<String> = vec![];
-            for column in &config.columns {
-                let mut str_value = "".to_string();
-                if column.is_next_present() {
-                    str_value = match column.provider.value(i) {
-                        Value::Bool(value) => value.to_string(),
-                        Value::Int32(value) => value.to_string(),
-                        Value::Float64(value) => value.to_string(),
-                        Value::String(value) => value,
-                        Value::Date(value, date_format) => value.format(&date_format).to_string(),
-                        Value::Timestamp(value, date_format) => {
-                            value.format(&date_format).to_string()
-                        }
-                    };
-                }
+            for value in values {
+                let str_value = match value {
+                    Some(Value::Bool(value)) => value.to_string(),
+                    Some(Value::Int32(value)) => value.to_string(),
+                    Some(Value::Float64(value)) => value.to_string(),
+                    Some(Value::String(value)) => value,
+                    Some(Value::Date(value, date_format)) => value.format(&date_format).to_string(),
+                    Some(Value::Timestamp(value, date_format, timestamp_type)) => {
+                        timestamp_type.format(&value, &date_format)
+                    }
+                    Some(Value::Time(value, time_format)) => value.format(&time_format).to_string(),
+                    Some(Value::Duration(value, Format::Seconds)) => {
+                        value.num_seconds().to_string()
+                    }
+                    Some(Value::Duration(value, Format::Iso)) => duration::iso(value.num_seconds()),
+                    Some(Value::Uuid(bytes)) => uuid::hyphenated(&bytes),
+                    Some(Value::Point(latitude, longitude)) => point::wkt(latitude, longitude),
+                    Some(Value::Bytes(bytes, _)) => encoding::base64(&bytes),
+                    Some(Value::Null) | None => "".to_string(),
+                };
                 row.push(str_value);
             }
             if let Err(e) = wtr.write_record(row) {
@@ -84,6 +99,7 @@ mod tests {
     use crate::config::{Column, Config, Info, OutputType};
     use crate::options::presence;
     use crate::providers::increment::integer::IncrementIntegerProvider;
+    use crate::providers::parameters::timestamp::TimestampType;
     use crate::providers::random::bool::BoolProvider;
     use crate::providers::random::date::date::DateProvider;
     use crate::providers::random::date::datetime::DatetimeProvider;
@@ -98,7 +114,11 @@ mod tests {
         for _ in 0..nb_columns {
             columns.push(Column {
                 name: "id".to_string(),
-                provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                provider: Box::new(IncrementIntegerProvider {
+                    start: 0,
+                    step: 1,
+                    cycle_after: None,
+                }),
                 presence: presence::new_from_yaml(
                     &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
                 ),
@@ -162,7 +182,11 @@ mod tests {
         let columns = vec![
             Column {
                 name: "id".to_string(),
-                provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                provider: Box::new(IncrementIntegerProvider {
+                    start: 0,
+                    step: 1,
+                    cycle_after: None,
+                }),
                 presence: presence::new_from_yaml(
                     &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
                 ),
@@ -208,6 +232,7 @@ mod tests {
                     format: "%Y-%m-%d %H:%M:%S".to_string(),
                     after: 10_000_000,
                     before: 12_000_000,
+                    timestamp_type: TimestampType::default(),
                 }),
                 presence: presence::new_from_yaml(
                     &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
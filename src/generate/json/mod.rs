@@ -1,10 +1,13 @@
 use crate::config::Config;
 use crate::errors::FakeLakeError;
 use crate::generate::output_format::OutputFormat;
+use crate::providers::parameters::duration::{self, Format};
+use crate::providers::parameters::encoding;
 use crate::providers::provider::Value;
+use crate::providers::random::uuid;
 use crate::rng;
 use serde_json::Value as sv;
-use serde_json::{Map, Number};
+use serde_json::{json, Map, Number};
 use std::fs::File;
 use std::io::{BufWriter, Write};
 
@@ -37,24 +40,45 @@ impl OutputFormat for OutputJson {
 
         let mut buffer = BufWriter::new(File::create(file_name)?);
         let mut json = Vec::<sv>::new();
+        let order: Vec<usize> = config.evaluation_levels()?.concat();
+        let read_columns = config.get_read_columns();
+
         for i in 0..rows {
+            let values = config.next_row(i, &order, &read_columns);
+
             let mut row = Map::new();
-            for column in &config.columns {
-                if column.is_next_present() {
-                    let str_value = match column.provider.value(i) {
-                        Value::Bool(value) => sv::Bool(value),
-                        Value::Int32(value) => sv::Number(Number::from(value)),
-                        Value::Float64(value) => sv::Number(Number::from_f64(value).unwrap()),
-                        Value::String(value) => sv::String(value),
-                        Value::Date(value, date_format) => {
-                            sv::String(value.format(&date_format).to_string())
-                        }
-                        Value::Timestamp(value, date_format) => {
-                            sv::String(value.format(&date_format).to_string())
-                        }
-                    };
-                    row.insert(column.name.to_string(), str_value);
-                }
+            for (column, value) in config.columns.iter().zip(values) {
+                let str_value = match value {
+                    Some(Value::Bool(value)) => sv::Bool(value),
+                    Some(Value::Int32(value)) => sv::Number(Number::from(value)),
+                    // infinite and NaN floats have no JSON number
+                    Some(Value::Float64(value)) => {
+                        Number::from_f64(value).map_or(sv::Null, sv::Number)
+                    }
+                    Some(Value::String(value)) => sv::String(value),
+                    Some(Value::Date(value, date_format)) => {
+                        sv::String(value.format(&date_format).to_string())
+                    }
+                    Some(Value::Timestamp(value, date_format, timestamp_type)) => {
+                        sv::String(timestamp_type.format(&value, &date_format))
+                    }
+                    Some(Value::Time(value, time_format)) => {
+                        sv::String(value.format(&time_format).to_string())
+                    }
+                    Some(Value::Duration(value, Format::Seconds)) => {
+                        sv::Number(Number::from(value.num_seconds()))
+                    }
+                    Some(Value::Duration(value, Format::Iso)) => {
+                        sv::String(duration::iso(value.num_seconds()))
+                    }
+                    Some(Value::Uuid(bytes)) => sv::String(uuid::hyphenated(&bytes)),
+                    Some(Value::Point(latitude, longitude)) => {
+                        json!({"type": "Point", "coordinates": [longitude, latitude]})
+                    }
+                    Some(Value::Bytes(bytes, _)) => sv::String(encoding::base64(&bytes)),
+                    Some(Value::Null) | None => continue,
+                };
+                row.insert(column.name.to_string(), str_value);
             }
 
             if self.wrap_up {
@@ -86,6 +110,7 @@ mod tests {
     use crate::config::{Column, Config, Info, OutputType};
     use crate::options::presence;
     use crate::providers::increment::integer::IncrementIntegerProvider;
+    use crate::providers::parameters::timestamp::TimestampType;
     use crate::providers::random::bool::BoolProvider;
     use crate::providers::random::date::date::DateProvider;
     use crate::providers::random::date::datetime::DatetimeProvider;
@@ -100,7 +125,11 @@ mod tests {
         for _ in 0..nb_columns {
             columns.push(Column {
                 name: "id".to_string(),
-                provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                provider: Box::new(IncrementIntegerProvider {
+                    start: 0,
+                    step: 1,
+                    cycle_after: None,
+                }),
                 presence: presence::new_from_yaml(
                     &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
                 ),
@@ -164,7 +193,11 @@ mod tests {
         let columns = vec![
             Column {
                 name: "id".to_string(),
-                provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                provider: Box::new(IncrementIntegerProvider {
+                    start: 0,
+                    step: 1,
+                    cycle_after: None,
+                }),
                 presence: presence::new_from_yaml(
                     &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
                 ),
@@ -210,6 +243,7 @@ mod tests {
                     format: "%Y-%m-%d %H:%M:%S".to_string(),
                     after: 10_000_000,
                     before: 12_000_000,
+                    timestamp_type: TimestampType::default(),
                 }),
                 presence: presence::new_from_yaml(
                     &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
@@ -239,7 +273,11 @@ mod tests {
     fn given_should_wrap_up_in_a_array() {
         let columns = vec![Column {
             name: "id".to_string(),
-            provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+            provider: Box::new(IncrementIntegerProvider {
+                start: 0,
+                step: 1,
+                cycle_after: None,
+            }),
             presence: presence::new_from_yaml(
                 &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
             ),
@@ -268,11 +306,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn given_not_finite_float_should_write_null() {
+        let columns = vec![Column {
+            name: "ratio".to_string(),
+            provider: Box::new(F64Provider {
+                min: f64::INFINITY,
+                max: f64::INFINITY,
+            }),
+            presence: presence::new_from_yaml(
+                &YamlLoader::load_from_str("name: ratio\npresence: 1").unwrap()[0],
+            ),
+        }];
+
+        let config = Config {
+            columns,
+            info: Some(Info {
+                output_name: Some("target/test_generated/output_not_finite".to_string()),
+                output_format: Some(OutputType::Json(true)),
+                rows: Some(2),
+                files: None,
+                seed: None,
+            }),
+        };
+
+        let output = OutputJson { wrap_up: true };
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        assert_eq!(
+            "[{\"ratio\":null},{\"ratio\":null}]",
+            std::fs::read_to_string("target/test_generated/output_not_finite.json").unwrap()
+        );
+    }
+
     #[test]
     fn given_should_not_wrap_up() {
         let columns = vec![Column {
             name: "id".to_string(),
-            provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+            provider: Box::new(IncrementIntegerProvider {
+                start: 0,
+                step: 1,
+                cycle_after: None,
+            }),
             presence: presence::new_from_yaml(
                 &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
             ),
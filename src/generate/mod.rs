@@ -5,18 +5,29 @@ pub mod parquet;
 
 use crate::config;
 use crate::errors::FakeLakeError;
+use crate::providers::provider::Value;
+use crate::providers::reference::capture::CapturingProvider;
+use crate::providers::reference::registry;
 use csv::OutputCsv;
 use json::OutputJson;
 use output_format::OutputFormat;
 use parquet::OutputParquet;
 
 use log::{debug, info, warn};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 
 pub fn generate_from_paths(paths_to_config: Vec<PathBuf>) -> Result<(), FakeLakeError> {
     let mut res: Result<(), FakeLakeError> = Ok(());
 
+    let mut tables: Vec<config::Table> = Vec::new();
+    let mut read_paths: Vec<PathBuf> = Vec::new();
     for path in paths_to_config {
+        // a file given several times is one table, not tables with the same name
+        if read_paths.contains(&path) {
+            continue;
+        }
+        read_paths.push(path.clone());
         debug!("Parsing YAML file at: {:?}", path);
 
         let file_content = match std::fs::read_to_string(&path) {
@@ -27,8 +38,8 @@ pub fn generate_from_paths(paths_to_config: Vec<PathBuf>) -> Result<(), FakeLake
             }
         };
 
-        match generate_from_string(&path, file_content) {
-            Ok(_) => info!("File from path {:?} generated.", &path),
+        match config::get_tables_from_string(&get_table_name(&path), &file_content) {
+            Ok(value) => tables.extend(value),
             Err(e) => {
                 res = Err(FakeLakeError::BadYAMLFormat(format!(
                     "Unexpected error during file generation from path {:?}: {}",
@@ -38,13 +49,124 @@ pub fn generate_from_paths(paths_to_config: Vec<PathBuf>) -> Result<(), FakeLake
         };
     }
 
+    let referenced_columns: Vec<(String, String)> = tables
+        .iter()
+        .flat_map(|table| table.referenced_columns())
+        .collect();
+
+    for table in order_tables(tables)? {
+        match generate_table(&table, &referenced_columns) {
+            Ok(_) => info!("Table {} generated.", &table.name),
+            Err(e) => {
+                res = Err(FakeLakeError::BadYAMLFormat(format!(
+                    "Unexpected error during generation of table {}: {}",
+                    &table.name, e
+                )));
+            }
+        };
+    }
+
     res
 }
 
-fn generate_from_string(_: &PathBuf, file_content: String) -> Result<(), FakeLakeError> {
-    let config = config::get_config_from_string(file_content)?;
+/// Order the tables so that a referenced table is generated before the tables referencing it.
+/// Tables without dependencies between them keep their order. Table names should be unique
+/// over all the files, as the tables referencing them could not tell them apart.
+fn order_tables(tables: Vec<config::Table>) -> Result<Vec<config::Table>, FakeLakeError> {
+    let names: Vec<String> = tables.iter().map(|table| table.name.clone()).collect();
+    for (position, name) in names.iter().enumerate() {
+        if names[..position].contains(name) {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "The table {} is specified more than once.",
+                name
+            )));
+        }
+    }
+    let mut remaining: Vec<(config::Table, Vec<String>)> = tables
+        .into_iter()
+        .map(|table| {
+            let dependencies = table
+                .referenced_tables()
+                .into_iter()
+                .filter(|name| names.contains(name))
+                .collect();
+            (table, dependencies)
+        })
+        .collect();
+
+    let mut ordered = Vec::new();
+    while !remaining.is_empty() {
+        let ready = remaining.iter().position(|(_, dependencies)| {
+            dependencies
+                .iter()
+                .all(|name| !remaining.iter().any(|(table, _)| table.name == *name))
+        });
+
+        match ready {
+            Some(position) => ordered.push(remaining.remove(position).0),
+            None => {
+                let names: Vec<&str> = remaining
+                    .iter()
+                    .map(|(table, _)| table.name.as_str())
+                    .collect();
+                return Err(FakeLakeError::BadYAMLFormat(format!(
+                    "Tables {} reference each other in a cycle.",
+                    names.join(", ")
+                )));
+            }
+        }
+    }
+
+    Ok(ordered)
+}
+
+fn generate_table(
+    table: &config::Table,
+    referenced_columns: &[(String, String)],
+) -> Result<(), FakeLakeError> {
+    let mut config = table.get_config()?;
     debug!("Parsed YAML config: {:?}", config);
-    generate_from_config(config)
+
+    let captured = capture_referenced_columns(&table.name, &mut config, referenced_columns);
+    generate_from_config(config)?;
+
+    for (column, values) in captured {
+        let values = std::mem::take(&mut *values.lock().unwrap());
+        registry::publish(&table.name, &column, values);
+    }
+    Ok(())
+}
+
+/// A table is named after its YAML file, without the extension
+fn get_table_name(path: &Path) -> String {
+    path.file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn capture_referenced_columns(
+    table: &str,
+    config: &mut config::Config,
+    referenced_columns: &[(String, String)],
+) -> Vec<(String, Arc<Mutex<Vec<Value>>>)> {
+    let mut captured = Vec::new();
+    for column in config.columns.iter_mut() {
+        let is_referenced = referenced_columns
+            .iter()
+            .any(|(t, c)| t == table && *c == column.name);
+        if !is_referenced {
+            continue;
+        }
+
+        let values = Arc::new(Mutex::new(Vec::new()));
+        column.provider = Box::new(CapturingProvider {
+            provider: column.provider.clone_box(),
+            values: values.clone(),
+        });
+        captured.push((column.name.clone(), values));
+    }
+    captured
 }
 
 pub fn generate_from_config(config: config::Config) -> Result<(), FakeLakeError> {
@@ -183,4 +305,108 @@ mod tests {
         let output = generate_from_paths(paths);
         expecting_ok(&output);
     }
+
+    // order_tables
+    fn get_tables(file_content: &str) -> Vec<config::Table> {
+        config::get_tables_from_string("file", file_content).unwrap()
+    }
+
+    fn get_names(tables: &[config::Table]) -> Vec<&str> {
+        tables.iter().map(|table| table.name.as_str()).collect()
+    }
+
+    #[test]
+    fn given_independent_tables_should_keep_order() {
+        let tables = get_tables(
+            "
+        tables:
+            - name: b
+              columns:
+                - name: id
+                  provider: Increment.integer
+            - name: a
+              columns:
+                - name: id
+                  provider: Increment.integer
+        ",
+        );
+
+        let ordered = order_tables(tables).unwrap();
+        assert_eq!(get_names(&ordered), vec!["b", "a"]);
+    }
+
+    #[test]
+    fn given_referenced_table_after_should_generate_it_first() {
+        let tables = get_tables(
+            "
+        tables:
+            - name: order_lines
+              columns:
+                - name: order_id
+                  provider: Reference.column
+                  table: orders
+                  column: id
+            - name: orders
+              columns:
+                - name: id
+                  provider: Increment.integer
+                - name: customer_id
+                  provider: Reference.column
+                  table: customers
+                  column: id
+            - name: customers
+              columns:
+                - name: id
+                  provider: Increment.integer
+        ",
+        );
+
+        let ordered = order_tables(tables).unwrap();
+        assert_eq!(
+            get_names(&ordered),
+            vec!["customers", "orders", "order_lines"]
+        );
+    }
+
+    #[test]
+    fn given_cycle_should_return_err() {
+        let tables = get_tables(
+            "
+        tables:
+            - name: a
+              columns:
+                - name: b_id
+                  provider: Reference.column
+                  table: b
+                  column: id
+            - name: b
+              columns:
+                - name: a_id
+                  provider: Reference.column
+                  table: a
+                  column: id
+        ",
+        );
+
+        expecting_err(&order_tables(tables));
+    }
+
+    #[test]
+    fn given_same_table_in_several_files_should_return_err() {
+        let mut tables = config::get_tables_from_string(
+            "customers",
+            "columns:\n  - name: id\n    provider: Increment.integer",
+        )
+        .unwrap();
+        tables.extend(get_tables(
+            "
+        tables:
+            - name: customers
+              columns:
+                - name: id
+                  provider: Increment.integer
+        ",
+        ));
+        expecting_err(&order_tables(tables));
+    }
 }
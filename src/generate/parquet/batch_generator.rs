@@ -1,12 +1,15 @@
 use super::utils::get_parquet_type_from_column;
 use crate::config::Column;
+use crate::providers::geo::point;
 use crate::providers::provider::Value;
 use arrow_array::{
-    Array, ArrayRef, BooleanArray, Date32Array, Float64Array, Int32Array, StringArray,
-    TimestampSecondArray,
+    Array, ArrayRef, BinaryArray, BooleanArray, Date32Array, DurationSecondArray,
+    FixedSizeBinaryArray, Float64Array, Int32Array, LargeBinaryArray, StringArray,
+    Time64MicrosecondArray, TimestampMicrosecondArray, TimestampMillisecondArray,
+    TimestampNanosecondArray, TimestampSecondArray,
 };
 use arrow_schema::{DataType, TimeUnit};
-use chrono::{Datelike, NaiveDate};
+use chrono::{Datelike, NaiveDate, Timelike};
 use std::sync::Arc;
 
 pub trait CloneParquetBatchGenerator {
@@ -23,11 +26,22 @@ where
 }
 
 pub trait ParquetBatchGenerator: CloneParquetBatchGenerator + Send + Sync {
-    fn batch_array(&self, rows_to_generate: u32) -> Arc<dyn Array>;
-    fn name(&self) -> &str;
+    /// Array of the given values of the column, None being null
+    fn array_from_values(&self, values: Vec<Option<Value>>) -> Arc<dyn Array>;
+    fn column(&self) -> &Column;
     fn new(column: Column) -> Self
     where
         Self: Sized;
+
+    fn batch_array(&self, first_index: u32, rows_to_generate: u32) -> Arc<dyn Array> {
+        let values = (first_index..first_index + rows_to_generate)
+            .map(|i| self.column().next_value(i))
+            .collect();
+        self.array_from_values(values)
+    }
+    fn name(&self) -> &str {
+        &self.column().name
+    }
 }
 
 impl Clone for Box<dyn ParquetBatchGenerator> {
@@ -41,23 +55,22 @@ struct BoolBatchGenerator {
     column: Column,
 }
 impl ParquetBatchGenerator for BoolBatchGenerator {
-    fn batch_array(&self, rows_to_generate: u32) -> Arc<dyn Array> {
-        let mut vec: Vec<Option<bool>> = Vec::new();
-        for i in 0..rows_to_generate {
-            if self.column.is_next_present() {
-                match self.column.provider.value(i) {
-                    Value::Bool(value) => vec.push(Some(value)),
-                    _ => panic!("Wrong provider type"),
-                }
-            } else {
-                vec.push(None)
-            }
-        }
+    fn array_from_values(&self, values: Vec<Option<Value>>) -> Arc<dyn Array> {
+        let vec: Vec<Option<bool>> = values
+            .into_iter()
+            .map(|value| match value {
+                Some(Value::Bool(value)) => Some(value),
+                None | Some(Value::Null) => None,
+                _ => panic!("Wrong provider type"),
+            })
+            .collect();
         Arc::new(BooleanArray::from(vec)) as ArrayRef
     }
-    fn name(&self) -> &str {
-        &self.column.name
+
+    fn column(&self) -> &Column {
+        &self.column
     }
+
     fn new(column: Column) -> BoolBatchGenerator {
         BoolBatchGenerator { column }
     }
@@ -68,23 +81,20 @@ struct IntBatchGenerator {
     column: Column,
 }
 impl ParquetBatchGenerator for IntBatchGenerator {
-    fn batch_array(&self, rows_to_generate: u32) -> Arc<dyn Array> {
-        let mut vec: Vec<Option<i32>> = Vec::new();
-        for i in 0..rows_to_generate {
-            if self.column.is_next_present() {
-                match self.column.provider.value(i) {
-                    Value::Int32(value) => vec.push(Some(value)),
-                    _ => panic!("Wrong provider type"),
-                }
-            } else {
-                vec.push(None)
-            }
-        }
+    fn array_from_values(&self, values: Vec<Option<Value>>) -> Arc<dyn Array> {
+        let vec: Vec<Option<i32>> = values
+            .into_iter()
+            .map(|value| match value {
+                Some(Value::Int32(value)) => Some(value),
+                None | Some(Value::Null) => None,
+                _ => panic!("Wrong provider type"),
+            })
+            .collect();
         Arc::new(Int32Array::from(vec)) as ArrayRef
     }
 
-    fn name(&self) -> &str {
-        &self.column.name
+    fn column(&self) -> &Column {
+        &self.column
     }
 
     fn new(column: Column) -> IntBatchGenerator {
@@ -97,23 +107,20 @@ struct FloatBatchGenerator {
     column: Column,
 }
 impl ParquetBatchGenerator for FloatBatchGenerator {
-    fn batch_array(&self, rows_to_generate: u32) -> Arc<dyn Array> {
-        let mut vec: Vec<Option<f64>> = Vec::new();
-        for i in 0..rows_to_generate {
-            if self.column.is_next_present() {
-                match self.column.provider.value(i) {
-                    Value::Float64(value) => vec.push(Some(value)),
-                    _ => panic!("Wrong provider type"),
-                }
-            } else {
-                vec.push(None)
-            }
-        }
+    fn array_from_values(&self, values: Vec<Option<Value>>) -> Arc<dyn Array> {
+        let vec: Vec<Option<f64>> = values
+            .into_iter()
+            .map(|value| match value {
+                Some(Value::Float64(value)) => Some(value),
+                None | Some(Value::Null) => None,
+                _ => panic!("Wrong provider type"),
+            })
+            .collect();
         Arc::new(Float64Array::from(vec)) as ArrayRef
     }
 
-    fn name(&self) -> &str {
-        &self.column.name
+    fn column(&self) -> &Column {
+        &self.column
     }
 
     fn new(column: Column) -> FloatBatchGenerator {
@@ -126,23 +133,20 @@ struct StrBatchGenerator {
     column: Column,
 }
 impl ParquetBatchGenerator for StrBatchGenerator {
-    fn batch_array(&self, rows_to_generate: u32) -> Arc<dyn Array> {
-        let mut vec: Vec<Option<String>> = Vec::new();
-        for i in 0..rows_to_generate {
-            if self.column.is_next_present() {
-                match self.column.provider.value(i) {
-                    Value::String(value) => vec.push(Some(value)),
-                    _ => panic!("Wrong provider type"),
-                }
-            } else {
-                vec.push(None)
-            }
-        }
+    fn array_from_values(&self, values: Vec<Option<Value>>) -> Arc<dyn Array> {
+        let vec: Vec<Option<String>> = values
+            .into_iter()
+            .map(|value| match value {
+                Some(Value::String(value)) => Some(value),
+                None | Some(Value::Null) => None,
+                _ => panic!("Wrong provider type"),
+            })
+            .collect();
         Arc::new(StringArray::from(vec)) as ArrayRef
     }
 
-    fn name(&self) -> &str {
-        &self.column.name
+    fn column(&self) -> &Column {
+        &self.column
     }
 
     fn new(column: Column) -> StrBatchGenerator {
@@ -155,27 +159,24 @@ struct DateBatchGenerator {
     column: Column,
 }
 impl ParquetBatchGenerator for DateBatchGenerator {
-    fn batch_array(&self, rows_to_generate: u32) -> Arc<dyn Array> {
+    fn array_from_values(&self, values: Vec<Option<Value>>) -> Arc<dyn Array> {
         let epoch_days = NaiveDate::from_ymd_opt(1970, 1, 1)
             .unwrap()
             .num_days_from_ce();
 
-        let mut vec: Vec<Option<i32>> = Vec::new();
-        for i in 0..rows_to_generate {
-            if self.column.is_next_present() {
-                match self.column.provider.value(i) {
-                    Value::Date(value, _) => vec.push(Some(value.num_days_from_ce() - epoch_days)),
-                    _ => panic!("Wrong provider type"),
-                }
-            } else {
-                vec.push(None)
-            }
-        }
+        let vec: Vec<Option<i32>> = values
+            .into_iter()
+            .map(|value| match value {
+                Some(Value::Date(value, _)) => Some(value.num_days_from_ce() - epoch_days),
+                None | Some(Value::Null) => None,
+                _ => panic!("Wrong provider type"),
+            })
+            .collect();
         Arc::new(Date32Array::from(vec)) as ArrayRef
     }
 
-    fn name(&self) -> &str {
-        &self.column.name
+    fn column(&self) -> &Column {
+        &self.column
     }
 
     fn new(column: Column) -> DateBatchGenerator {
@@ -186,27 +187,188 @@ impl ParquetBatchGenerator for DateBatchGenerator {
 #[derive(Clone)]
 struct TimestampBatchGenerator {
     column: Column,
+    unit: TimeUnit,
+    timezone: Option<Arc<str>>,
 }
 impl ParquetBatchGenerator for TimestampBatchGenerator {
-    fn batch_array(&self, rows_to_generate: u32) -> Arc<dyn Array> {
-        let mut vec: Vec<Option<i64>> = Vec::new();
-        for i in 0..rows_to_generate {
-            if self.column.is_next_present() {
-                match self.column.provider.value(i) {
-                    Value::Timestamp(value, _) => vec.push(Some(value.timestamp())),
-                    _ => panic!("Wrong provider type"),
-                }
-            } else {
-                vec.push(None)
+    fn array_from_values(&self, values: Vec<Option<Value>>) -> Arc<dyn Array> {
+        let vec: Vec<Option<i64>> = values
+            .into_iter()
+            .map(|value| match value {
+                Some(Value::Timestamp(value, ..)) => match self.unit {
+                    TimeUnit::Second => Some(value.timestamp()),
+                    TimeUnit::Millisecond => Some(value.timestamp_millis()),
+                    TimeUnit::Microsecond => Some(value.timestamp_micros()),
+                    // null when out of the range of nanoseconds timestamps
+                    TimeUnit::Nanosecond => value.timestamp_nanos_opt(),
+                },
+                None | Some(Value::Null) => None,
+                _ => panic!("Wrong provider type"),
+            })
+            .collect();
+        let timezone = self.timezone.clone();
+        match self.unit {
+            TimeUnit::Second => {
+                Arc::new(TimestampSecondArray::from(vec).with_timezone_opt(timezone)) as ArrayRef
+            }
+            TimeUnit::Millisecond => {
+                Arc::new(TimestampMillisecondArray::from(vec).with_timezone_opt(timezone))
+                    as ArrayRef
+            }
+            TimeUnit::Microsecond => {
+                Arc::new(TimestampMicrosecondArray::from(vec).with_timezone_opt(timezone))
+                    as ArrayRef
+            }
+            TimeUnit::Nanosecond => {
+                Arc::new(TimestampNanosecondArray::from(vec).with_timezone_opt(timezone))
+                    as ArrayRef
             }
         }
-        Arc::new(TimestampSecondArray::from(vec)) as ArrayRef
     }
-    fn name(&self) -> &str {
-        &self.column.name
+
+    fn column(&self) -> &Column {
+        &self.column
     }
+
     fn new(column: Column) -> TimestampBatchGenerator {
-        TimestampBatchGenerator { column }
+        match get_parquet_type_from_column(column.clone()) {
+            DataType::Timestamp(unit, timezone) => TimestampBatchGenerator {
+                column,
+                unit,
+                timezone,
+            },
+            _ => panic!("Wrong provider type"),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct TimeBatchGenerator {
+    column: Column,
+}
+impl ParquetBatchGenerator for TimeBatchGenerator {
+    fn array_from_values(&self, values: Vec<Option<Value>>) -> Arc<dyn Array> {
+        let vec: Vec<Option<i64>> = values
+            .into_iter()
+            .map(|value| match value {
+                Some(Value::Time(value, _)) => Some(
+                    value.num_seconds_from_midnight() as i64 * 1_000_000
+                        + value.nanosecond() as i64 / 1_000,
+                ),
+                None | Some(Value::Null) => None,
+                _ => panic!("Wrong provider type"),
+            })
+            .collect();
+        Arc::new(Time64MicrosecondArray::from(vec)) as ArrayRef
+    }
+
+    fn column(&self) -> &Column {
+        &self.column
+    }
+
+    fn new(column: Column) -> TimeBatchGenerator {
+        TimeBatchGenerator { column }
+    }
+}
+
+#[derive(Clone)]
+struct DurationBatchGenerator {
+    column: Column,
+}
+impl ParquetBatchGenerator for DurationBatchGenerator {
+    fn array_from_values(&self, values: Vec<Option<Value>>) -> Arc<dyn Array> {
+        let vec: Vec<Option<i64>> = values
+            .into_iter()
+            .map(|value| match value {
+                Some(Value::Duration(value, _)) => Some(value.num_seconds()),
+                None | Some(Value::Null) => None,
+                _ => panic!("Wrong provider type"),
+            })
+            .collect();
+        Arc::new(DurationSecondArray::from(vec)) as ArrayRef
+    }
+
+    fn column(&self) -> &Column {
+        &self.column
+    }
+
+    fn new(column: Column) -> DurationBatchGenerator {
+        DurationBatchGenerator { column }
+    }
+}
+
+#[derive(Clone)]
+struct UuidBatchGenerator {
+    column: Column,
+}
+impl ParquetBatchGenerator for UuidBatchGenerator {
+    fn array_from_values(&self, values: Vec<Option<Value>>) -> Arc<dyn Array> {
+        let vec: Vec<Option<[u8; 16]>> = values
+            .into_iter()
+            .map(|value| match value {
+                Some(Value::Uuid(value)) => Some(value),
+                None | Some(Value::Null) => None,
+                _ => panic!("Wrong provider type"),
+            })
+            .collect();
+        Arc::new(FixedSizeBinaryArray::try_from_sparse_iter_with_size(vec.into_iter(), 16).unwrap())
+            as ArrayRef
+    }
+
+    fn column(&self) -> &Column {
+        &self.column
+    }
+
+    fn new(column: Column) -> UuidBatchGenerator {
+        UuidBatchGenerator { column }
+    }
+}
+
+/// Bytes written for a binary value, WKB for points
+fn binary_value(value: Option<Value>) -> Option<Vec<u8>> {
+    match value {
+        Some(Value::Point(latitude, longitude)) => Some(point::wkb(latitude, longitude)),
+        Some(Value::Bytes(bytes, _)) => Some(bytes),
+        None | Some(Value::Null) => None,
+        _ => panic!("Wrong provider type"),
+    }
+}
+
+#[derive(Clone)]
+struct BinaryBatchGenerator {
+    column: Column,
+}
+impl ParquetBatchGenerator for BinaryBatchGenerator {
+    fn array_from_values(&self, values: Vec<Option<Value>>) -> Arc<dyn Array> {
+        Arc::new(BinaryArray::from_iter(values.into_iter().map(binary_value))) as ArrayRef
+    }
+
+    fn column(&self) -> &Column {
+        &self.column
+    }
+
+    fn new(column: Column) -> BinaryBatchGenerator {
+        BinaryBatchGenerator { column }
+    }
+}
+
+#[derive(Clone)]
+struct LargeBinaryBatchGenerator {
+    column: Column,
+}
+impl ParquetBatchGenerator for LargeBinaryBatchGenerator {
+    fn array_from_values(&self, values: Vec<Option<Value>>) -> Arc<dyn Array> {
+        Arc::new(LargeBinaryArray::from_iter(
+            values.into_iter().map(binary_value),
+        )) as ArrayRef
+    }
+
+    fn column(&self) -> &Column {
+        &self.column
+    }
+
+    fn new(column: Column) -> LargeBinaryBatchGenerator {
+        LargeBinaryBatchGenerator { column }
     }
 }
 
@@ -217,9 +379,16 @@ pub fn parquet_batch_generator_builder(column: Column) -> Box<dyn ParquetBatchGe
         DataType::Float64 => Box::new(FloatBatchGenerator::new(column.clone())),
         DataType::Utf8 => Box::new(StrBatchGenerator::new(column.clone())),
         DataType::Date32 => Box::new(DateBatchGenerator::new(column.clone())),
-        DataType::Timestamp(TimeUnit::Second, None) => {
-            Box::new(TimestampBatchGenerator::new(column.clone()))
+        DataType::Timestamp(_, _) => Box::new(TimestampBatchGenerator::new(column.clone())),
+        DataType::Time64(TimeUnit::Microsecond) => {
+            Box::new(TimeBatchGenerator::new(column.clone()))
+        }
+        DataType::Duration(TimeUnit::Second) => {
+            Box::new(DurationBatchGenerator::new(column.clone()))
         }
+        DataType::FixedSizeBinary(16) => Box::new(UuidBatchGenerator::new(column.clone())),
+        DataType::Binary => Box::new(BinaryBatchGenerator::new(column.clone())),
+        DataType::LargeBinary => Box::new(LargeBinaryBatchGenerator::new(column.clone())),
         _ => panic!("Parquet type expected not handled."),
     }
 }
@@ -228,15 +397,34 @@ pub fn parquet_batch_generator_builder(column: Column) -> Box<dyn ParquetBatchGe
 mod tests {
     use super::*;
     use crate::options::presence::new_from_yaml;
+    use crate::providers::geo::area::Area;
+    use crate::providers::geo::point::{Kind, PointProvider};
+    use crate::providers::parameters::duration;
+    use crate::providers::parameters::encoding::Encoding;
+    use crate::providers::parameters::timestamp::{Precision, TimestampType};
+    use crate::providers::random::bytes::BytesProvider;
+    use crate::providers::random::date::time::TimeProvider;
+    use crate::providers::random::duration::DurationProvider;
     use crate::providers::random::number::f64::F64Provider;
     use crate::providers::{
         increment::integer::IncrementIntegerProvider, random::bool::BoolProvider,
         random::date::date::DateProvider, random::date::datetime::DatetimeProvider,
-        random::string::alphanumeric::AlphanumericProvider,
+        random::string::alphanumeric::AlphanumericProvider, random::uuid::Format,
+        random::uuid::UuidProvider, random::uuid::Version,
     };
 
+    use chrono::FixedOffset;
     use yaml_rust::YamlLoader;
 
+    fn uuid_provider() -> Box<UuidProvider> {
+        Box::new(UuidProvider {
+            version: Version::V7,
+            format: Format::Hyphenated,
+            binary: true,
+            start: 0,
+        })
+    }
+
     // Bool batch generator
     #[test]
     fn given_bool_provider_should_return_batch_generator() {
@@ -258,7 +446,7 @@ mod tests {
             presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
         };
         let batch_generator = BoolBatchGenerator { column };
-        let arr = batch_generator.batch_array(1000);
+        let arr = batch_generator.batch_array(0, 1000);
 
         assert_eq!(arr.len(), 1000);
     }
@@ -273,7 +461,7 @@ mod tests {
             ),
         };
         let batch_generator = BoolBatchGenerator { column };
-        let arr = batch_generator.batch_array(1000);
+        let arr = batch_generator.batch_array(0, 1000);
 
         assert_eq!(arr.len(), 1000);
     }
@@ -290,7 +478,7 @@ mod tests {
             presence: new_from_yaml(&YamlLoader::load_from_str("name: temp").unwrap()[0]),
         };
         let batch_generator = BoolBatchGenerator { column };
-        let _ = batch_generator.batch_array(1);
+        let _ = batch_generator.batch_array(0, 1);
     }
 
     // Int32 batch generator
@@ -298,7 +486,11 @@ mod tests {
     fn given_int_provider_should_return_batch_generator() {
         let column = Column {
             name: "int_column".to_string(),
-            provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+            provider: Box::new(IncrementIntegerProvider {
+                start: 0,
+                step: 1,
+                cycle_after: None,
+            }),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
         };
 
@@ -310,11 +502,15 @@ mod tests {
     fn given_int_batch_generator_should_batch_correctly() {
         let column = Column {
             name: "int_column".to_string(),
-            provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+            provider: Box::new(IncrementIntegerProvider {
+                start: 0,
+                step: 1,
+                cycle_after: None,
+            }),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
         };
         let batch_generator = IntBatchGenerator { column };
-        let arr = batch_generator.batch_array(1000);
+        let arr = batch_generator.batch_array(0, 1000);
 
         assert_eq!(arr.len(), 1000);
     }
@@ -323,13 +519,17 @@ mod tests {
     fn given_int_batch_generator_with_presence_should_batch_correctly() {
         let column = Column {
             name: "int_column".to_string(),
-            provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+            provider: Box::new(IncrementIntegerProvider {
+                start: 0,
+                step: 1,
+                cycle_after: None,
+            }),
             presence: new_from_yaml(
                 &YamlLoader::load_from_str("name: id\npresence: 0.5").unwrap()[0],
             ),
         };
         let batch_generator = IntBatchGenerator { column };
-        let arr = batch_generator.batch_array(1000);
+        let arr = batch_generator.batch_array(0, 1000);
 
         assert_eq!(arr.len(), 1000);
     }
@@ -346,7 +546,7 @@ mod tests {
             presence: new_from_yaml(&YamlLoader::load_from_str("name: temp").unwrap()[0]),
         };
         let batch_generator = IntBatchGenerator { column };
-        let _ = batch_generator.batch_array(1);
+        let _ = batch_generator.batch_array(0, 1);
     }
 
     // Float64 batch generator
@@ -370,7 +570,7 @@ mod tests {
             presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
         };
         let batch_generator = FloatBatchGenerator { column };
-        let arr = batch_generator.batch_array(1000);
+        let arr = batch_generator.batch_array(0, 1000);
 
         assert_eq!(arr.len(), 1000);
     }
@@ -385,7 +585,7 @@ mod tests {
             ),
         };
         let batch_generator = FloatBatchGenerator { column };
-        let arr = batch_generator.batch_array(1000);
+        let arr = batch_generator.batch_array(0, 1000);
 
         assert_eq!(arr.len(), 1000);
     }
@@ -402,7 +602,7 @@ mod tests {
             presence: new_from_yaml(&YamlLoader::load_from_str("name: temp").unwrap()[0]),
         };
         let batch_generator = FloatBatchGenerator { column };
-        let _ = batch_generator.batch_array(1);
+        let _ = batch_generator.batch_array(0, 1);
     }
 
     // String batch generator
@@ -432,7 +632,7 @@ mod tests {
             presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
         };
         let batch_generator = StrBatchGenerator { column };
-        let arr = batch_generator.batch_array(1000);
+        let arr = batch_generator.batch_array(0, 1000);
 
         assert_eq!(arr.len(), 1000);
     }
@@ -450,7 +650,7 @@ mod tests {
             ),
         };
         let batch_generator = StrBatchGenerator { column };
-        let arr = batch_generator.batch_array(1000);
+        let arr = batch_generator.batch_array(0, 1000);
 
         assert_eq!(arr.len(), 1000);
     }
@@ -460,11 +660,15 @@ mod tests {
     fn given_str_batch_generator_with_wrong_provider_should_panic() {
         let column = Column {
             name: "str_column".to_string(),
-            provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+            provider: Box::new(IncrementIntegerProvider {
+                start: 0,
+                step: 1,
+                cycle_after: None,
+            }),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: temp").unwrap()[0]),
         };
         let batch_generator = StrBatchGenerator { column };
-        let _ = batch_generator.batch_array(1);
+        let _ = batch_generator.batch_array(0, 1);
     }
 
     // Date batch generator
@@ -496,7 +700,7 @@ mod tests {
             presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
         };
         let batch_generator = DateBatchGenerator { column };
-        let arr = batch_generator.batch_array(1000);
+        let arr = batch_generator.batch_array(0, 1000);
 
         assert_eq!(arr.len(), 1000);
     }
@@ -515,7 +719,7 @@ mod tests {
             ),
         };
         let batch_generator = DateBatchGenerator { column };
-        let arr = batch_generator.batch_array(1000);
+        let arr = batch_generator.batch_array(0, 1000);
 
         assert_eq!(arr.len(), 1000);
     }
@@ -525,11 +729,15 @@ mod tests {
     fn given_date_batch_generator_with_wrong_provider_should_panic() {
         let column = Column {
             name: "date_column".to_string(),
-            provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+            provider: Box::new(IncrementIntegerProvider {
+                start: 0,
+                step: 1,
+                cycle_after: None,
+            }),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: temp").unwrap()[0]),
         };
         let batch_generator = DateBatchGenerator { column };
-        let _ = batch_generator.batch_array(1);
+        let _ = batch_generator.batch_array(0, 1);
     }
 
     // Timestamp batch generator
@@ -541,6 +749,7 @@ mod tests {
                 format: "%Y-%m-%d %H:%M:%S".to_string(),
                 after: 10_000_000,
                 before: 12_000_000,
+                timestamp_type: TimestampType::default(),
             }),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
         };
@@ -557,11 +766,12 @@ mod tests {
                 format: "%Y-%m-%d %H:%M:%S".to_string(),
                 after: 10_000_000,
                 before: 12_000_000,
+                timestamp_type: TimestampType::default(),
             }),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
         };
-        let batch_generator = TimestampBatchGenerator { column };
-        let arr = batch_generator.batch_array(1000);
+        let batch_generator = TimestampBatchGenerator::new(column);
+        let arr = batch_generator.batch_array(0, 1000);
 
         assert_eq!(arr.len(), 1000);
     }
@@ -574,26 +784,303 @@ mod tests {
                 format: "%Y-%m-%d %H:%M:%S".to_string(),
                 after: 10_000_000,
                 before: 12_000_000,
+                timestamp_type: TimestampType::default(),
             }),
             presence: new_from_yaml(
                 &YamlLoader::load_from_str("name: id\npresence: 0.5").unwrap()[0],
             ),
         };
-        let batch_generator = TimestampBatchGenerator { column };
-        let arr = batch_generator.batch_array(1000);
+        let batch_generator = TimestampBatchGenerator::new(column);
+        let arr = batch_generator.batch_array(0, 1000);
 
         assert_eq!(arr.len(), 1000);
     }
 
+    #[test]
+    fn given_timestamp_batch_generator_with_precision_and_timezone_should_batch_correctly() {
+        let column = Column {
+            name: "timestamp_column".to_string(),
+            provider: Box::new(DatetimeProvider {
+                format: "%Y-%m-%d %H:%M:%S".to_string(),
+                after: 10_000_000,
+                before: 12_000_000,
+                timestamp_type: TimestampType {
+                    precision: Precision::Microseconds,
+                    timezone: FixedOffset::east_opt(0),
+                },
+            }),
+            presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
+        };
+        let batch_generator = TimestampBatchGenerator::new(column);
+        let arr = batch_generator.batch_array(0, 1000);
+
+        assert_eq!(arr.len(), 1000);
+        assert_eq!(
+            arr.data_type(),
+            &DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into()))
+        );
+        let values = arr
+            .as_any()
+            .downcast_ref::<TimestampMicrosecondArray>()
+            .unwrap();
+        assert!(values
+            .values()
+            .iter()
+            .all(|value| (10_000_000_000_000..12_000_000_000_000).contains(value)));
+    }
+
     #[test]
     #[should_panic]
     fn given_timestamp_batch_generator_with_wrong_provider_should_panic() {
         let column = Column {
             name: "timestamp_column".to_string(),
-            provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+            provider: Box::new(IncrementIntegerProvider {
+                start: 0,
+                step: 1,
+                cycle_after: None,
+            }),
+            presence: new_from_yaml(&YamlLoader::load_from_str("name: temp").unwrap()[0]),
+        };
+        let batch_generator = TimestampBatchGenerator::new(column);
+        let _ = batch_generator.batch_array(0, 1);
+    }
+
+    // Time batch generator
+    #[test]
+    fn given_time_provider_should_batch_correctly() {
+        let column = Column {
+            name: "time_column".to_string(),
+            provider: Box::new(TimeProvider {
+                format: "%H:%M:%S".to_string(),
+                after: 28_800,
+                before: 64_800,
+            }),
+            presence: new_from_yaml(
+                &YamlLoader::load_from_str("name: id\npresence: 0.5").unwrap()[0],
+            ),
+        };
+        let batch_generator = parquet_batch_generator_builder(column);
+        let arr = batch_generator.batch_array(0, 1000);
+
+        assert_eq!(arr.len(), 1000);
+        let values = arr
+            .as_any()
+            .downcast_ref::<Time64MicrosecondArray>()
+            .unwrap();
+        assert!(values
+            .iter()
+            .flatten()
+            .all(|value| (28_800_000_000..64_800_000_000).contains(&value)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn given_time_batch_generator_with_wrong_provider_should_panic() {
+        let column = Column {
+            name: "time_column".to_string(),
+            provider: Box::new(IncrementIntegerProvider {
+                start: 0,
+                step: 1,
+                cycle_after: None,
+            }),
+            presence: new_from_yaml(&YamlLoader::load_from_str("name: temp").unwrap()[0]),
+        };
+        let batch_generator = TimeBatchGenerator { column };
+        let _ = batch_generator.batch_array(0, 1);
+    }
+
+    // Duration batch generator
+    #[test]
+    fn given_duration_provider_should_batch_correctly() {
+        let column = Column {
+            name: "duration_column".to_string(),
+            provider: Box::new(DurationProvider {
+                min: 60,
+                max: 3600,
+                format: duration::Format::Iso,
+            }),
+            presence: new_from_yaml(
+                &YamlLoader::load_from_str("name: id\npresence: 0.5").unwrap()[0],
+            ),
+        };
+        let batch_generator = parquet_batch_generator_builder(column);
+        let arr = batch_generator.batch_array(0, 1000);
+
+        assert_eq!(arr.len(), 1000);
+        let values = arr.as_any().downcast_ref::<DurationSecondArray>().unwrap();
+        assert!(values
+            .iter()
+            .flatten()
+            .all(|value| (60..3600).contains(&value)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn given_duration_batch_generator_with_wrong_provider_should_panic() {
+        let column = Column {
+            name: "duration_column".to_string(),
+            provider: Box::new(IncrementIntegerProvider {
+                start: 0,
+                step: 1,
+                cycle_after: None,
+            }),
+            presence: new_from_yaml(&YamlLoader::load_from_str("name: temp").unwrap()[0]),
+        };
+        let batch_generator = DurationBatchGenerator { column };
+        let _ = batch_generator.batch_array(0, 1);
+    }
+
+    // Uuid batch generator
+    #[test]
+    fn given_uuid_provider_should_return_batch_generator() {
+        let column = Column {
+            name: "uuid_column".to_string(),
+            provider: uuid_provider(),
+            presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
+        };
+
+        let ret = parquet_batch_generator_builder(column);
+        assert_eq!(ret.name(), "uuid_column");
+    }
+
+    #[test]
+    fn given_uuid_batch_generator_with_presence_should_batch_correctly() {
+        let column = Column {
+            name: "uuid_column".to_string(),
+            provider: uuid_provider(),
+            presence: new_from_yaml(
+                &YamlLoader::load_from_str("name: id\npresence: 0.5").unwrap()[0],
+            ),
+        };
+        let batch_generator = UuidBatchGenerator { column };
+        let arr = batch_generator.batch_array(0, 1000);
+
+        assert_eq!(arr.len(), 1000);
+    }
+
+    #[test]
+    #[should_panic]
+    fn given_uuid_batch_generator_with_wrong_provider_should_panic() {
+        let column = Column {
+            name: "uuid_column".to_string(),
+            provider: Box::new(IncrementIntegerProvider {
+                start: 0,
+                step: 1,
+                cycle_after: None,
+            }),
+            presence: new_from_yaml(&YamlLoader::load_from_str("name: temp").unwrap()[0]),
+        };
+        let batch_generator = UuidBatchGenerator { column };
+        let _ = batch_generator.batch_array(0, 1);
+    }
+
+    // Point batch generator
+    #[test]
+    fn given_point_provider_should_return_wkb_batch() {
+        let column = Column {
+            name: "point_column".to_string(),
+            provider: Box::new(PointProvider {
+                kind: Kind::Point,
+                area: Area::BoundingBox {
+                    west: 2.0,
+                    south: 48.0,
+                    east: 3.0,
+                    north: 49.0,
+                },
+                precision: 6,
+            }),
+            presence: new_from_yaml(
+                &YamlLoader::load_from_str("name: point\npresence: 0.5").unwrap()[0],
+            ),
+        };
+
+        let ret = parquet_batch_generator_builder(column);
+        assert_eq!(ret.name(), "point_column");
+        let arr = ret.batch_array(0, 1000);
+        assert_eq!(arr.len(), 1000);
+
+        let arr = arr.as_any().downcast_ref::<BinaryArray>().unwrap();
+        for value in arr.iter().flatten() {
+            assert_eq!(value.len(), 21);
+            let latitude = f64::from_le_bytes(value[13..].try_into().unwrap());
+            assert!((48.0..=49.0).contains(&latitude));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn given_point_batch_generator_with_wrong_provider_should_panic() {
+        let column = Column {
+            name: "point_column".to_string(),
+            provider: Box::new(IncrementIntegerProvider {
+                start: 0,
+                step: 1,
+                cycle_after: None,
+            }),
+            presence: new_from_yaml(&YamlLoader::load_from_str("name: temp").unwrap()[0]),
+        };
+        let batch_generator = BinaryBatchGenerator { column };
+        let _ = batch_generator.batch_array(0, 1);
+    }
+
+    // Bytes batch generator
+    #[test]
+    fn given_bytes_provider_should_return_binary_batch() {
+        let column = Column {
+            name: "bytes_column".to_string(),
+            provider: Box::new(BytesProvider {
+                min_length: 4,
+                max_length: 8,
+                encoding: Encoding::Raw,
+                large: false,
+            }),
+            presence: new_from_yaml(
+                &YamlLoader::load_from_str("name: bytes\npresence: 0.5").unwrap()[0],
+            ),
+        };
+
+        let ret = parquet_batch_generator_builder(column);
+        let arr = ret.batch_array(0, 1000);
+        assert_eq!(arr.len(), 1000);
+        assert!(arr.null_count() > 0);
+
+        let arr = arr.as_any().downcast_ref::<BinaryArray>().unwrap();
+        for value in arr.iter().flatten() {
+            assert!((4..8).contains(&value.len()));
+        }
+    }
+
+    #[test]
+    fn given_large_bytes_provider_should_return_large_binary_batch() {
+        let column = Column {
+            name: "bytes_column".to_string(),
+            provider: Box::new(BytesProvider {
+                min_length: 32,
+                max_length: 33,
+                encoding: Encoding::Raw,
+                large: true,
+            }),
+            presence: new_from_yaml(&YamlLoader::load_from_str("name: bytes").unwrap()[0]),
+        };
+
+        let ret = parquet_batch_generator_builder(column);
+        let arr = ret.batch_array(0, 100);
+        let arr = arr.as_any().downcast_ref::<LargeBinaryArray>().unwrap();
+        assert_eq!(arr.len(), 100);
+        for value in arr.iter() {
+            assert_eq!(value.unwrap().len(), 32);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn given_large_binary_batch_generator_with_wrong_provider_should_panic() {
+        let column = Column {
+            name: "bytes_column".to_string(),
+            provider: Box::new(BoolProvider {}),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: temp").unwrap()[0]),
         };
-        let batch_generator = TimestampBatchGenerator { column };
-        let _ = batch_generator.batch_array(1);
+        let batch_generator = LargeBinaryBatchGenerator { column };
+        let _ = batch_generator.batch_array(0, 1);
     }
 }
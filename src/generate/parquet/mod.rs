@@ -4,18 +4,27 @@ pub mod utils;
 use crate::config::Config;
 use crate::errors::FakeLakeError;
 use crate::generate::output_format::OutputFormat;
+use crate::providers::provider::Value;
 use crate::rng;
+use crate::row;
 use batch_generator::{parquet_batch_generator_builder, ParquetBatchGenerator};
 
-use arrow_array::{ArrayRef, Int32Array, RecordBatch};
-use arrow_schema::{Field, Schema};
+use arrow_array::{ArrayRef, RecordBatch};
+use arrow_schema::extension::Uuid;
+use arrow_schema::{DataType, Field, Schema};
 use log::debug;
 use parquet::{arrow::ArrowWriter, basic::Compression, file::properties::WriterProperties};
 use rayon::prelude::*;
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
 
 const PARQUET_EXTENSION: &str = ".parquet";
 
+/// Rows of each record batch written in the file
+const BATCH_SIZE: u32 = 8192 * 8;
+
+/// Array of a column for one batch, with its values when other columns read them
+type BatchColumn = (usize, ArrayRef, Option<Vec<Option<Value>>>);
+
 #[derive(Debug, PartialEq)]
 pub struct OutputParquet;
 
@@ -39,21 +48,32 @@ impl OutputFormat for OutputParquet {
             .set_compression(Compression::SNAPPY)
             .build();
 
-        let batch_size = 8192 * 8;
+        let batch_size = BATCH_SIZE;
         let iterations = (rows as f64 / batch_size as f64).ceil() as u32;
 
         let file = std::fs::File::create(file_name)?;
         let mut writer = ArrowWriter::try_new(file, Arc::new(schema.clone()), Some(props))?;
 
-        let mut schema_cols: Vec<(String, ArrayRef)> = Vec::new();
-        let mut provider_generators: Vec<Box<dyn ParquetBatchGenerator>> = Vec::new();
-        config.columns.clone().into_iter().for_each(|column| {
-            schema_cols.push((
-                column.clone().name,
-                Arc::new(Int32Array::from(vec![0])) as ArrayRef,
-            ));
-            provider_generators.push(parquet_batch_generator_builder(column.clone()))
-        });
+        let provider_generators: Vec<Box<dyn ParquetBatchGenerator>> = config
+            .columns
+            .iter()
+            .map(|column| parquet_batch_generator_builder(column.clone()))
+            .collect();
+
+        let levels = config.evaluation_levels()?;
+        let read_columns = config.get_read_columns();
+        let dependencies: Vec<Vec<usize>> = config
+            .columns
+            .iter()
+            .map(|column| {
+                column
+                    .provider
+                    .dependencies()
+                    .iter()
+                    .filter_map(|name| config.columns.iter().position(|c| &c.name == name))
+                    .collect()
+            })
+            .collect();
 
         for i in 0..iterations {
             debug!("Generating batch {} of {}...", i, iterations);
@@ -63,24 +83,64 @@ impl OutputFormat for OutputParquet {
                 batch_size
             };
 
-            let schema_cols: Mutex<Vec<(String, ArrayRef)>> = Mutex::new(schema_cols.clone());
-            let provider_generators = provider_generators.clone();
-
-            provider_generators.into_par_iter().enumerate().for_each(
-                |(col_index, provider_generator)| {
-                    let col_seed = rng::derive_seed(
-                        file_seed,
-                        rng::DOMAIN_PROVIDER,
-                        &[i as u64, col_index as u64],
-                    );
-                    let _scope = rng::scoped_seeded(col_seed);
-                    let array = provider_generator.batch_array(rows_to_generate);
-                    schema_cols.lock().unwrap()[col_index] =
-                        (provider_generator.name().to_string(), array);
-                },
-            );
+            let mut arrays: Vec<Option<ArrayRef>> = vec![None; config.columns.len()];
+            // values of the batch kept for the columns read by other columns
+            let mut read_values: Vec<Option<Vec<Option<Value>>>> = vec![None; config.columns.len()];
+
+            for level in &levels {
+                let generated: Vec<BatchColumn> = level
+                    .par_iter()
+                    .map(|&col_index| {
+                        let col_seed = rng::derive_seed(
+                            file_seed,
+                            rng::DOMAIN_PROVIDER,
+                            &[i as u64, col_index as u64],
+                        );
+                        let _scope = rng::scoped_seeded(col_seed);
+                        let provider_generator = &provider_generators[col_index];
+
+                        if dependencies[col_index].is_empty() && !read_columns[col_index] {
+                            // the row index goes on from one batch to the next
+                            let array =
+                                provider_generator.batch_array(i * batch_size, rows_to_generate);
+                            return (col_index, array, None);
+                        }
 
-            let batch = RecordBatch::try_from_iter(schema_cols.lock().unwrap().clone())?;
+                        let values: Vec<Option<Value>> = (0..rows_to_generate as usize)
+                            .map(|row_index| {
+                                for &dependency in &dependencies[col_index] {
+                                    let dependency_values =
+                                        read_values[dependency].as_ref().unwrap();
+                                    row::set(
+                                        &config.columns[dependency].name,
+                                        dependency_values[row_index].clone(),
+                                    );
+                                }
+                                provider_generator
+                                    .column()
+                                    .next_value(i * batch_size + row_index as u32)
+                            })
+                            .collect();
+                        let kept = read_columns[col_index].then(|| values.clone());
+                        (
+                            col_index,
+                            provider_generator.array_from_values(values),
+                            kept,
+                        )
+                    })
+                    .collect();
+
+                for (col_index, array, kept) in generated {
+                    arrays[col_index] = Some(array);
+                    read_values[col_index] = kept;
+                }
+            }
+
+            let batch = RecordBatch::try_from_iter(provider_generators.iter().zip(arrays).map(
+                |(provider_generator, array)| {
+                    (provider_generator.name().to_string(), array.unwrap())
+                },
+            ))?;
             writer.write(&batch)?;
         }
         writer.close()?;
@@ -94,7 +154,15 @@ fn get_schema_from_config(config: &Config) -> Schema {
 
     for column in &config.columns {
         let parquet_type = utils::get_parquet_type_from_column(column.clone());
-        fields.push(Field::new(&column.name, parquet_type, column.can_be_null()));
+        let field = match parquet_type {
+            // written with the UUID logical type
+            DataType::FixedSizeBinary(16) => {
+                Field::new(&column.name, parquet_type, column.can_be_null())
+                    .with_extension_type(Uuid)
+            }
+            _ => Field::new(&column.name, parquet_type, column.can_be_null()),
+        };
+        fields.push(field);
     }
 
     Schema::new(fields)
@@ -109,7 +177,12 @@ mod tests {
     use crate::config::{Column, Config, Info};
     use crate::options::presence;
     use crate::providers::increment::integer::IncrementIntegerProvider;
+    use crate::providers::random::uuid::{Format, UuidProvider, Version};
 
+    use arrow_array::Int32Array;
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use parquet::basic::LogicalType;
+    use parquet::file::reader::{FileReader, SerializedFileReader};
     use yaml_rust::YamlLoader;
 
     #[ctor]
@@ -121,7 +194,11 @@ mod tests {
     fn given_config_get_schema() {
         let columns = vec![Column {
             name: "id".to_string(),
-            provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+            provider: Box::new(IncrementIntegerProvider {
+                start: 0,
+                step: 1,
+                cycle_after: None,
+            }),
             presence: presence::new_from_yaml(
                 &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
             ),
@@ -143,6 +220,31 @@ mod tests {
         assert_eq!(schema.fields()[0].name(), "id");
     }
     #[test]
+    fn given_binary_uuid_column_should_get_schema_with_uuid_extension() {
+        let columns = vec![Column {
+            name: "id".to_string(),
+            provider: Box::new(UuidProvider {
+                version: Version::V4,
+                format: Format::Hyphenated,
+                binary: true,
+                start: 0,
+            }),
+            presence: presence::new_from_yaml(&YamlLoader::load_from_str("name: id").unwrap()[0]),
+        }];
+
+        let config = Config {
+            columns,
+            info: None,
+        };
+        let schema = get_schema_from_config(&config);
+
+        assert_eq!(
+            schema.fields()[0].data_type(),
+            &DataType::FixedSizeBinary(16)
+        );
+        assert_eq!(schema.fields()[0].extension_type_name(), Some("arrow.uuid"));
+    }
+    #[test]
     fn given_get_extension() {
         let output_parquet = OutputParquet {};
         assert_eq!(output_parquet.get_extension(), ".parquet");
@@ -151,7 +253,11 @@ mod tests {
     fn given_normal_config_should_generate_file() {
         let columns = vec![Column {
             name: "id".to_string(),
-            provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+            provider: Box::new(IncrementIntegerProvider {
+                start: 0,
+                step: 1,
+                cycle_after: None,
+            }),
             presence: presence::new_from_yaml(
                 &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
             ),
@@ -193,4 +299,103 @@ mod tests {
             _ => panic!(),
         }
     }
+
+    #[test]
+    fn given_columns_reading_other_columns_should_generate_file() {
+        let file_content = "
+        columns:
+            - name: total
+              provider: Expression
+              expression: price * quantity
+            - name: price
+              provider: Random.Number.f64
+              presence: 0.5
+            - name: quantity
+              provider: Increment.integer
+        info:
+            output_name: target/test_generated/expression_columns
+            rows: 70000
+        "
+        .to_string();
+        let config = crate::config::get_config_from_string(file_content).unwrap();
+
+        let output_parquet = OutputParquet {};
+        match output_parquet.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    #[test]
+    fn given_binary_uuid_column_should_write_uuid_logical_type() {
+        let file_content = "
+        columns:
+            - name: id
+              provider: Random.uuid
+              version: 7
+              binary: true
+        info:
+            output_name: target/test_generated/uuid_columns
+            rows: 10
+        "
+        .to_string();
+        let config = crate::config::get_config_from_string(file_content).unwrap();
+
+        let output_parquet = OutputParquet {};
+        output_parquet.generate_from_config(&config).unwrap();
+
+        let file = fs::File::open("target/test_generated/uuid_columns.parquet").unwrap();
+        let reader = SerializedFileReader::new(file).unwrap();
+        let column = reader.metadata().file_metadata().schema_descr().column(0);
+        assert_eq!(column.logical_type(), Some(LogicalType::Uuid));
+        assert_eq!(reader.metadata().file_metadata().num_rows(), 10);
+    }
+
+    #[test]
+    fn given_several_batches_should_go_on_with_row_index() {
+        let rows = BATCH_SIZE + 10;
+        let file_content = format!(
+            "
+        columns:
+            - name: id
+              provider: Increment.integer
+            - name: next_id
+              provider: Expression
+              expression: id + 1
+        info:
+            output_name: target/test_generated/several_batches
+            rows: {}
+        ",
+            rows
+        );
+        let config = crate::config::get_config_from_string(file_content).unwrap();
+
+        let output_parquet = OutputParquet {};
+        output_parquet.generate_from_config(&config).unwrap();
+
+        let file = fs::File::open("target/test_generated/several_batches.parquet").unwrap();
+        let reader = ParquetRecordBatchReaderBuilder::try_new(file)
+            .unwrap()
+            .build()
+            .unwrap();
+        let mut ids: Vec<i32> = Vec::new();
+        let mut next_ids: Vec<i32> = Vec::new();
+        for batch in reader {
+            let batch = batch.unwrap();
+            let column = |index: usize| {
+                batch
+                    .column(index)
+                    .as_any()
+                    .downcast_ref::<Int32Array>()
+                    .unwrap()
+                    .values()
+                    .to_vec()
+            };
+            ids.extend(column(0));
+            next_ids.extend(column(1));
+        }
+
+        assert_eq!(ids, (0..rows as i32).collect::<Vec<i32>>());
+        assert_eq!(next_ids, (1..rows as i32 + 1).collect::<Vec<i32>>());
+    }
 }
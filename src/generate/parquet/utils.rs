@@ -1,15 +1,32 @@
 use crate::config::Column;
+use crate::providers::parameters::timestamp::{Precision, TimestampType};
 use crate::providers::provider::Value;
 use arrow_schema::{DataType, TimeUnit};
 
+fn get_timestamp_type(timestamp_type: TimestampType) -> DataType {
+    let unit = match timestamp_type.precision {
+        Precision::Seconds => TimeUnit::Second,
+        Precision::Milliseconds => TimeUnit::Millisecond,
+        Precision::Microseconds => TimeUnit::Microsecond,
+        Precision::Nanoseconds => TimeUnit::Nanosecond,
+    };
+    DataType::Timestamp(unit, timestamp_type.timezone_name().map(Into::into))
+}
+
 pub fn get_parquet_type_from_column(column: Column) -> DataType {
-    match column.provider.value(0) {
+    match column.provider.sample_value() {
         Value::Bool(_) => DataType::Boolean,
         Value::Int32(_) => DataType::Int32,
         Value::Float64(_) => DataType::Float64,
         Value::String(_) => DataType::Utf8,
         Value::Date(_, _) => DataType::Date32,
-        Value::Timestamp(_, _) => DataType::Timestamp(TimeUnit::Second, None),
+        Value::Timestamp(_, _, timestamp_type) => get_timestamp_type(timestamp_type),
+        Value::Time(_, _) => DataType::Time64(TimeUnit::Microsecond),
+        Value::Duration(_, _) => DataType::Duration(TimeUnit::Second),
+        Value::Uuid(_) => DataType::FixedSizeBinary(16),
+        Value::Point(_, _) | Value::Bytes(_, false) => DataType::Binary,
+        Value::Bytes(_, true) => DataType::LargeBinary,
+        Value::Null => DataType::Null,
     }
 }
 
@@ -19,14 +36,22 @@ mod tests {
 
     use crate::config::Column;
     use crate::options::presence::new_from_yaml;
+    use crate::providers::parameters::duration;
+    use crate::providers::parameters::encoding::Encoding;
+    use crate::providers::parameters::timestamp::{Precision, TimestampType};
+    use crate::providers::random::bytes::BytesProvider;
+    use crate::providers::random::date::time::TimeProvider;
+    use crate::providers::random::duration::DurationProvider;
     use crate::providers::random::number::f64::F64Provider;
     use crate::providers::{
         increment::integer::IncrementIntegerProvider, random::bool::BoolProvider,
         random::date::date::DateProvider, random::date::datetime::DatetimeProvider,
-        random::string::alphanumeric::AlphanumericProvider,
+        random::string::alphanumeric::AlphanumericProvider, random::uuid::Format,
+        random::uuid::UuidProvider, random::uuid::Version,
     };
 
     use arrow_schema::{DataType, TimeUnit};
+    use chrono::FixedOffset;
     use yaml_rust::YamlLoader;
 
     #[test]
@@ -43,7 +68,11 @@ mod tests {
     fn given_int_provider_should_return_int_datatype() {
         let column = Column {
             name: "int_column".to_string(),
-            provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+            provider: Box::new(IncrementIntegerProvider {
+                start: 0,
+                step: 1,
+                cycle_after: None,
+            }),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
         };
         assert_eq!(get_parquet_type_from_column(column), DataType::Int32);
@@ -94,6 +123,7 @@ mod tests {
                 format: "%Y-%m-%d %H:%M:%S".to_string(),
                 after: 10_000_000,
                 before: 12_000_000,
+                timestamp_type: TimestampType::default(),
             }),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
         };
@@ -102,4 +132,110 @@ mod tests {
             DataType::Timestamp(TimeUnit::Second, None)
         );
     }
+
+    #[test]
+    fn given_timestamp_provider_with_precision_and_timezone_should_return_them() {
+        for (precision, timezone, unit, timezone_name) in [
+            (Precision::Milliseconds, None, TimeUnit::Millisecond, None),
+            (
+                Precision::Microseconds,
+                Some(0),
+                TimeUnit::Microsecond,
+                Some("UTC"),
+            ),
+            (
+                Precision::Nanoseconds,
+                Some(-3600),
+                TimeUnit::Nanosecond,
+                Some("-01:00"),
+            ),
+        ] {
+            let column = Column {
+                name: "timestamp_column".to_string(),
+                provider: Box::new(DatetimeProvider {
+                    format: "%Y-%m-%d %H:%M:%S".to_string(),
+                    after: 10_000_000,
+                    before: 12_000_000,
+                    timestamp_type: TimestampType {
+                        precision,
+                        timezone: timezone.map(|offset| FixedOffset::east_opt(offset).unwrap()),
+                    },
+                }),
+                presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
+            };
+            assert_eq!(
+                get_parquet_type_from_column(column),
+                DataType::Timestamp(unit, timezone_name.map(Into::into))
+            );
+        }
+    }
+
+    #[test]
+    fn given_time_provider_should_return_time64_datatype() {
+        let column = Column {
+            name: "time_column".to_string(),
+            provider: Box::new(TimeProvider {
+                format: "%H:%M:%S".to_string(),
+                after: 0,
+                before: 0,
+            }),
+            presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
+        };
+        assert_eq!(
+            get_parquet_type_from_column(column),
+            DataType::Time64(TimeUnit::Microsecond)
+        );
+    }
+
+    #[test]
+    fn given_duration_provider_should_return_duration_datatype() {
+        let column = Column {
+            name: "duration_column".to_string(),
+            provider: Box::new(DurationProvider {
+                min: 0,
+                max: 60,
+                format: duration::Format::Seconds,
+            }),
+            presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
+        };
+        assert_eq!(
+            get_parquet_type_from_column(column),
+            DataType::Duration(TimeUnit::Second)
+        );
+    }
+
+    #[test]
+    fn given_binary_uuid_provider_should_return_fixed_size_binary_datatype() {
+        let column = Column {
+            name: "uuid_column".to_string(),
+            provider: Box::new(UuidProvider {
+                version: Version::V4,
+                format: Format::Hyphenated,
+                binary: true,
+                start: 0,
+            }),
+            presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
+        };
+        assert_eq!(
+            get_parquet_type_from_column(column),
+            DataType::FixedSizeBinary(16)
+        );
+    }
+
+    #[test]
+    fn given_bytes_provider_should_return_binary_datatype() {
+        for (large, data_type) in [(false, DataType::Binary), (true, DataType::LargeBinary)] {
+            let column = Column {
+                name: "bytes_column".to_string(),
+                provider: Box::new(BytesProvider {
+                    min_length: 4,
+                    max_length: 8,
+                    encoding: Encoding::Raw,
+                    large,
+                }),
+                presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
+            };
+            assert_eq!(get_parquet_type_from_column(column), data_type);
+        }
+    }
 }
@@ -6,6 +6,7 @@ mod logger;
 mod options;
 mod providers;
 mod rng;
+mod row;
 
 use crate::cli::{Cli, Commands};
 use crate::generate::generate_from_paths;
@@ -0,0 +1,314 @@
+use crate::providers::parameters::locale::{Locale, LocaleParameter};
+use crate::providers::provider::{Provider, Value};
+use crate::providers::utils::string::random_alphanumeric;
+use crate::rng;
+use crate::row;
+
+use super::places::{self, Place};
+
+use yaml_rust::Yaml;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Kind {
+    Street,
+    City,
+    Postcode,
+    Country,
+    CountryCode,
+    State,
+    Full,
+}
+
+impl Kind {
+    pub fn from_name(name: &str) -> Option<Kind> {
+        match name {
+            "street" => Some(Kind::Street),
+            "city" => Some(Kind::City),
+            "postcode" => Some(Kind::Postcode),
+            "country" => Some(Kind::Country),
+            "country_code" => Some(Kind::CountryCode),
+            "state" => Some(Kind::State),
+            "full" => Some(Kind::Full),
+            _ => None,
+        }
+    }
+}
+
+/// Name of the country of the locale in its language
+fn country(locale: Locale) -> &'static str {
+    match locale {
+        Locale::DeDE => "Deutschland",
+        Locale::EnGB => "United Kingdom",
+        Locale::EnUS => "United States",
+        Locale::EsES => "España",
+        Locale::FrFR => "France",
+        Locale::ItIT => "Italia",
+        Locale::JaJP => "日本",
+        Locale::PtBR => "Brasil",
+        Locale::ZhCN => "中国",
+    }
+}
+
+fn random_street(locale: Locale) -> String {
+    let streets = places::streets(locale);
+    let street = &streets[rng::usize(..streets.len())];
+    match locale {
+        Locale::DeDE => format!("{} {}", street, rng::u32(1..200)),
+        Locale::EnGB | Locale::FrFR => format!("{} {}", rng::u32(1..200), street),
+        Locale::EnUS => format!("{} {}", rng::u32(1..10000), street),
+        Locale::EsES | Locale::ItIT => format!("{}, {}", street, rng::u32(1..200)),
+        Locale::PtBR => format!("{}, {}", street, rng::u32(1..3000)),
+        Locale::JaJP => format!(
+            "{}{}丁目{}-{}",
+            street,
+            rng::u32(1..10),
+            rng::u32(1..30),
+            rng::u32(1..20)
+        ),
+        Locale::ZhCN => format!("{}{}号", street, rng::u32(1..1000)),
+    }
+}
+
+fn full_address(locale: Locale, street: &str, postcode: &str, place: &Place) -> String {
+    let country = country(locale);
+    let (city, state) = (&place.city, &place.state);
+    match locale {
+        Locale::EnGB => format!("{}, {} {}, {}", street, city, postcode, country),
+        Locale::EnUS => format!("{}, {}, {} {}, {}", street, city, state, postcode, country),
+        Locale::JaJP => format!("〒{} {}{}{}", postcode, state, city, street),
+        Locale::PtBR => format!(
+            "{}, {} - {}, {}, {}",
+            street, city, state, postcode, country
+        ),
+        Locale::ZhCN => format!("{}{}{} {}", state, city, street, postcode),
+        _ => format!("{}, {} {}, {}", street, postcode, city, country),
+    }
+}
+
+#[derive(Clone)]
+pub struct AddressProvider {
+    pub kind: Kind,
+    pub locale: Locale,
+    /// Address column of the row the city, state and postcode are taken from
+    pub place_column: Option<(String, Kind)>,
+    /// Street column of the row a street or a full address is taken from
+    pub street_column: Option<String>,
+}
+
+impl AddressProvider {
+    fn read(column: &str) -> Option<String> {
+        match row::get(column) {
+            Some(Value::String(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Place of the place column value, or a random place if there is none
+    fn place(&self) -> &'static Place {
+        let places = places::places(self.locale);
+        let matching: Vec<&Place> = match &self.place_column {
+            Some((column, kind)) => match Self::read(column) {
+                Some(value) => places
+                    .iter()
+                    .filter(|place| match kind {
+                        Kind::City => place.city == value,
+                        Kind::State => place.state == value,
+                        Kind::Postcode => place.has_postcode(&value),
+                        _ => true,
+                    })
+                    .collect(),
+                None => Vec::new(),
+            },
+            None => Vec::new(),
+        };
+
+        match matching.is_empty() {
+            true => &places[rng::usize(..places.len())],
+            false => matching[rng::usize(..matching.len())],
+        }
+    }
+
+    fn postcode(&self, place: &Place) -> String {
+        match &self.place_column {
+            Some((column, Kind::Postcode)) => match Self::read(column) {
+                Some(value) if place.has_postcode(&value) => value,
+                _ => place.random_postcode(),
+            },
+            _ => place.random_postcode(),
+        }
+    }
+
+    fn street(&self) -> String {
+        match self.street_column.as_ref().and_then(|c| Self::read(c)) {
+            Some(value) => value,
+            None => random_street(self.locale),
+        }
+    }
+}
+
+impl Provider for AddressProvider {
+    fn value(&self, _: u32) -> Value {
+        let value = match self.kind {
+            Kind::Street => self.street(),
+            Kind::City => self.place().city.clone(),
+            Kind::Postcode => self.postcode(self.place()),
+            Kind::Country => country(self.locale).to_string(),
+            Kind::CountryCode => self.locale.country_code().to_string(),
+            Kind::State => self.place().state.clone(),
+            Kind::Full => {
+                let place = self.place();
+                full_address(self.locale, &self.street(), &self.postcode(place), place)
+            }
+        };
+        Value::String(value)
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        Value::String(random_alphanumeric(10))
+    }
+    fn dependencies(&self) -> Vec<String> {
+        let mut dependencies = Vec::new();
+        if let Some((column, _)) = &self.place_column {
+            dependencies.push(column.clone());
+        }
+        if let Some(column) = &self.street_column {
+            dependencies.push(column.clone());
+        }
+        dependencies
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml, kind: Kind) -> Box<AddressProvider> {
+    Box::new(AddressProvider {
+        kind,
+        locale: LocaleParameter::new(column).value,
+        place_column: None,
+        street_column: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_from_yaml, AddressProvider, Kind};
+    use crate::providers::address::places;
+    use crate::providers::parameters::locale::Locale;
+    use crate::providers::provider::{Provider, Value};
+    use crate::row;
+
+    use regex::Regex;
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider(yaml_str: &str, kind: Kind) -> Box<AddressProvider> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        new_from_yaml(&yaml[0], kind)
+    }
+
+    fn string_value(provider: &dyn Provider) -> String {
+        match provider.value(0) {
+            Value::String(value) => value,
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_names_should_return_kinds() {
+        assert_eq!(Kind::from_name("country_code"), Some(Kind::CountryCode));
+        assert_eq!(Kind::from_name("full"), Some(Kind::Full));
+        assert_eq!(Kind::from_name("zip"), None);
+    }
+
+    #[test]
+    fn given_locale_should_use_it() {
+        let provider = generate_provider("name: a\nlocale: de_DE", Kind::City);
+        assert_eq!(provider.locale, Locale::DeDE);
+        assert!(provider.dependencies().is_empty());
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_locale_should_return_country() {
+        let provider = generate_provider("name: a\nlocale: pt_BR", Kind::Country);
+        assert_eq!(string_value(provider.as_ref()), "Brasil");
+        let provider = generate_provider("name: a\nlocale: pt_BR", Kind::CountryCode);
+        assert_eq!(string_value(provider.as_ref()), "BR");
+    }
+
+    #[test]
+    fn given_locale_should_return_postcodes_of_country() {
+        for (locale, pattern) in [
+            ("fr_FR", r"^\d{5}$"),
+            ("en_US", r"^\d{5}$"),
+            ("en_GB", r"^[A-Z]{1,2}\d{1,2} \d[A-Z]{2}$"),
+            ("ja_JP", r"^\d{3}-\d{4}$"),
+            ("pt_BR", r"^\d{5}-\d{3}$"),
+            ("zh_CN", r"^\d{6}$"),
+        ] {
+            let re = Regex::new(pattern).unwrap();
+            let provider =
+                generate_provider(&format!("name: a\nlocale: {}", locale), Kind::Postcode);
+            for _ in 0..50 {
+                let value = string_value(provider.as_ref());
+                assert!(
+                    re.is_match(&value),
+                    "{} is not a {} postcode",
+                    value,
+                    locale
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn given_locale_should_return_streets_of_locale() {
+        let provider = generate_provider("name: a\nlocale: de_DE", Kind::Street);
+        let value = string_value(provider.as_ref());
+        assert!(places::streets(Locale::DeDE)
+            .iter()
+            .any(|street| value.starts_with(street.as_str())));
+    }
+
+    #[test]
+    fn given_place_column_should_return_same_place() {
+        let mut provider = generate_provider("name: a\nlocale: fr_FR", Kind::Postcode);
+        provider.place_column = Some(("address_city".to_string(), Kind::City));
+        assert_eq!(provider.dependencies(), vec!["address_city"]);
+
+        row::set("address_city", Some(Value::String("Lyon".to_string())));
+        for _ in 0..20 {
+            assert!(string_value(provider.as_ref()).starts_with("6900"));
+        }
+
+        let mut provider = generate_provider("name: a\nlocale: fr_FR", Kind::City);
+        provider.place_column = Some(("address_postcode".to_string(), Kind::Postcode));
+        row::set("address_postcode", Some(Value::String("33012".to_string())));
+        assert_eq!(string_value(provider.as_ref()), "Bordeaux");
+    }
+
+    #[test]
+    fn given_columns_should_full_address_reuse_them() {
+        let mut provider = generate_provider("name: a\nlocale: fr_FR", Kind::Full);
+        provider.place_column = Some(("full_postcode".to_string(), Kind::Postcode));
+        provider.street_column = Some("full_street".to_string());
+
+        row::set("full_postcode", Some(Value::String("75011".to_string())));
+        row::set(
+            "full_street",
+            Some(Value::String("3 rue Pasteur".to_string())),
+        );
+        assert_eq!(
+            string_value(provider.as_ref()),
+            "3 rue Pasteur, 75011 Paris, France"
+        );
+    }
+
+    #[test]
+    fn given_unknown_place_should_return_random_place() {
+        let mut provider = generate_provider("name: a\nlocale: en_US", Kind::State);
+        provider.place_column = Some(("unknown_city".to_string(), Kind::City));
+        row::set("unknown_city", Some(Value::String("Paris".to_string())));
+        let value = string_value(provider.as_ref());
+        assert!(places::places(Locale::EnUS)
+            .iter()
+            .any(|place| place.state == value));
+    }
+}
@@ -0,0 +1,71 @@
+use crate::errors::FakeLakeError;
+use crate::providers::provider::{unknown_provider, Provider};
+
+use super::address::{self, Kind};
+
+use yaml_rust::Yaml;
+
+const AVAILABLE: &[&str] = &[
+    "address.city",
+    "address.country",
+    "address.country_code",
+    "address.full",
+    "address.postcode",
+    "address.state",
+    "address.street",
+];
+
+pub fn get_corresponding_provider(
+    mut provider_split: std::str::Split<'_, char>,
+    column: &Yaml,
+) -> Result<Box<dyn Provider>, FakeLakeError> {
+    match provider_split.next() {
+        Some(name) if Kind::from_name(name).is_some() => Ok(address::new_from_yaml(
+            column,
+            Kind::from_name(name).unwrap(),
+        )),
+        other => Err(unknown_provider("address", other, AVAILABLE)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::get_corresponding_provider;
+
+    use yaml_rust::YamlLoader;
+
+    #[test]
+    fn given_address_providers_should_return_provider() {
+        for provider_name in [
+            "city",
+            "country",
+            "country_code",
+            "full",
+            "postcode",
+            "state",
+            "street",
+        ] {
+            let yaml_str = format!("name: address{}provider: {}", '\n', provider_name);
+            let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+            let provider_split = provider_name.split('.');
+            match get_corresponding_provider(provider_split, column) {
+                Ok(_) => (),
+                _ => panic!("{} should return a provider", provider_name),
+            }
+        }
+    }
+
+    #[test]
+    fn given_wrong_provider_should_return_error() {
+        let provider_name = "not_a_provider";
+        let yaml_str = format!("name: address{}provider: {}", '\n', provider_name);
+        let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+        let provider_split = provider_name.split('.');
+        match get_corresponding_provider(provider_split, column) {
+            Err(_) => (),
+            _ => panic!(),
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use crate::providers::parameters::locale::{Locale, LocaleParameter};
+use crate::providers::provider::Provider;
+
+use super::address::{self, AddressProvider, Kind};
+
+use std::collections::HashMap;
+use yaml_rust::Yaml;
+
+/// Kinds giving the place of an address, the most precise first
+const PLACE_KINDS: [Kind; 3] = [Kind::Postcode, Kind::City, Kind::State];
+
+struct AddressColumn<'a> {
+    name: &'a str,
+    kind: Kind,
+    column: &'a Yaml,
+}
+
+/// Address columns of the yaml, by locale and address_group
+fn get_groups(columns: &[Yaml]) -> Vec<Vec<AddressColumn<'_>>> {
+    let mut groups: Vec<((Locale, String), Vec<AddressColumn>)> = Vec::new();
+    for column in columns {
+        let name = match column["name"].as_str() {
+            Some(value) => value,
+            None => continue,
+        };
+        let provider = column["provider"]
+            .as_str()
+            .unwrap_or_default()
+            .to_lowercase();
+        let kind = match provider.strip_prefix("address.").and_then(Kind::from_name) {
+            Some(value) => value,
+            None => continue,
+        };
+
+        let key = (
+            LocaleParameter::new(column).value,
+            column["address_group"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+        );
+        let address_column = AddressColumn { name, kind, column };
+        match groups.iter_mut().find(|(group_key, _)| *group_key == key) {
+            Some((_, group)) => group.push(address_column),
+            None => groups.push((key, vec![address_column])),
+        }
+    }
+    groups.into_iter().map(|(_, group)| group).collect()
+}
+
+/// Address providers reading the other address columns of their group, so that the
+/// street, city, state and postcode of a row are consistent. Columns reading no other
+/// column are not returned.
+pub fn new_from_yaml(columns: &[Yaml]) -> HashMap<String, Box<dyn Provider>> {
+    let mut providers: HashMap<String, Box<dyn Provider>> = HashMap::new();
+
+    for group in get_groups(columns) {
+        let place = PLACE_KINDS
+            .iter()
+            .find_map(|kind| group.iter().find(|column| column.kind == *kind));
+        let street = group.iter().find(|column| column.kind == Kind::Street);
+
+        for column in &group {
+            let mut provider: Box<AddressProvider> =
+                address::new_from_yaml(column.column, column.kind);
+            if let Some(place) = place {
+                if place.name != column.name
+                    && (PLACE_KINDS.contains(&column.kind) || column.kind == Kind::Full)
+                {
+                    provider.place_column = Some((place.name.to_string(), place.kind));
+                }
+            }
+            if let Some(street) = street {
+                if street.name != column.name && [Kind::Street, Kind::Full].contains(&column.kind) {
+                    provider.street_column = Some(street.name.to_string());
+                }
+            }
+
+            if !provider.dependencies().is_empty() {
+                providers.insert(column.name.to_string(), provider);
+            }
+        }
+    }
+
+    providers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::new_from_yaml;
+
+    use yaml_rust::YamlLoader;
+
+    fn dependencies(yaml_str: &str) -> Vec<(String, Vec<String>)> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        let providers = new_from_yaml(yaml[0].as_vec().unwrap());
+        let mut dependencies: Vec<(String, Vec<String>)> = providers
+            .iter()
+            .map(|(name, provider)| (name.clone(), provider.dependencies()))
+            .collect();
+        dependencies.sort();
+        dependencies
+    }
+
+    fn pair(name: &str, dependencies: &[&str]) -> (String, Vec<String>) {
+        (
+            name.to_string(),
+            dependencies.iter().map(|v| v.to_string()).collect(),
+        )
+    }
+
+    #[test]
+    fn given_address_columns_should_read_most_precise_place() {
+        let yaml = "
+- name: country
+  provider: Address.country
+- name: city
+  provider: Address.city
+- name: street
+  provider: Address.street
+- name: postcode
+  provider: Address.postcode
+- name: full
+  provider: Address.full
+- name: id
+  provider: Increment.integer
+";
+        assert_eq!(
+            dependencies(yaml),
+            vec![
+                pair("city", &["postcode"]),
+                pair("full", &["postcode", "street"])
+            ]
+        );
+    }
+
+    #[test]
+    fn given_one_address_column_should_return_nothing() {
+        assert!(dependencies("- name: city\n  provider: address.city").is_empty());
+    }
+
+    #[test]
+    fn given_groups_and_locales_should_link_only_inside_them() {
+        let yaml = "
+- name: billing_city
+  provider: address.city
+  address_group: billing
+- name: billing_state
+  provider: address.state
+  address_group: billing
+- name: shipping_city
+  provider: address.city
+  address_group: shipping
+- name: us_state
+  provider: address.state
+  address_group: shipping
+  locale: en_US
+";
+        assert_eq!(
+            dependencies(yaml),
+            vec![pair("billing_state", &["billing_city"])]
+        );
+    }
+}
@@ -0,0 +1,6 @@
+pub mod builder;
+
+#[allow(clippy::module_inception)]
+pub mod address;
+pub mod group;
+pub mod places;
@@ -0,0 +1,173 @@
+use crate::providers::parameters::locale::{Locale, AVAILABLE_LOCALES};
+use crate::providers::utils::string::lines;
+use crate::rng;
+
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+
+/// A city with its state and the pattern of its postcodes
+#[derive(Debug, PartialEq)]
+pub struct Place {
+    pub city: String,
+    pub state: String,
+    /// Postcode where # is a digit and ? an uppercase letter
+    pub postcode: String,
+}
+
+impl Place {
+    fn from_line(line: &str) -> Place {
+        let mut fields = line.split(';');
+        let mut next = || fields.next().unwrap_or_default().to_string();
+        Place {
+            city: next(),
+            state: next(),
+            postcode: next(),
+        }
+    }
+
+    /// A random postcode of the pattern
+    pub fn random_postcode(&self) -> String {
+        self.postcode
+            .chars()
+            .map(|c| match c {
+                '#' => char::from(b'0' + rng::u32(0..10) as u8),
+                '?' => char::from(b'A' + rng::u32(0..26) as u8),
+                c => c,
+            })
+            .collect()
+    }
+
+    /// Whether a postcode follows the pattern
+    pub fn has_postcode(&self, value: &str) -> bool {
+        value.chars().count() == self.postcode.chars().count()
+            && value
+                .chars()
+                .zip(self.postcode.chars())
+                .all(|(v, p)| match p {
+                    '#' => v.is_ascii_digit(),
+                    '?' => v.is_ascii_uppercase(),
+                    p => v == p,
+                })
+    }
+}
+
+pub struct LocaleAddresses {
+    pub places: Vec<Place>,
+    pub streets: Vec<String>,
+}
+
+/// Places and street names of a locale
+fn files(locale: Locale) -> [&'static str; 2] {
+    match locale {
+        Locale::DeDE => [
+            include_str!("../../../static/address_de_DE.txt"),
+            include_str!("../../../static/street_de_DE.txt"),
+        ],
+        Locale::EnGB => [
+            include_str!("../../../static/address_en_GB.txt"),
+            include_str!("../../../static/street_en_GB.txt"),
+        ],
+        Locale::EnUS => [
+            include_str!("../../../static/address_en_US.txt"),
+            include_str!("../../../static/street_en_US.txt"),
+        ],
+        Locale::EsES => [
+            include_str!("../../../static/address_es_ES.txt"),
+            include_str!("../../../static/street_es_ES.txt"),
+        ],
+        Locale::FrFR => [
+            include_str!("../../../static/address_fr_FR.txt"),
+            include_str!("../../../static/street_fr_FR.txt"),
+        ],
+        Locale::ItIT => [
+            include_str!("../../../static/address_it_IT.txt"),
+            include_str!("../../../static/street_it_IT.txt"),
+        ],
+        Locale::JaJP => [
+            include_str!("../../../static/address_ja_JP.txt"),
+            include_str!("../../../static/street_ja_JP.txt"),
+        ],
+        Locale::PtBR => [
+            include_str!("../../../static/address_pt_BR.txt"),
+            include_str!("../../../static/street_pt_BR.txt"),
+        ],
+        Locale::ZhCN => [
+            include_str!("../../../static/address_zh_CN.txt"),
+            include_str!("../../../static/street_zh_CN.txt"),
+        ],
+    }
+}
+
+fn load(locale: Locale) -> LocaleAddresses {
+    let [places, streets] = files(locale);
+    LocaleAddresses {
+        places: places.lines().map(Place::from_line).collect(),
+        streets: lines(streets),
+    }
+}
+
+static ADDRESSES: Lazy<HashMap<Locale, LocaleAddresses>> = Lazy::new(|| {
+    AVAILABLE_LOCALES
+        .iter()
+        .filter_map(|code| Locale::from_code(code))
+        .map(|locale| (locale, load(locale)))
+        .collect()
+});
+
+pub fn places(locale: Locale) -> &'static [Place] {
+    &ADDRESSES[&locale].places
+}
+
+pub fn streets(locale: Locale) -> &'static [String] {
+    &ADDRESSES[&locale].streets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{places, streets, Place};
+    use crate::providers::parameters::locale::{Locale, AVAILABLE_LOCALES};
+
+    fn locales() -> Vec<Locale> {
+        AVAILABLE_LOCALES
+            .iter()
+            .filter_map(|code| Locale::from_code(code))
+            .collect()
+    }
+
+    #[test]
+    fn given_locale_should_have_places_and_streets() {
+        for locale in locales() {
+            assert!(!places(locale).is_empty());
+            assert!(!streets(locale).is_empty());
+            for place in places(locale) {
+                assert!(!place.city.is_empty() && !place.state.is_empty());
+                assert!(!place.postcode.is_empty(), "{} has no postcode", place.city);
+            }
+        }
+    }
+
+    #[test]
+    fn given_locale_should_postcodes_belong_to_one_place() {
+        for locale in locales() {
+            for place in places(locale) {
+                for _ in 0..20 {
+                    let postcode = place.random_postcode();
+                    let owners: Vec<&Place> = places(locale)
+                        .iter()
+                        .filter(|other| other.has_postcode(&postcode))
+                        .collect();
+                    assert_eq!(owners, vec![place], "{} has several places", postcode);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn given_pattern_should_check_postcodes() {
+        let place = Place::from_line("London;England;SW# #??");
+        assert!(place.has_postcode("SW1 2AB"));
+        assert!(!place.has_postcode("SW1 2A1"));
+        assert!(!place.has_postcode("SW12 2AB"));
+        assert!(place.has_postcode(&place.random_postcode()));
+    }
+}
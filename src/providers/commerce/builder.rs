@@ -0,0 +1,60 @@
+use crate::errors::FakeLakeError;
+use crate::providers::provider::{unknown_provider, Provider};
+
+use super::{price, product, sku};
+
+use yaml_rust::Yaml;
+
+const AVAILABLE: &[&str] = &[
+    "commerce.category",
+    "commerce.price",
+    "commerce.product_name",
+    "commerce.sku",
+];
+
+pub fn get_corresponding_provider(
+    mut provider_split: std::str::Split<'_, char>,
+    column: &Yaml,
+) -> Result<Box<dyn Provider>, FakeLakeError> {
+    match provider_split.next() {
+        Some("category") => Ok(product::new_category_from_yaml(column)?),
+        Some("price") => Ok(price::new_from_yaml(column)?),
+        Some("product_name") => Ok(product::new_from_yaml(column)),
+        Some("sku") => Ok(sku::new_from_yaml(column)?),
+        other => Err(unknown_provider("commerce", other, AVAILABLE)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::get_corresponding_provider;
+
+    use yaml_rust::YamlLoader;
+
+    #[test]
+    fn given_commerce_providers_should_return_provider() {
+        for provider_name in ["category", "price", "product_name", "sku"] {
+            let yaml_str = format!("name: commerce{}provider: {}", '\n', provider_name);
+            let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+            let provider_split = provider_name.split('.');
+            match get_corresponding_provider(provider_split, column) {
+                Ok(_) => (),
+                _ => panic!("{} should return a provider", provider_name),
+            }
+        }
+    }
+
+    #[test]
+    fn given_wrong_provider_should_return_error() {
+        let provider_name = "not_a_provider";
+        let yaml_str = format!("name: commerce{}provider: {}", '\n', provider_name);
+        let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+        let provider_split = provider_name.split('.');
+        match get_corresponding_provider(provider_split, column) {
+            Err(_) => (),
+            _ => panic!(),
+        }
+    }
+}
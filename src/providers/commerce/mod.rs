@@ -0,0 +1,5 @@
+pub mod builder;
+
+pub mod price;
+pub mod product;
+pub mod sku;
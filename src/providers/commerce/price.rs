@@ -0,0 +1,214 @@
+use crate::errors::FakeLakeError;
+use crate::providers::parameters::f64::F64Parameter;
+use crate::providers::parameters::get_column_name;
+use crate::providers::parameters::locale::{Locale, LocaleParameter};
+use crate::providers::parameters::wstring::{weighted_random, WStringParameter};
+use crate::providers::provider::{Provider, Value};
+use crate::rng;
+
+use yaml_rust::Yaml;
+
+/// Endings of the prices of a locale and their weights, like 9.99 or 1980 yen
+fn default_endings(locale: Locale) -> &'static [(&'static str, u32)] {
+    match locale {
+        Locale::EnGB | Locale::EnUS => &[("0.99", 50), ("0.49", 15), ("0.95", 15), ("0", 20)],
+        Locale::JaJP => &[("80", 45), ("98", 25), ("0", 30)],
+        Locale::ZhCN => &[("0.9", 35), ("0.8", 20), ("0.99", 20), ("0", 25)],
+        _ => &[
+            ("0.99", 40),
+            ("0.9", 20),
+            ("0.95", 10),
+            ("0.5", 10),
+            ("0", 20),
+        ],
+    }
+}
+
+/// Prices of a locale are a multiple of the unit plus an ending
+fn unit(locale: Locale) -> f64 {
+    match locale {
+        Locale::JaJP => 100.0,
+        _ => 1.0,
+    }
+}
+
+fn default_range(locale: Locale) -> (f64, f64) {
+    match locale {
+        Locale::JaJP => (100.0, 10000.0),
+        _ => (1.0, 100.0),
+    }
+}
+
+fn round(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+#[derive(Clone)]
+pub struct PriceProvider {
+    pub min: f64,
+    pub max: f64,
+    pub unit: f64,
+    /// What is added to a multiple of the unit, as strings of numbers
+    pub endings: Vec<WStringParameter>,
+}
+
+impl PriceProvider {
+    fn price(&self) -> f64 {
+        let ending: f64 = weighted_random(&self.endings).parse().unwrap();
+        // multiples of the unit whose price with the ending is between the min and the max
+        let first = ((self.min - ending) / self.unit).ceil() as i64;
+        let last = ((self.max - ending) / self.unit).ceil() as i64 - 1;
+        round(rng::i64(first..last + 1) as f64 * self.unit + ending)
+    }
+}
+
+impl Provider for PriceProvider {
+    fn value(&self, _: u32) -> Value {
+        Value::Float64(self.price())
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        // negative prices
+        Value::Float64(-self.price())
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml) -> Result<Box<PriceProvider>, FakeLakeError> {
+    let column_name = get_column_name(column);
+    let locale = LocaleParameter::new(column).value;
+    let unit = unit(locale);
+
+    let (default_min, default_max) = default_range(locale);
+    let min = F64Parameter::from_yaml(column, "min", default_min)?.value;
+    let max = F64Parameter::from_yaml(column, "max", default_max)?.value;
+    if min < 0.0 || max - min < unit {
+        return Err(FakeLakeError::BadYAMLFormat(format!(
+            "Column {} param min should be positive and at least {} less than max.",
+            column_name, unit
+        )));
+    }
+
+    let endings = match &column["endings"] {
+        Yaml::BadValue => default_endings(locale)
+            .iter()
+            .map(|(ending, weight)| WStringParameter {
+                value: ending.to_string(),
+                weight: *weight,
+            })
+            .collect(),
+        _ => WStringParameter::new(column, "endings", "0"),
+    };
+    for ending in &endings {
+        match ending.value.parse::<f64>() {
+            Ok(value) if (0.0..unit).contains(&value) => (),
+            _ => {
+                return Err(FakeLakeError::BadYAMLFormat(format!(
+                    "Column {} param endings should be numbers between 0 and {}, not {}.",
+                    column_name, unit, ending.value
+                )))
+            }
+        }
+    }
+
+    Ok(Box::new(PriceProvider {
+        min,
+        max,
+        unit,
+        endings,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_from_yaml, PriceProvider};
+    use crate::providers::provider::{Provider, Value};
+
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider(yaml_str: &str) -> Box<PriceProvider> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        new_from_yaml(&yaml[0]).unwrap()
+    }
+
+    fn float_value(value: Value) -> f64 {
+        match value {
+            Value::Float64(value) => value,
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    fn cents(value: f64) -> i64 {
+        (value * 100.0).round() as i64 % 100
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_no_params_should_use_defaults_of_locale() {
+        let provider = generate_provider("name: price\nlocale: en_US");
+        assert_eq!(
+            (provider.min, provider.max, provider.unit),
+            (1.0, 100.0, 1.0)
+        );
+        assert_eq!(provider.endings[0].value, "0.99");
+
+        let provider = generate_provider("name: price\nlocale: ja_JP");
+        assert_eq!(
+            (provider.min, provider.max, provider.unit),
+            (100.0, 10000.0, 100.0)
+        );
+    }
+
+    #[test]
+    fn given_wrong_params_should_return_err() {
+        for params in [
+            "min: a",
+            "min: -1",
+            "min: 10\nmax: 5",
+            "min: 10\nmax: 10.5",
+            "endings: [0.99, 1.5]",
+            "endings: a",
+        ] {
+            let yaml = YamlLoader::load_from_str(&format!("name: price\n{}", params)).unwrap();
+            assert!(
+                new_from_yaml(&yaml[0]).is_err(),
+                "{} should be wrong",
+                params
+            );
+        }
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_locale_should_return_price_points_of_locale() {
+        let provider = generate_provider("name: price\nlocale: en_US\nmin: 5\nmax: 50");
+        for i in 0..200 {
+            let value = float_value(provider.value(i));
+            assert!((5.0..50.0).contains(&value), "{}", value);
+            assert!([99, 49, 95, 0].contains(&cents(value)), "{}", value);
+        }
+
+        let provider = generate_provider("name: price\nlocale: ja_JP");
+        for i in 0..200 {
+            let value = float_value(provider.value(i));
+            assert!((100.0..10000.0).contains(&value), "{}", value);
+            assert_eq!(value.fract(), 0.0);
+            assert!([80, 98, 0].contains(&(value as i64 % 100)), "{}", value);
+        }
+    }
+
+    #[test]
+    fn given_endings_should_use_them() {
+        let provider = generate_provider("name: price\nmin: 1.5\nmax: 2.5\nendings: [0.99, 0.49]");
+        for i in 0..100 {
+            let value = float_value(provider.value(i));
+            assert!(value == 1.99 || value == 2.49, "{}", value);
+        }
+    }
+
+    #[test]
+    fn given_corrupted_should_return_negative_prices() {
+        let provider = generate_provider("name: price");
+        for i in 0..50 {
+            assert!(float_value(provider.corrupted_value(i)) < 0.0);
+        }
+    }
+}
@@ -0,0 +1,311 @@
+use crate::errors::FakeLakeError;
+use crate::providers::parameters::get_column_name;
+use crate::providers::provider::{Provider, Value};
+use crate::providers::utils::string::{pick, random_alphanumeric};
+use crate::rng;
+
+use yaml_rust::Yaml;
+
+/// Department of a store, with the code starting its SKUs, its categories and its products
+pub struct Department {
+    pub name: &'static str,
+    pub code: &'static str,
+    pub categories: &'static [&'static str],
+    pub products: &'static [&'static str],
+}
+
+pub const DEPARTMENTS: &[Department] = &[
+    Department {
+        name: "Beauty",
+        code: "BEA",
+        categories: &["Fragrance", "Hair Care", "Makeup", "Skin Care"],
+        products: &["Face Cream", "Lipstick", "Perfume", "Shampoo", "Serum"],
+    },
+    Department {
+        name: "Books",
+        code: "BOO",
+        categories: &["Children", "Comics", "Cooking", "Fiction", "Travel"],
+        products: &["Cookbook", "Guide", "Notebook", "Novel", "Picture Book"],
+    },
+    Department {
+        name: "Clothing",
+        code: "CLO",
+        categories: &["Accessories", "Kids", "Men", "Shoes", "Women"],
+        products: &[
+            "Coat", "Dress", "Jacket", "Jeans", "Scarf", "Shirt", "Sneakers", "Sweater",
+        ],
+    },
+    Department {
+        name: "Electronics",
+        code: "ELE",
+        categories: &["Audio", "Cameras", "Computers", "Phones", "TV & Video"],
+        products: &[
+            "Camera",
+            "Headphones",
+            "Keyboard",
+            "Laptop",
+            "Monitor",
+            "Mouse",
+            "Speaker",
+            "Tablet",
+        ],
+    },
+    Department {
+        name: "Garden",
+        code: "GAR",
+        categories: &["Furniture", "Plants", "Tools", "Watering"],
+        products: &["Bench", "Hose", "Lawn Mower", "Planter", "Shovel"],
+    },
+    Department {
+        name: "Grocery",
+        code: "GRO",
+        categories: &["Bakery", "Beverages", "Dairy", "Pantry", "Snacks"],
+        products: &[
+            "Chocolate",
+            "Coffee",
+            "Cookies",
+            "Juice",
+            "Olive Oil",
+            "Pasta",
+            "Tea",
+        ],
+    },
+    Department {
+        name: "Home",
+        code: "HOM",
+        categories: &["Bedding", "Decor", "Furniture", "Kitchen", "Lighting"],
+        products: &[
+            "Blanket", "Chair", "Lamp", "Mug", "Pan", "Pillow", "Table", "Vase",
+        ],
+    },
+    Department {
+        name: "Sports",
+        code: "SPO",
+        categories: &["Camping", "Cycling", "Fitness", "Running", "Team Sports"],
+        products: &[
+            "Backpack", "Ball", "Bottle", "Dumbbell", "Helmet", "Tent", "Yoga Mat",
+        ],
+    },
+    Department {
+        name: "Toys",
+        code: "TOY",
+        categories: &["Board Games", "Dolls", "Outdoor", "Puzzles", "Vehicles"],
+        products: &[
+            "Board Game",
+            "Building Set",
+            "Doll",
+            "Kite",
+            "Puzzle",
+            "Toy Car",
+        ],
+    },
+];
+
+const ADJECTIVES: &[&str] = &[
+    "Classic",
+    "Compact",
+    "Deluxe",
+    "Durable",
+    "Elegant",
+    "Ergonomic",
+    "Essential",
+    "Handmade",
+    "Lightweight",
+    "Modern",
+    "Organic",
+    "Portable",
+    "Premium",
+    "Recycled",
+    "Rustic",
+    "Sleek",
+    "Smart",
+    "Vintage",
+];
+const MATERIALS: &[&str] = &[
+    "Bamboo", "Ceramic", "Cotton", "Glass", "Leather", "Linen", "Steel", "Wooden", "Wool",
+];
+
+pub fn random_department() -> &'static Department {
+    &DEPARTMENTS[rng::usize(..DEPARTMENTS.len())]
+}
+
+/// Product name made of an adjective, sometimes a material, and a product of a department
+pub fn product_name() -> String {
+    let product = pick(random_department().products);
+    match rng::u32(0..3) {
+        0 => format!("{} {} {}", pick(ADJECTIVES), pick(MATERIALS), product),
+        _ => format!("{} {}", pick(ADJECTIVES), product),
+    }
+}
+
+/// Level of the category in the tree of departments
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Level {
+    Department,
+    Category,
+    /// Department and category, like Electronics > Audio
+    Path,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Kind {
+    ProductName,
+    Category(Level),
+}
+
+#[derive(Clone)]
+pub struct ProductProvider {
+    pub kind: Kind,
+}
+
+impl Provider for ProductProvider {
+    fn value(&self, _: u32) -> Value {
+        Value::String(match self.kind {
+            Kind::ProductName => product_name(),
+            Kind::Category(level) => {
+                let department = random_department();
+                match level {
+                    Level::Department => department.name.to_string(),
+                    Level::Category => pick(department.categories).to_string(),
+                    Level::Path => format!("{} > {}", department.name, pick(department.categories)),
+                }
+            }
+        })
+    }
+    fn corrupted_value(&self, index: u32) -> Value {
+        // random characters instead of words
+        match self.value(index) {
+            Value::String(value) => {
+                Value::String(random_alphanumeric(value.chars().count() as u32))
+            }
+            _ => panic!("Product values should be strings"),
+        }
+    }
+}
+
+pub fn new_from_yaml(_: &Yaml) -> Box<ProductProvider> {
+    Box::new(ProductProvider {
+        kind: Kind::ProductName,
+    })
+}
+
+pub fn new_category_from_yaml(column: &Yaml) -> Result<Box<ProductProvider>, FakeLakeError> {
+    let level = match column["level"].as_str() {
+        None if column["level"].is_badvalue() => Level::Category,
+        Some("department") => Level::Department,
+        Some("category") => Level::Category,
+        Some("path") => Level::Path,
+        _ => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} param level should be department, category or path.",
+                get_column_name(column)
+            )))
+        }
+    };
+    Ok(Box::new(ProductProvider {
+        kind: Kind::Category(level),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_category_from_yaml, new_from_yaml, Kind, Level, ProductProvider, DEPARTMENTS};
+    use crate::providers::provider::{Provider, Value};
+
+    use std::collections::HashSet;
+    use yaml_rust::YamlLoader;
+
+    fn generate_category_provider(yaml_str: &str) -> Box<ProductProvider> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        new_category_from_yaml(&yaml[0]).unwrap()
+    }
+
+    fn string_value(value: Value) -> String {
+        match value {
+            Value::String(value) => value,
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_level_should_use_it() {
+        for (level, expected) in [
+            ("", Level::Category),
+            ("\nlevel: department", Level::Department),
+            ("\nlevel: category", Level::Category),
+            ("\nlevel: path", Level::Path),
+        ] {
+            let provider = generate_category_provider(&format!("name: category{}", level));
+            assert_eq!(provider.kind, Kind::Category(expected));
+        }
+    }
+
+    #[test]
+    fn given_wrong_level_should_return_err() {
+        for level in ["level: product", "level: 1"] {
+            let yaml = YamlLoader::load_from_str(&format!("name: category\n{}", level)).unwrap();
+            assert!(new_category_from_yaml(&yaml[0]).is_err());
+        }
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_departments_should_have_unique_codes() {
+        let codes: HashSet<&str> = DEPARTMENTS
+            .iter()
+            .map(|department| department.code)
+            .collect();
+        assert_eq!(codes.len(), DEPARTMENTS.len());
+        for department in DEPARTMENTS {
+            assert_eq!(department.code.len(), 3);
+        }
+    }
+
+    #[test]
+    fn given_product_name_should_end_with_product() {
+        let yaml = YamlLoader::load_from_str("name: product").unwrap();
+        let provider = new_from_yaml(&yaml[0]);
+        for i in 0..100 {
+            let value = string_value(provider.value(i));
+            assert!(
+                DEPARTMENTS.iter().any(|department| department
+                    .products
+                    .iter()
+                    .any(|product| value.ends_with(&format!(" {}", product)))),
+                "{}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn given_levels_should_return_categories_of_level() {
+        let provider = generate_category_provider("name: category\nlevel: department");
+        for i in 0..50 {
+            let value = string_value(provider.value(i));
+            assert!(DEPARTMENTS
+                .iter()
+                .any(|department| department.name == value));
+        }
+
+        let provider = generate_category_provider("name: category\nlevel: path");
+        for i in 0..50 {
+            let value = string_value(provider.value(i));
+            let (name, category) = value.split_once(" > ").unwrap();
+            let department = DEPARTMENTS.iter().find(|d| d.name == name).unwrap();
+            assert!(department.categories.contains(&category));
+        }
+    }
+
+    #[test]
+    fn given_corrupted_should_return_random_characters() {
+        let provider = generate_category_provider("name: category\nlevel: department");
+        for i in 0..50 {
+            let value = string_value(provider.corrupted_value(i));
+            assert!(!DEPARTMENTS
+                .iter()
+                .any(|department| department.name == value));
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use crate::errors::FakeLakeError;
+use crate::providers::parameters::get_column_name;
+use crate::providers::provider::{Provider, Value};
+use crate::rng;
+
+use super::product::random_department;
+
+use yaml_rust::Yaml;
+
+const DEFAULT_DIGITS: u32 = 6;
+const MAX_DIGITS: u32 = 10;
+
+#[derive(Clone)]
+pub struct SkuProvider {
+    /// Digits of the number following the department code
+    pub digits: u32,
+}
+
+impl SkuProvider {
+    fn sku(&self, index: u32) -> String {
+        // the index makes the SKUs unique
+        format!(
+            "{}-{:0width$}",
+            random_department().code,
+            index,
+            width = self.digits as usize
+        )
+    }
+}
+
+impl Provider for SkuProvider {
+    fn value(&self, index: u32) -> Value {
+        Value::String(self.sku(index))
+    }
+    fn corrupted_value(&self, index: u32) -> Value {
+        // lowercase SKUs or SKUs without their dash
+        let sku = self.sku(index);
+        Value::String(match rng::bool() {
+            true => sku.to_lowercase(),
+            false => sku.replace('-', ""),
+        })
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml) -> Result<Box<SkuProvider>, FakeLakeError> {
+    let digits = match &column["digits"] {
+        Yaml::BadValue => DEFAULT_DIGITS,
+        Yaml::Integer(value) if (1..=MAX_DIGITS as i64).contains(value) => *value as u32,
+        _ => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} param digits should be an integer between 1 and {}.",
+                get_column_name(column),
+                MAX_DIGITS
+            )))
+        }
+    };
+    Ok(Box::new(SkuProvider { digits }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_from_yaml, SkuProvider};
+    use crate::providers::provider::{Provider, Value};
+
+    use regex::Regex;
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider(yaml_str: &str) -> Box<SkuProvider> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        new_from_yaml(&yaml[0]).unwrap()
+    }
+
+    fn string_value(value: Value) -> String {
+        match value {
+            Value::String(value) => value,
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_digits_should_use_them() {
+        assert_eq!(generate_provider("name: sku").digits, 6);
+        assert_eq!(generate_provider("name: sku\ndigits: 8").digits, 8);
+    }
+
+    #[test]
+    fn given_wrong_digits_should_return_err() {
+        for digits in ["0", "11", "a"] {
+            let yaml =
+                YamlLoader::load_from_str(&format!("name: sku\ndigits: {}", digits)).unwrap();
+            assert!(new_from_yaml(&yaml[0]).is_err());
+        }
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_index_should_return_unique_skus() {
+        let provider = generate_provider("name: sku\ndigits: 4");
+        let re = Regex::new(r"^[A-Z]{3}-\d{4}$").unwrap();
+        for i in 0..100 {
+            let value = string_value(provider.value(i));
+            assert!(re.is_match(&value), "{}", value);
+            assert!(value.ends_with(&format!("{:04}", i)));
+        }
+    }
+
+    #[test]
+    fn given_corrupted_should_return_malformed_skus() {
+        let provider = generate_provider("name: sku");
+        let re = Regex::new(r"^[A-Z]{3}-\d{6}$").unwrap();
+        for i in 0..50 {
+            assert!(!re.is_match(&string_value(provider.corrupted_value(i))));
+        }
+    }
+}
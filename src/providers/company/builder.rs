@@ -0,0 +1,54 @@
+use crate::errors::FakeLakeError;
+use crate::providers::provider::{unknown_provider, Provider};
+
+use super::company;
+
+use yaml_rust::Yaml;
+
+const AVAILABLE: &[&str] = &["company.industry", "company.job_title", "company.name"];
+
+pub fn get_corresponding_provider(
+    mut provider_split: std::str::Split<'_, char>,
+    column: &Yaml,
+) -> Result<Box<dyn Provider>, FakeLakeError> {
+    match provider_split.next() {
+        Some("industry") => Ok(company::new_from_yaml(column, company::Kind::Industry)),
+        Some("job_title") => Ok(company::new_from_yaml(column, company::Kind::JobTitle)),
+        Some("name") => Ok(company::new_from_yaml(column, company::Kind::Name)),
+        other => Err(unknown_provider("company", other, AVAILABLE)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::get_corresponding_provider;
+
+    use yaml_rust::YamlLoader;
+
+    #[test]
+    fn given_company_providers_should_return_provider() {
+        for provider_name in ["industry", "job_title", "name"] {
+            let yaml_str = format!("name: company{}provider: {}", '\n', provider_name);
+            let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+            let provider_split = provider_name.split('.');
+            match get_corresponding_provider(provider_split, column) {
+                Ok(_) => (),
+                _ => panic!("{} should return a provider", provider_name),
+            }
+        }
+    }
+
+    #[test]
+    fn given_wrong_provider_should_return_error() {
+        let provider_name = "not_a_provider";
+        let yaml_str = format!("name: company{}provider: {}", '\n', provider_name);
+        let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+        let provider_split = provider_name.split('.');
+        match get_corresponding_provider(provider_split, column) {
+            Err(_) => (),
+            _ => panic!(),
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use crate::providers::parameters::locale::{Locale, LocaleParameter};
+use crate::providers::person::names;
+use crate::providers::provider::{Provider, Value};
+use crate::providers::utils::string::{pick, random_alphanumeric};
+use crate::rng;
+
+use yaml_rust::Yaml;
+
+const INDUSTRIES: &[&str] = &[
+    "Aerospace & Defense",
+    "Agriculture",
+    "Automotive",
+    "Banking",
+    "Biotechnology",
+    "Chemicals",
+    "Construction",
+    "Consulting",
+    "Consumer Electronics",
+    "E-commerce",
+    "Education",
+    "Energy",
+    "Entertainment",
+    "Food & Beverage",
+    "Healthcare",
+    "Hospitality",
+    "Insurance",
+    "Logistics",
+    "Manufacturing",
+    "Media",
+    "Mining",
+    "Pharmaceuticals",
+    "Real Estate",
+    "Retail",
+    "Software",
+    "Telecommunications",
+    "Textiles",
+    "Transportation",
+    "Utilities",
+    "Video Games",
+];
+
+/// Seniorities of a job, the most common ones being repeated
+const SENIORITIES: &[&str] = &[
+    "",
+    "",
+    "",
+    "Junior ",
+    "Senior ",
+    "Senior ",
+    "Lead ",
+    "Principal ",
+];
+const FIELDS: &[&str] = &[
+    "Accounting",
+    "Brand",
+    "Business",
+    "Customer Success",
+    "Data",
+    "Finance",
+    "Human Resources",
+    "Legal",
+    "Logistics",
+    "Marketing",
+    "Operations",
+    "Procurement",
+    "Product",
+    "Quality",
+    "Sales",
+    "Security",
+    "Software",
+    "Supply Chain",
+];
+const ROLES: &[&str] = &[
+    "Analyst",
+    "Assistant",
+    "Consultant",
+    "Coordinator",
+    "Designer",
+    "Engineer",
+    "Manager",
+    "Officer",
+    "Specialist",
+];
+/// Titles of the managers of a field
+const HEADS: &[&str] = &["Head of ", "Director of ", "VP of ", "Chief of "];
+
+/// Legal forms of the companies of a locale
+fn legal_forms(locale: Locale) -> &'static [&'static str] {
+    match locale {
+        Locale::DeDE => &["GmbH", "GmbH", "AG", "KG", "GmbH & Co. KG"],
+        Locale::EnGB => &["Ltd", "Ltd", "PLC", "LLP"],
+        Locale::EnUS => &["Inc.", "Inc.", "LLC", "Corp.", "Group"],
+        Locale::EsES => &["S.L.", "S.L.", "S.A."],
+        Locale::FrFR => &["SAS", "SAS", "SARL", "SA"],
+        Locale::ItIT => &["S.r.l.", "S.r.l.", "S.p.A."],
+        Locale::JaJP => &["商事", "工業", "電機", "製作所", "物産"],
+        Locale::PtBR => &["Ltda.", "Ltda.", "S.A."],
+        Locale::ZhCN => &["科技", "贸易", "实业", "电子", "物流"],
+    }
+}
+
+/// Company name made of last names of the locale and a legal form
+pub fn company_name(locale: Locale) -> String {
+    let last_names = names::last_names(locale);
+    let last_name = || last_names[rng::usize(..last_names.len())].as_str();
+    let legal_form = pick(legal_forms(locale));
+    match locale {
+        // 株式会社 is written before or after the name
+        Locale::JaJP => match rng::bool() {
+            true => format!("株式会社{}{}", last_name(), legal_form),
+            false => format!("{}{}株式会社", last_name(), legal_form),
+        },
+        Locale::ZhCN => format!("{}氏{}有限公司", last_name(), legal_form),
+        _ => match rng::u32(0..4) {
+            0 => format!("{} & {} {}", last_name(), last_name(), legal_form),
+            1 => format!("{}-{} {}", last_name(), last_name(), legal_form),
+            _ => format!("{} {}", last_name(), legal_form),
+        },
+    }
+}
+
+/// Job title like Senior Data Engineer or Head of Marketing
+pub fn job_title() -> String {
+    match rng::u32(0..8) {
+        0 => format!("{}{}", pick(HEADS), pick(FIELDS)),
+        _ => format!("{}{} {}", pick(SENIORITIES), pick(FIELDS), pick(ROLES)),
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Kind {
+    Name,
+    Industry,
+    JobTitle,
+}
+
+#[derive(Clone)]
+pub struct CompanyProvider {
+    pub kind: Kind,
+    pub locale: Locale,
+}
+
+impl Provider for CompanyProvider {
+    fn value(&self, _: u32) -> Value {
+        Value::String(match self.kind {
+            Kind::Name => company_name(self.locale),
+            Kind::Industry => pick(INDUSTRIES).to_string(),
+            Kind::JobTitle => job_title(),
+        })
+    }
+    fn corrupted_value(&self, index: u32) -> Value {
+        // random characters instead of words
+        match self.value(index) {
+            Value::String(value) => {
+                Value::String(random_alphanumeric(value.chars().count() as u32))
+            }
+            _ => panic!("Company values should be strings"),
+        }
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml, kind: Kind) -> Box<CompanyProvider> {
+    Box::new(CompanyProvider {
+        kind,
+        locale: LocaleParameter::new(column).value,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_from_yaml, CompanyProvider, Kind, INDUSTRIES};
+    use crate::providers::parameters::locale::Locale;
+    use crate::providers::person::names;
+    use crate::providers::provider::{Provider, Value};
+
+    use regex::Regex;
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider(yaml_str: &str, kind: Kind) -> Box<CompanyProvider> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        new_from_yaml(&yaml[0], kind)
+    }
+
+    fn string_value(value: Value) -> String {
+        match value {
+            Value::String(value) => value,
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_locale_should_use_it() {
+        let provider = generate_provider("name: company\nlocale: de_DE", Kind::Name);
+        assert_eq!(provider.locale, Locale::DeDE);
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_locale_should_return_names_of_locale() {
+        let provider = generate_provider("name: company\nlocale: de_DE", Kind::Name);
+        let last_names = names::last_names(Locale::DeDE);
+        for i in 0..100 {
+            let value = string_value(provider.value(i));
+            let (name, _) = value
+                .split_once(" GmbH")
+                .or_else(|| value.split_once(" AG"))
+                .or_else(|| value.split_once(" KG"))
+                .unwrap_or_else(|| panic!("{} has no legal form", value));
+            assert!(
+                name.split([' ', '-', '&'])
+                    .filter(|part| !part.is_empty())
+                    .all(|part| last_names.iter().any(|last_name| last_name.contains(part))),
+                "{}",
+                value
+            );
+        }
+
+        let provider = generate_provider("name: company\nlocale: ja_JP", Kind::Name);
+        for i in 0..20 {
+            assert!(string_value(provider.value(i)).contains("株式会社"));
+        }
+    }
+
+    #[test]
+    fn given_industry_should_return_one_of_them() {
+        let provider = generate_provider("name: industry", Kind::Industry);
+        for i in 0..50 {
+            assert!(INDUSTRIES.contains(&string_value(provider.value(i)).as_str()));
+        }
+    }
+
+    #[test]
+    fn given_job_title_should_return_titles() {
+        let provider = generate_provider("name: job", Kind::JobTitle);
+        let re = Regex::new(r"^((Junior|Senior|Lead|Principal) )?[A-Z][A-Za-z ]+$").unwrap();
+        for i in 0..50 {
+            let value = string_value(provider.value(i));
+            assert!(re.is_match(&value), "{}", value);
+        }
+    }
+
+    #[test]
+    fn given_corrupted_should_return_random_characters() {
+        let provider = generate_provider("name: industry", Kind::Industry);
+        for i in 0..50 {
+            let value = string_value(provider.corrupted_value(i));
+            assert!(!INDUSTRIES.contains(&value.as_str()));
+        }
+    }
+}
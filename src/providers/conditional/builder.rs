@@ -0,0 +1,57 @@
+use yaml_rust::Yaml;
+
+use crate::errors::FakeLakeError;
+use crate::providers::provider::{unknown_provider, Provider};
+
+use super::conditional;
+
+const AVAILABLE: &[&str] = &["conditional"];
+
+pub fn get_corresponding_provider(
+    mut provider_split: std::str::Split<'_, char>,
+    column: &Yaml,
+) -> Result<Box<dyn Provider>, FakeLakeError> {
+    match provider_split.next() {
+        None => conditional::new_from_yaml(column),
+        other => Err(unknown_provider("conditional", other, AVAILABLE)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::get_corresponding_provider;
+
+    use yaml_rust::YamlLoader;
+
+    #[test]
+    fn given_conditional_should_return_provider() {
+        let yaml_str = "
+        name: refund_amount
+        provider: Conditional
+        when:
+            - condition: status == 'refunded'
+              provider: Random.Number.f64
+        ";
+        let column = &YamlLoader::load_from_str(yaml_str).unwrap()[0];
+
+        let mut provider_split = "conditional".split('.');
+        provider_split.next();
+        match get_corresponding_provider(provider_split, column) {
+            Ok(_) => (),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn given_wrong_provider_should_return_error() {
+        let yaml_str = "name: refund_amount\nprovider: Conditional.not_a_provider";
+        let column = &YamlLoader::load_from_str(yaml_str).unwrap()[0];
+
+        let mut provider_split = "conditional.not_a_provider".split('.');
+        provider_split.next();
+        match get_corresponding_provider(provider_split, column) {
+            Err(_) => (),
+            _ => panic!(),
+        }
+    }
+}
@@ -0,0 +1,448 @@
+use crate::errors::FakeLakeError;
+use crate::providers::expression::evaluation::{self, Type};
+use crate::providers::expression::expression::parse_parameter;
+use crate::providers::expression::parser::Expr;
+use crate::providers::parameters::get_column_name;
+use crate::providers::provider::{ColumnType, CorruptedProvider, Provider, ProviderBuilder, Value};
+use crate::rng;
+
+use std::collections::HashMap;
+use yaml_rust::Yaml;
+
+pub struct Branch {
+    pub condition: Expr,
+    pub provider: Box<dyn Provider>,
+}
+
+impl Clone for Branch {
+    fn clone(&self) -> Self {
+        Branch {
+            condition: self.condition.clone(),
+            provider: self.provider.clone_box(),
+        }
+    }
+}
+
+pub struct ConditionalProvider {
+    pub branches: Vec<Branch>,
+    /// Provider used when no condition is true. The value is null without it.
+    pub otherwise: Option<Box<dyn Provider>>,
+}
+
+impl Clone for ConditionalProvider {
+    fn clone(&self) -> Self {
+        ConditionalProvider {
+            branches: self.branches.clone(),
+            otherwise: self.otherwise.as_ref().map(|provider| provider.clone_box()),
+        }
+    }
+}
+
+impl ConditionalProvider {
+    /// Position of the first branch whose condition is true in the current row,
+    /// the number of branches meaning otherwise
+    fn matching_branch(&self) -> usize {
+        self.branches
+            .iter()
+            .position(|branch| evaluation::evaluate(&branch.condition) == Value::Bool(true))
+            .unwrap_or(self.branches.len())
+    }
+
+    fn branch_value(&self, position: usize, index: u32) -> Value {
+        match self.branches.get(position) {
+            Some(branch) => branch.provider.value(index),
+            None => match &self.otherwise {
+                Some(provider) => provider.value(index),
+                None => Value::Null,
+            },
+        }
+    }
+
+    fn providers(&self) -> impl Iterator<Item = &Box<dyn Provider>> {
+        self.branches
+            .iter()
+            .map(|branch| &branch.provider)
+            .chain(self.otherwise.iter())
+    }
+}
+
+impl Provider for ConditionalProvider {
+    fn value(&self, index: u32) -> Value {
+        self.branch_value(self.matching_branch(), index)
+    }
+    fn corrupted_value(&self, index: u32) -> Value {
+        // use another branch than the one matching the row
+        let matching = self.matching_branch();
+        let mut position = rng::usize(..self.branches.len());
+        if position >= matching {
+            position += 1;
+        }
+        self.branch_value(position, index)
+    }
+    fn sample_value(&self) -> Value {
+        self.branches[0].provider.sample_value()
+    }
+    fn dependencies(&self) -> Vec<String> {
+        let mut dependencies: Vec<String> = Vec::new();
+        let columns = self
+            .branches
+            .iter()
+            .flat_map(|branch| branch.condition.columns())
+            .chain(
+                self.providers()
+                    .flat_map(|provider| provider.dependencies()),
+            );
+        for column in columns {
+            if !dependencies.contains(&column) {
+                dependencies.push(column);
+            }
+        }
+        dependencies
+    }
+    fn bind(&mut self, columns: &HashMap<String, ColumnType>) -> Result<(), FakeLakeError> {
+        for branch in self.branches.iter_mut() {
+            let condition = match evaluation::check(branch.condition.clone(), columns) {
+                Ok((condition, Type::Bool)) => condition,
+                Ok((_, condition_type)) => {
+                    return Err(FakeLakeError::BadYAMLFormat(format!(
+                        "condition should be a bool, got {}.",
+                        condition_type.name()
+                    )))
+                }
+                Err(e) => return Err(FakeLakeError::BadYAMLFormat(e)),
+            };
+            branch.condition = condition;
+            branch.provider.bind(columns)?;
+        }
+        if let Some(provider) = self.otherwise.as_mut() {
+            provider.bind(columns)?;
+        }
+
+        let samples: Vec<Value> = self
+            .providers()
+            .map(|provider| provider.sample_value())
+            .collect();
+        for sample in &samples[1..] {
+            if !same_type(&samples[0], sample) {
+                return Err(FakeLakeError::BadYAMLFormat(format!(
+                    "every provider should give values of the same type, got {} and {}.",
+                    type_name(&samples[0]),
+                    type_name(sample)
+                )));
+            }
+        }
+        Ok(())
+    }
+    fn can_be_null(&self) -> bool {
+        self.otherwise.is_none() || self.providers().any(|provider| provider.can_be_null())
+    }
+    fn max_rows(&self) -> Option<u32> {
+        self.providers()
+            .filter_map(|provider| provider.max_rows())
+            .min()
+    }
+}
+
+/// Whether two values are written with the same type, timestamps needing the same
+/// precision and timezone and bytes the same size of offsets
+fn same_type(left: &Value, right: &Value) -> bool {
+    match (left, right) {
+        (Value::Timestamp(_, _, left), Value::Timestamp(_, _, right)) => left == right,
+        (Value::Bytes(_, left), Value::Bytes(_, right)) => left == right,
+        _ => std::mem::discriminant(left) == std::mem::discriminant(right),
+    }
+}
+
+fn type_name(value: &Value) -> String {
+    match value {
+        Value::Bool(_) => "bool".to_string(),
+        Value::Int32(_) => "integer".to_string(),
+        Value::Float64(_) => "float".to_string(),
+        Value::String(_) => "string".to_string(),
+        Value::Date(_, _) => "date".to_string(),
+        Value::Timestamp(_, _, timestamp_type) => format!(
+            "timestamp ({:?}, {})",
+            timestamp_type.precision,
+            timestamp_type
+                .timezone_name()
+                .unwrap_or("no timezone".to_string())
+        ),
+        Value::Time(_, _) => "time".to_string(),
+        Value::Duration(_, _) => "duration".to_string(),
+        Value::Uuid(_) => "uuid".to_string(),
+        Value::Point(_, _) => "point".to_string(),
+        Value::Bytes(_, false) => "bytes".to_string(),
+        Value::Bytes(_, true) => "large bytes".to_string(),
+        Value::Null => "null".to_string(),
+    }
+}
+
+/// Params of the column given to each of its entries, unless the entry has its own
+const INHERITED_PARAMS: [&str; 2] = ["locale", "table_seed"];
+
+/// Give the column name, locale and table seed to a when or otherwise entry, so it is
+/// parsed like a column
+fn named_entry(column: &Yaml, entry: &Yaml) -> Result<Yaml, FakeLakeError> {
+    let column_name = get_column_name(column);
+    let mut entry = match entry {
+        Yaml::Hash(value) => value.clone(),
+        _ => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} should describe each provider with its parameters.",
+                column_name
+            )))
+        }
+    };
+    entry.insert(
+        Yaml::String("name".to_string()),
+        Yaml::String(column_name.to_string()),
+    );
+    for param in INHERITED_PARAMS {
+        let key = Yaml::String(param.to_string());
+        if !column[param].is_badvalue() && !entry.contains_key(&key) {
+            entry.insert(key, column[param].clone());
+        }
+    }
+    Ok(Yaml::Hash(entry))
+}
+
+fn get_entry_provider(entry: &Yaml) -> Result<Box<dyn Provider>, FakeLakeError> {
+    let provider = match entry["provider"].as_str() {
+        Some(value) => value,
+        None => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} should specify a provider for each condition.",
+                get_column_name(entry)
+            )))
+        }
+    };
+
+    let provider = ProviderBuilder::get_corresponding_provider(provider, entry)?;
+    Ok(CorruptedProvider::new_from_yaml(entry, provider))
+}
+
+pub fn new_from_yaml(column: &Yaml) -> Result<Box<dyn Provider>, FakeLakeError> {
+    let column_name = get_column_name(column);
+
+    let entries = match column["when"].as_vec() {
+        Some(value) if !value.is_empty() => value,
+        _ => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} should specify a when list of conditions and providers.",
+                column_name
+            )))
+        }
+    };
+
+    let mut branches = Vec::new();
+    for entry in entries {
+        let entry = named_entry(column, entry)?;
+        branches.push(Branch {
+            condition: parse_parameter(&entry, "condition")?,
+            provider: get_entry_provider(&entry)?,
+        });
+    }
+
+    let otherwise = match &column["otherwise"] {
+        Yaml::BadValue | Yaml::Null => None,
+        entry => Some(get_entry_provider(&named_entry(column, entry)?)?),
+    };
+
+    Ok(Box::new(ConditionalProvider {
+        branches,
+        otherwise,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{named_entry, new_from_yaml};
+    use crate::providers::provider::{ColumnType, Provider, Value};
+    use crate::row;
+
+    use std::collections::HashMap;
+    use yaml_rust::YamlLoader;
+
+    fn status_column() -> HashMap<String, ColumnType> {
+        HashMap::from([(
+            "status".to_string(),
+            ColumnType {
+                sample: Value::String(String::new()),
+                nullable: false,
+            },
+        )])
+    }
+
+    fn generate_provider(yaml_str: &str) -> Box<dyn Provider> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        let mut provider = match new_from_yaml(&yaml[0]) {
+            Ok(provider) => provider,
+            Err(e) => panic!("Provider should be created: {}", e),
+        };
+        match provider.bind(&status_column()) {
+            Ok(_) => provider,
+            Err(e) => panic!("Provider should be bound: {}", e),
+        }
+    }
+
+    fn expecting_err(yaml_str: &str) {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        if let Ok(mut provider) = new_from_yaml(&yaml[0]) {
+            if provider.bind(&status_column()).is_ok() {
+                panic!("Provider should not be created")
+            }
+        }
+    }
+
+    const REFUND: &str = "
+    name: refund_amount
+    when:
+        - condition: status == 'refunded'
+          provider: Constant.string
+          data: refund
+        - condition: status == 'partial'
+          provider: Constant.string
+          data: partial refund
+    ";
+
+    // Validate YAML file
+    #[test]
+    fn given_wrong_params_should_return_err() {
+        let yaml_strs = [
+            "name: a",
+            "name: a\nwhen: []",
+            "name: a\nwhen:\n  - provider: Constant.string",
+            "name: a\nwhen:\n  - condition: status == 'a'",
+            "name: a\nwhen:\n  - condition: status ==\n    provider: Constant.string",
+            "name: a\nwhen:\n  - condition: status\n    provider: Constant.string",
+            "name: a\nwhen:\n  - condition: unknown == 'a'\n    provider: Constant.string",
+            "name: a\nwhen:\n  - condition: status == 'a'\n    provider: Constant.string\notherwise:\n  provider: Increment.integer",
+        ];
+        for yaml_str in yaml_strs {
+            expecting_err(yaml_str);
+        }
+    }
+
+    #[test]
+    fn given_providers_written_with_other_types_should_return_err() {
+        // these values are all read as strings or integers in conditions
+        let yaml_strs = [
+            "name: a\nwhen:\n  - condition: status == 'a'\n    provider: random.date.time\notherwise:\n  provider: Constant.string\n  data: none",
+            "name: a\nwhen:\n  - condition: status == 'a'\n    provider: random.uuid\n    binary: true\notherwise:\n  provider: Constant.string\n  data: none",
+            "name: a\nwhen:\n  - condition: status == 'a'\n    provider: random.bytes\notherwise:\n  provider: random.bytes\n  large: true",
+            "name: a\nwhen:\n  - condition: status == 'a'\n    provider: random.duration\notherwise:\n  provider: Increment.integer",
+            "name: a\nwhen:\n  - condition: status == 'a'\n    provider: random.date.datetime\notherwise:\n  provider: random.date.datetime\n  precision: ms",
+        ];
+        for yaml_str in yaml_strs {
+            let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+            let mut provider = new_from_yaml(&yaml[0]).unwrap();
+            assert!(provider.bind(&status_column()).is_err(), "{}", yaml_str);
+        }
+    }
+
+    #[test]
+    fn given_conditions_should_depend_on_their_columns() {
+        let provider = generate_provider(REFUND);
+        assert_eq!(provider.dependencies(), vec!["status"]);
+    }
+
+    #[test]
+    fn given_no_otherwise_should_be_nullable() {
+        let provider = generate_provider(REFUND);
+        assert!(provider.can_be_null());
+
+        let provider = generate_provider(&format!(
+            "{}\n    otherwise:\n        provider: Constant.string\n        data: none",
+            REFUND
+        ));
+        assert!(!provider.can_be_null());
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_row_should_use_first_true_condition() {
+        let provider = generate_provider(REFUND);
+
+        row::set("status", Some(Value::String("refunded".to_string())));
+        assert_eq!(provider.value(0), Value::String("refund".to_string()));
+
+        row::set("status", Some(Value::String("partial".to_string())));
+        assert_eq!(
+            provider.value(0),
+            Value::String("partial refund".to_string())
+        );
+
+        row::set("status", Some(Value::String("paid".to_string())));
+        assert_eq!(provider.value(0), Value::Null);
+
+        row::set("status", None);
+        assert_eq!(provider.value(0), Value::Null);
+    }
+
+    #[test]
+    fn given_otherwise_should_use_it_when_no_condition_is_true() {
+        let provider = generate_provider(&format!(
+            "{}\n    otherwise:\n        provider: Constant.string\n        data: none",
+            REFUND
+        ));
+
+        row::set("status", Some(Value::String("paid".to_string())));
+        assert_eq!(provider.value(0), Value::String("none".to_string()));
+    }
+
+    #[test]
+    fn given_column_locale_should_be_used_by_branches_without_one() {
+        let provider = generate_provider(
+            "
+    name: iban
+    locale: de_DE
+    table_seed: 42
+    when:
+        - condition: status == 'local'
+          provider: Finance.iban
+        - condition: status == 'french'
+          provider: Finance.iban
+          locale: fr_FR
+    otherwise:
+        provider: Finance.iban
+    ",
+        );
+
+        for (status, country) in [("local", "DE"), ("french", "FR"), ("other", "DE")] {
+            row::set("status", Some(Value::String(status.to_string())));
+            match provider.value(0) {
+                Value::String(value) => assert!(value.starts_with(country), "{}", value),
+                _ => panic!("Wrong type"),
+            }
+        }
+    }
+
+    #[test]
+    fn given_column_params_should_give_them_to_entries() {
+        let column =
+            &YamlLoader::load_from_str("name: a\nlocale: de_DE\ntable_seed: 42").unwrap()[0];
+        let entry = &YamlLoader::load_from_str("provider: Person.fname\nlocale: fr_FR").unwrap()[0];
+        let entry = named_entry(column, entry).unwrap();
+        assert_eq!(entry["name"].as_str(), Some("a"));
+        assert_eq!(entry["locale"].as_str(), Some("fr_FR"));
+        assert_eq!(entry["table_seed"].as_i64(), Some(42));
+    }
+
+    #[test]
+    fn given_row_should_corrupted_use_another_branch() {
+        let provider = generate_provider(REFUND);
+
+        row::set("status", Some(Value::String("refunded".to_string())));
+        for i in 0..100 {
+            assert_ne!(
+                provider.corrupted_value(i),
+                Value::String("refund".to_string())
+            );
+        }
+
+        row::set("status", Some(Value::String("paid".to_string())));
+        for i in 0..100 {
+            assert_ne!(provider.corrupted_value(i), Value::Null);
+        }
+    }
+}
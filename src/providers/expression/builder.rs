@@ -0,0 +1,51 @@
+use yaml_rust::Yaml;
+
+use crate::errors::FakeLakeError;
+use crate::providers::provider::{unknown_provider, Provider};
+
+use super::expression;
+
+const AVAILABLE: &[&str] = &["expression"];
+
+pub fn get_corresponding_provider(
+    mut provider_split: std::str::Split<'_, char>,
+    column: &Yaml,
+) -> Result<Box<dyn Provider>, FakeLakeError> {
+    match provider_split.next() {
+        None => expression::new_from_yaml(column),
+        other => Err(unknown_provider("expression", other, AVAILABLE)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::get_corresponding_provider;
+
+    use yaml_rust::YamlLoader;
+
+    #[test]
+    fn given_expression_should_return_provider() {
+        let yaml_str = "name: total\nprovider: Expression\nexpression: price * quantity";
+        let column = &YamlLoader::load_from_str(yaml_str).unwrap()[0];
+
+        let mut provider_split = "expression".split('.');
+        provider_split.next();
+        match get_corresponding_provider(provider_split, column) {
+            Ok(_) => (),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn given_wrong_provider_should_return_error() {
+        let yaml_str = "name: total\nprovider: Expression.not_a_provider\nexpression: 1";
+        let column = &YamlLoader::load_from_str(yaml_str).unwrap()[0];
+
+        let mut provider_split = "expression.not_a_provider".split('.');
+        provider_split.next();
+        match get_corresponding_provider(provider_split, column) {
+            Err(_) => (),
+            _ => panic!(),
+        }
+    }
+}
@@ -0,0 +1,622 @@
+use super::parser::{Expr, Operator};
+use crate::providers::geo::point;
+use crate::providers::parameters::encoding;
+use crate::providers::parameters::timestamp::TimestampType;
+use crate::providers::provider::{ColumnType, Value};
+use crate::providers::random::uuid;
+use crate::row;
+
+use chrono::{DateTime, Datelike, Months, NaiveDate, TimeDelta, Utc};
+use std::collections::HashMap;
+
+const FUNCTIONS: &[&str] = &[
+    "abs", "coalesce", "concat", "date_add", "day", "float", "if", "int", "length", "lower",
+    "month", "round", "trim", "upper", "year",
+];
+const DATE_UNITS: &[&str] = &["day", "week", "month", "year"];
+const TIMESTAMP_UNITS: &[&str] = &["second", "minute", "hour", "day", "week", "month", "year"];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Type {
+    Bool,
+    Int,
+    Float,
+    String,
+    Date,
+    Timestamp(TimestampType),
+}
+
+impl Type {
+    pub fn of(value: &Value) -> Option<Type> {
+        match value {
+            Value::Bool(_) => Some(Type::Bool),
+            Value::Int32(_) => Some(Type::Int),
+            Value::Float64(_) => Some(Type::Float),
+            Value::String(_) => Some(Type::String),
+            Value::Date(_, _) => Some(Type::Date),
+            Value::Timestamp(_, _, timestamp_type) => Some(Type::Timestamp(*timestamp_type)),
+            Value::Time(_, _) | Value::Uuid(_) | Value::Point(_, _) | Value::Bytes(_, _) => {
+                Some(Type::String)
+            }
+            Value::Duration(_, _) => Some(Type::Int),
+            Value::Null => None,
+        }
+    }
+
+    fn is_number(&self) -> bool {
+        matches!(self, Type::Int | Type::Float)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Type::Bool => "bool",
+            Type::Int => "integer",
+            Type::Float => "float",
+            Type::String => "string",
+            Type::Date => "date",
+            Type::Timestamp(_) => "timestamp",
+        }
+    }
+
+    /// Value of the type, used to infer the type of the column
+    pub fn sample(&self) -> Value {
+        match self {
+            Type::Bool => Value::Bool(false),
+            Type::Int => Value::Int32(0),
+            Type::Float => Value::Float64(0.0),
+            Type::String => Value::String(String::new()),
+            Type::Date => Value::Date(NaiveDate::default(), "%Y-%m-%d".to_string()),
+            Type::Timestamp(timestamp_type) => Value::Timestamp(
+                DateTime::default(),
+                "%Y-%m-%d %H:%M:%S".to_string(),
+                *timestamp_type,
+            ),
+        }
+    }
+}
+
+fn to_float(expr: Expr, expr_type: Type) -> Expr {
+    match expr_type {
+        Type::Int => Expr::Call("float".to_string(), vec![expr]),
+        _ => expr,
+    }
+}
+
+/// Common type of two branches, integers being converted to float when mixed with floats
+fn unify(
+    left: (Expr, Type),
+    right: (Expr, Type),
+    context: &str,
+) -> Result<(Expr, Expr, Type), String> {
+    match (left.1, right.1) {
+        (l, r) if l == r => Ok((left.0, right.0, l)),
+        // timestamps of different units, the left one giving the unit
+        (Type::Timestamp(l), Type::Timestamp(_)) => Ok((left.0, right.0, Type::Timestamp(l))),
+        (l, r) if l.is_number() && r.is_number() => {
+            Ok((to_float(left.0, l), to_float(right.0, r), Type::Float))
+        }
+        (l, r) => Err(format!(
+            "{} expects values of the same type, got {} and {}.",
+            context,
+            l.name(),
+            r.name()
+        )),
+    }
+}
+
+/// Types are compared by name, timestamps of any unit being expected the same way
+fn expect_type(expr_type: Type, expected: &[Type], context: &str) -> Result<(), String> {
+    match expected.iter().any(|t| t.name() == expr_type.name()) {
+        true => Ok(()),
+        false => Err(format!(
+            "{} expects {}, got {}.",
+            context,
+            expected
+                .iter()
+                .map(|t| t.name())
+                .collect::<Vec<_>>()
+                .join(" or "),
+            expr_type.name()
+        )),
+    }
+}
+
+/// Check the types of the expression against the columns it reads. Returns the expression
+/// with the conversions needed to mix integers and floats, and the type of its values.
+pub fn check(expr: Expr, columns: &HashMap<String, ColumnType>) -> Result<(Expr, Type), String> {
+    match expr {
+        Expr::Literal(value) => match Type::of(&value) {
+            Some(value_type) => Ok((Expr::Literal(value), value_type)),
+            None => Err("Null literal is not supported.".to_string()),
+        },
+        Expr::Column(name) => match columns
+            .get(&name)
+            .and_then(|column| Type::of(&column.sample))
+        {
+            Some(column_type) => Ok((Expr::Column(name), column_type)),
+            None => Err(format!("Unknown column {}.", name)),
+        },
+        Expr::Negate(expr) => {
+            let (expr, expr_type) = check(*expr, columns)?;
+            expect_type(expr_type, &[Type::Int, Type::Float], "-")?;
+            Ok((Expr::Negate(Box::new(expr)), expr_type))
+        }
+        Expr::Not(expr) => {
+            let (expr, expr_type) = check(*expr, columns)?;
+            expect_type(expr_type, &[Type::Bool], "not")?;
+            Ok((Expr::Not(Box::new(expr)), Type::Bool))
+        }
+        Expr::Binary(operator, left, right) => check_binary(operator, *left, *right, columns),
+        Expr::Call(function, args) => check_call(function, args, columns),
+    }
+}
+
+fn check_binary(
+    operator: Operator,
+    left: Expr,
+    right: Expr,
+    columns: &HashMap<String, ColumnType>,
+) -> Result<(Expr, Type), String> {
+    let left = check(left, columns)?;
+    let right = check(right, columns)?;
+    let symbol = operator.symbol();
+    let binary = |left, right| Expr::Binary(operator, Box::new(left), Box::new(right));
+
+    match operator {
+        Operator::Add | Operator::Subtract | Operator::Multiply => {
+            expect_type(left.1, &[Type::Int, Type::Float], symbol)?;
+            expect_type(right.1, &[Type::Int, Type::Float], symbol)?;
+            let (left, right, result_type) = unify(left, right, symbol)?;
+            Ok((binary(left, right), result_type))
+        }
+        Operator::Divide => {
+            expect_type(left.1, &[Type::Int, Type::Float], symbol)?;
+            expect_type(right.1, &[Type::Int, Type::Float], symbol)?;
+            Ok((
+                binary(to_float(left.0, left.1), to_float(right.0, right.1)),
+                Type::Float,
+            ))
+        }
+        Operator::Modulo => {
+            expect_type(left.1, &[Type::Int], symbol)?;
+            expect_type(right.1, &[Type::Int], symbol)?;
+            Ok((binary(left.0, right.0), Type::Int))
+        }
+        Operator::Equal | Operator::NotEqual => {
+            let (left, right, _) = unify(left, right, symbol)?;
+            Ok((binary(left, right), Type::Bool))
+        }
+        Operator::Lower | Operator::LowerOrEqual | Operator::Greater | Operator::GreaterOrEqual => {
+            let (left, right, compared_type) = unify(left, right, symbol)?;
+            expect_type(
+                compared_type,
+                &[
+                    Type::Int,
+                    Type::Float,
+                    Type::String,
+                    Type::Date,
+                    Type::Timestamp(TimestampType::default()),
+                ],
+                symbol,
+            )?;
+            Ok((binary(left, right), Type::Bool))
+        }
+        Operator::And | Operator::Or => {
+            expect_type(left.1, &[Type::Bool], symbol)?;
+            expect_type(right.1, &[Type::Bool], symbol)?;
+            Ok((binary(left.0, right.0), Type::Bool))
+        }
+    }
+}
+
+fn expect_args(function: &str, args: &[(Expr, Type)], counts: &[usize]) -> Result<(), String> {
+    match counts.contains(&args.len()) {
+        true => Ok(()),
+        false => Err(format!(
+            "{} expects {} arguments, got {}.",
+            function,
+            counts
+                .iter()
+                .map(|c| c.to_string())
+                .collect::<Vec<_>>()
+                .join(" or "),
+            args.len()
+        )),
+    }
+}
+
+fn check_call(
+    function: String,
+    args: Vec<Expr>,
+    columns: &HashMap<String, ColumnType>,
+) -> Result<(Expr, Type), String> {
+    let mut args: Vec<(Expr, Type)> = args
+        .into_iter()
+        .map(|arg| check(arg, columns))
+        .collect::<Result<_, _>>()?;
+    let name = function.as_str();
+
+    let result_type = match name {
+        "concat" => {
+            if args.is_empty() {
+                return Err("concat expects at least one argument.".to_string());
+            }
+            Type::String
+        }
+        "upper" | "lower" | "trim" => {
+            expect_args(name, &args, &[1])?;
+            expect_type(args[0].1, &[Type::String], name)?;
+            Type::String
+        }
+        "length" => {
+            expect_args(name, &args, &[1])?;
+            expect_type(args[0].1, &[Type::String], name)?;
+            Type::Int
+        }
+        "abs" => {
+            expect_args(name, &args, &[1])?;
+            expect_type(args[0].1, &[Type::Int, Type::Float], name)?;
+            args[0].1
+        }
+        "round" => {
+            expect_args(name, &args, &[1, 2])?;
+            expect_type(args[0].1, &[Type::Int, Type::Float], name)?;
+            if let Some(digits) = args.get(1) {
+                expect_type(digits.1, &[Type::Int], name)?;
+            }
+            let (expr, expr_type) = args.remove(0);
+            args.insert(0, (to_float(expr, expr_type), Type::Float));
+            Type::Float
+        }
+        "float" | "int" => {
+            expect_args(name, &args, &[1])?;
+            expect_type(args[0].1, &[Type::Int, Type::Float], name)?;
+            match name {
+                "float" => Type::Float,
+                _ => Type::Int,
+            }
+        }
+        "year" | "month" | "day" => {
+            expect_args(name, &args, &[1])?;
+            expect_type(
+                args[0].1,
+                &[Type::Date, Type::Timestamp(TimestampType::default())],
+                name,
+            )?;
+            Type::Int
+        }
+        "date_add" => {
+            expect_args(name, &args, &[3])?;
+            expect_type(
+                args[0].1,
+                &[Type::Date, Type::Timestamp(TimestampType::default())],
+                name,
+            )?;
+            expect_type(args[1].1, &[Type::Int], name)?;
+            let units = match args[0].1 {
+                Type::Date => DATE_UNITS,
+                _ => TIMESTAMP_UNITS,
+            };
+            match &args[2].0 {
+                Expr::Literal(Value::String(unit)) if units.contains(&unit.as_str()) => (),
+                _ => {
+                    return Err(format!(
+                        "date_add unit should be one of '{}'.",
+                        units.join("', '")
+                    ))
+                }
+            }
+            args[0].1
+        }
+        "coalesce" | "if" => {
+            let values_start = match name {
+                "if" => {
+                    expect_args(name, &args, &[3])?;
+                    expect_type(args[0].1, &[Type::Bool], name)?;
+                    1
+                }
+                _ => {
+                    if args.is_empty() {
+                        return Err("coalesce expects at least one argument.".to_string());
+                    }
+                    0
+                }
+            };
+
+            let mut result_type = args[values_start].1;
+            for arg in &args[values_start + 1..] {
+                if arg.1 != result_type {
+                    match arg.1.is_number() && result_type.is_number() {
+                        true => result_type = Type::Float,
+                        false => {
+                            return Err(format!(
+                                "{} expects values of the same type, got {} and {}.",
+                                name,
+                                result_type.name(),
+                                arg.1.name()
+                            ))
+                        }
+                    }
+                }
+            }
+            if result_type == Type::Float {
+                args = args
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, (expr, expr_type))| match i >= values_start {
+                        true => (to_float(expr, expr_type), Type::Float),
+                        false => (expr, expr_type),
+                    })
+                    .collect();
+            }
+            result_type
+        }
+        _ => {
+            return Err(format!(
+                "Unknown function {}. Expected one of: {}.",
+                name,
+                FUNCTIONS.join(", ")
+            ))
+        }
+    };
+
+    Ok((
+        Expr::Call(function, args.into_iter().map(|(expr, _)| expr).collect()),
+        result_type,
+    ))
+}
+
+/// Whether the expression can return null, when a column it reads is null
+/// or when an operation has no result (division or modulo by zero, date out of range)
+pub fn can_be_null(expr: &Expr, columns: &HashMap<String, ColumnType>) -> bool {
+    match expr {
+        Expr::Literal(_) => false,
+        Expr::Column(name) => columns.get(name).is_none_or(|column| column.nullable),
+        Expr::Negate(expr) | Expr::Not(expr) => can_be_null(expr, columns),
+        Expr::Binary(Operator::Divide | Operator::Modulo, _, _) => true,
+        Expr::Binary(_, left, right) => can_be_null(left, columns) || can_be_null(right, columns),
+        Expr::Call(function, args) => match function.as_str() {
+            "date_add" => true,
+            "coalesce" => args.iter().all(|arg| can_be_null(arg, columns)),
+            _ => args.iter().any(|arg| can_be_null(arg, columns)),
+        },
+    }
+}
+
+/// Value of a checked expression for the current row. Null values propagate.
+pub fn evaluate(expr: &Expr) -> Value {
+    match expr {
+        Expr::Literal(value) => value.clone(),
+        Expr::Column(name) => match row::get(name) {
+            // times, UUIDs, points and bytes are read as strings, durations as seconds
+            Some(Value::Time(value, format)) => Value::String(value.format(&format).to_string()),
+            Some(Value::Duration(value, _)) => {
+                Value::Int32(value.num_seconds().clamp(i32::MIN as i64, i32::MAX as i64) as i32)
+            }
+            Some(Value::Uuid(bytes)) => Value::String(uuid::hyphenated(&bytes)),
+            Some(Value::Point(latitude, longitude)) => {
+                Value::String(point::wkt(latitude, longitude))
+            }
+            Some(Value::Bytes(bytes, _)) => Value::String(encoding::base64(&bytes)),
+            Some(value) => value,
+            None => Value::Null,
+        },
+        Expr::Negate(expr) => match evaluate(expr) {
+            Value::Int32(value) => Value::Int32(value.wrapping_neg()),
+            Value::Float64(value) => Value::Float64(-value),
+            _ => Value::Null,
+        },
+        Expr::Not(expr) => match evaluate(expr) {
+            Value::Bool(value) => Value::Bool(!value),
+            _ => Value::Null,
+        },
+        Expr::Binary(operator, left, right) => evaluate_binary(*operator, left, right),
+        Expr::Call(function, args) => evaluate_call(function, args),
+    }
+}
+
+fn evaluate_binary(operator: Operator, left: &Expr, right: &Expr) -> Value {
+    // and/or only evaluate the right side when needed
+    match (operator, evaluate(left)) {
+        (Operator::And, Value::Bool(false)) => Value::Bool(false),
+        (Operator::Or, Value::Bool(true)) => Value::Bool(true),
+        (Operator::And | Operator::Or, Value::Bool(_)) => match evaluate(right) {
+            Value::Bool(value) => Value::Bool(value),
+            _ => Value::Null,
+        },
+        (_, Value::Null) => Value::Null,
+        (operator, left) => match (operator, left, evaluate(right)) {
+            (_, _, Value::Null) => Value::Null,
+            (Operator::Add, Value::Int32(l), Value::Int32(r)) => Value::Int32(l.wrapping_add(r)),
+            (Operator::Subtract, Value::Int32(l), Value::Int32(r)) => {
+                Value::Int32(l.wrapping_sub(r))
+            }
+            (Operator::Multiply, Value::Int32(l), Value::Int32(r)) => {
+                Value::Int32(l.wrapping_mul(r))
+            }
+            (Operator::Modulo, Value::Int32(l), Value::Int32(r)) => match l.checked_rem(r) {
+                Some(value) => Value::Int32(value),
+                None => Value::Null,
+            },
+            (Operator::Add, Value::Float64(l), Value::Float64(r)) => Value::Float64(l + r),
+            (Operator::Subtract, Value::Float64(l), Value::Float64(r)) => Value::Float64(l - r),
+            (Operator::Multiply, Value::Float64(l), Value::Float64(r)) => Value::Float64(l * r),
+            (Operator::Divide, Value::Float64(l), Value::Float64(r)) => match l / r {
+                value if r != 0.0 && value.is_finite() => Value::Float64(value),
+                _ => Value::Null,
+            },
+            (Operator::Equal, l, r) => Value::Bool(l == r),
+            (Operator::NotEqual, l, r) => Value::Bool(l != r),
+            (operator, l, r) => match compare(&l, &r) {
+                Some(ordering) => Value::Bool(match operator {
+                    Operator::Lower => ordering.is_lt(),
+                    Operator::LowerOrEqual => ordering.is_le(),
+                    Operator::Greater => ordering.is_gt(),
+                    _ => ordering.is_ge(),
+                }),
+                None => Value::Null,
+            },
+        },
+    }
+}
+
+fn compare(left: &Value, right: &Value) -> Option<std::cmp::Ordering> {
+    match (left, right) {
+        (Value::Int32(l), Value::Int32(r)) => Some(l.cmp(r)),
+        (Value::Float64(l), Value::Float64(r)) => l.partial_cmp(r),
+        (Value::String(l), Value::String(r)) => Some(l.cmp(r)),
+        (Value::Date(l, _), Value::Date(r, _)) => Some(l.cmp(r)),
+        (Value::Timestamp(l, ..), Value::Timestamp(r, ..)) => Some(l.cmp(r)),
+        _ => None,
+    }
+}
+
+pub fn to_string(value: &Value) -> String {
+    match value {
+        Value::Bool(value) => value.to_string(),
+        Value::Int32(value) => value.to_string(),
+        Value::Float64(value) => value.to_string(),
+        Value::String(value) => value.clone(),
+        Value::Date(value, format) => value.format(format).to_string(),
+        Value::Timestamp(value, format, timestamp_type) => timestamp_type.format(value, format),
+        Value::Time(value, format) => value.format(format).to_string(),
+        Value::Duration(value, _) => value.num_seconds().to_string(),
+        Value::Uuid(bytes) => uuid::hyphenated(bytes),
+        Value::Point(latitude, longitude) => point::wkt(*latitude, *longitude),
+        Value::Bytes(bytes, _) => encoding::base64(bytes),
+        Value::Null => String::new(),
+    }
+}
+
+fn evaluate_call(function: &str, args: &[Expr]) -> Value {
+    match function {
+        "coalesce" => {
+            return args
+                .iter()
+                .map(evaluate)
+                .find(|value| *value != Value::Null)
+                .unwrap_or(Value::Null)
+        }
+        "if" => {
+            return match evaluate(&args[0]) {
+                Value::Bool(true) => evaluate(&args[1]),
+                Value::Bool(false) => evaluate(&args[2]),
+                _ => Value::Null,
+            }
+        }
+        _ => (),
+    }
+
+    let values: Vec<Value> = args.iter().map(evaluate).collect();
+    if values.contains(&Value::Null) {
+        return Value::Null;
+    }
+
+    match (function, values.as_slice()) {
+        ("concat", values) => Value::String(values.iter().map(to_string).collect()),
+        ("upper", [Value::String(value)]) => Value::String(value.to_uppercase()),
+        ("lower", [Value::String(value)]) => Value::String(value.to_lowercase()),
+        ("trim", [Value::String(value)]) => Value::String(value.trim().to_string()),
+        ("length", [Value::String(value)]) => Value::Int32(value.chars().count() as i32),
+        ("abs", [Value::Int32(value)]) => Value::Int32(value.wrapping_abs()),
+        ("abs", [Value::Float64(value)]) => Value::Float64(value.abs()),
+        ("round", [Value::Float64(value)]) => Value::Float64(value.round()),
+        ("round", [Value::Float64(value), Value::Int32(digits)]) => {
+            let factor = 10_f64.powi(*digits);
+            Value::Float64((value * factor).round() / factor)
+        }
+        ("float", [Value::Int32(value)]) => Value::Float64(*value as f64),
+        ("float", [value]) => value.clone(),
+        ("int", [Value::Float64(value)]) => Value::Int32(*value as i32),
+        ("int", [value]) => value.clone(),
+        ("year", [Value::Date(value, _)]) => Value::Int32(value.year()),
+        ("year", [Value::Timestamp(value, _, timestamp_type)]) => {
+            Value::Int32(timestamp_type.local(value).year())
+        }
+        ("month", [Value::Date(value, _)]) => Value::Int32(value.month() as i32),
+        ("month", [Value::Timestamp(value, _, timestamp_type)]) => {
+            Value::Int32(timestamp_type.local(value).month() as i32)
+        }
+        ("day", [Value::Date(value, _)]) => Value::Int32(value.day() as i32),
+        ("day", [Value::Timestamp(value, _, timestamp_type)]) => {
+            Value::Int32(timestamp_type.local(value).day() as i32)
+        }
+        ("date_add", [Value::Date(value, format), Value::Int32(n), Value::String(unit)]) => {
+            match add_to_date(*value, *n, unit) {
+                Some(date) => Value::Date(date, format.clone()),
+                None => Value::Null,
+            }
+        }
+        (
+            "date_add",
+            [Value::Timestamp(value, format, timestamp_type), Value::Int32(n), Value::String(unit)],
+        ) => match add_to_timestamp(*value, *n, unit) {
+            Some(timestamp) => Value::Timestamp(timestamp, format.clone(), *timestamp_type),
+            None => Value::Null,
+        },
+        _ => Value::Null,
+    }
+}
+
+fn add_months<T>(
+    value: T,
+    months: i64,
+    add: fn(T, Months) -> Option<T>,
+    sub: fn(T, Months) -> Option<T>,
+) -> Option<T> {
+    let count = Months::new(u32::try_from(months.unsigned_abs()).ok()?);
+    match months >= 0 {
+        true => add(value, count),
+        false => sub(value, count),
+    }
+}
+
+fn add_to_date(date: NaiveDate, n: i32, unit: &str) -> Option<NaiveDate> {
+    let n = n as i64;
+    match unit {
+        "day" => date.checked_add_signed(TimeDelta::try_days(n)?),
+        "week" => date.checked_add_signed(TimeDelta::try_weeks(n)?),
+        "month" => add_months(
+            date,
+            n,
+            NaiveDate::checked_add_months,
+            NaiveDate::checked_sub_months,
+        ),
+        "year" => add_months(
+            date,
+            n * 12,
+            NaiveDate::checked_add_months,
+            NaiveDate::checked_sub_months,
+        ),
+        _ => None,
+    }
+}
+
+fn add_to_timestamp(timestamp: DateTime<Utc>, n: i32, unit: &str) -> Option<DateTime<Utc>> {
+    let n = n as i64;
+    let delta = match unit {
+        "second" => TimeDelta::try_seconds(n)?,
+        "minute" => TimeDelta::try_minutes(n)?,
+        "hour" => TimeDelta::try_hours(n)?,
+        "day" => TimeDelta::try_days(n)?,
+        "week" => TimeDelta::try_weeks(n)?,
+        "month" => {
+            return add_months(
+                timestamp,
+                n,
+                DateTime::checked_add_months,
+                DateTime::checked_sub_months,
+            )
+        }
+        "year" => {
+            return add_months(
+                timestamp,
+                n * 12,
+                DateTime::checked_add_months,
+                DateTime::checked_sub_months,
+            )
+        }
+        _ => return None,
+    };
+    timestamp.checked_add_signed(delta)
+}
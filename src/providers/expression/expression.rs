@@ -0,0 +1,396 @@
+use crate::errors::FakeLakeError;
+use crate::providers::geo::point;
+use crate::providers::parameters::get_column_name;
+use crate::providers::provider::{ColumnType, Provider, Value};
+use crate::providers::utils::string::random_alphanumeric;
+use crate::rng;
+
+use super::evaluation::{self, Type};
+use super::parser::{self, Expr};
+
+use chrono::TimeDelta;
+use std::collections::HashMap;
+use yaml_rust::Yaml;
+
+#[derive(Clone)]
+pub struct ExpressionProvider {
+    pub expression: Expr,
+    /// Type of the values, known once the provider is bound to the columns it reads
+    pub output_type: Option<Type>,
+    pub nullable: bool,
+}
+
+impl Provider for ExpressionProvider {
+    fn value(&self, _: u32) -> Value {
+        evaluation::evaluate(&self.expression)
+    }
+    fn corrupted_value(&self, index: u32) -> Value {
+        // return a value of the same type, different from the result of the expression
+        match self.value(index) {
+            Value::Bool(value) => Value::Bool(!value),
+            Value::Int32(value) => Value::Int32(value.wrapping_add(rng::i32(1..1_000_000))),
+            Value::Float64(value) => Value::Float64(value + rng::f64_range(1.0..1_000_000.0)),
+            Value::String(value) => {
+                Value::String(random_alphanumeric(value.chars().count().max(1) as u32))
+            }
+            Value::Date(value, format) => {
+                Value::Date(value + TimeDelta::days(rng::i64(1..3650)), format)
+            }
+            Value::Timestamp(value, format, timestamp_type) => Value::Timestamp(
+                value + TimeDelta::seconds(rng::i64(1..315_360_000)),
+                format,
+                timestamp_type,
+            ),
+            Value::Time(value, format) => {
+                Value::Time(value + TimeDelta::seconds(rng::i64(1..86400)), format)
+            }
+            Value::Duration(value, format) => Value::Duration(-value, format),
+            Value::Uuid(mut bytes) => {
+                bytes[0] = bytes[0].wrapping_add(rng::u32(1..256) as u8);
+                Value::Uuid(bytes)
+            }
+            Value::Point(_, longitude) => Value::Point(point::invalid_latitude(), longitude),
+            Value::Bytes(mut bytes, large) => {
+                bytes.push(rng::u32(0..256) as u8);
+                Value::Bytes(bytes, large)
+            }
+            Value::Null => Value::Null,
+        }
+    }
+    fn sample_value(&self) -> Value {
+        match self.output_type {
+            Some(output_type) => output_type.sample(),
+            None => Value::Null,
+        }
+    }
+    fn dependencies(&self) -> Vec<String> {
+        self.expression.columns()
+    }
+    fn bind(&mut self, columns: &HashMap<String, ColumnType>) -> Result<(), FakeLakeError> {
+        let (expression, output_type) = match evaluation::check(self.expression.clone(), columns) {
+            Ok(value) => value,
+            Err(e) => return Err(FakeLakeError::BadYAMLFormat(e)),
+        };
+
+        self.nullable = evaluation::can_be_null(&expression, columns);
+        self.expression = expression;
+        self.output_type = Some(output_type);
+        Ok(())
+    }
+    fn can_be_null(&self) -> bool {
+        self.nullable
+    }
+}
+
+/// Parse the expression given in the param_name parameter of the column
+pub fn parse_parameter(column: &Yaml, param_name: &str) -> Result<Expr, FakeLakeError> {
+    let column_name = get_column_name(column);
+
+    let expression = match &column[param_name] {
+        Yaml::String(value) => value.clone(),
+        Yaml::Integer(value) => value.to_string(),
+        Yaml::Real(value) => value.clone(),
+        Yaml::Boolean(value) => value.to_string(),
+        _ => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} should specify the {} parameter.",
+                column_name, param_name
+            )))
+        }
+    };
+
+    match parser::parse(&expression) {
+        Ok(expression) => Ok(expression),
+        Err(e) => Err(FakeLakeError::BadYAMLFormat(format!(
+            "Column {} has a wrong {}: {}",
+            column_name, param_name, e
+        ))),
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml) -> Result<Box<dyn Provider>, FakeLakeError> {
+    Ok(Box::new(ExpressionProvider {
+        expression: parse_parameter(column, "expression")?,
+        output_type: None,
+        nullable: false,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_from_yaml, ExpressionProvider};
+    use crate::providers::expression::evaluation::Type;
+    use crate::providers::provider::{ColumnType, Provider, Value};
+    use crate::row;
+
+    use chrono::NaiveDate;
+    use std::collections::HashMap;
+    use yaml_rust::YamlLoader;
+
+    fn column_types(columns: &[(&str, Value, bool)]) -> HashMap<String, ColumnType> {
+        columns
+            .iter()
+            .map(|(name, sample, nullable)| {
+                (
+                    name.to_string(),
+                    ColumnType {
+                        sample: sample.clone(),
+                        nullable: *nullable,
+                    },
+                )
+            })
+            .collect()
+    }
+
+    fn generate_provider(expression: &str) -> Box<dyn Provider> {
+        let yaml_str = format!("name: derived{}expression: \"{}\"", '\n', expression);
+        let yaml = YamlLoader::load_from_str(yaml_str.as_str()).unwrap();
+        match new_from_yaml(&yaml[0]) {
+            Ok(provider) => provider,
+            Err(_) => panic!("Provider should be created"),
+        }
+    }
+
+    fn bound_provider(expression: &str, columns: &[(&str, Value, bool)]) -> Box<dyn Provider> {
+        let mut provider = generate_provider(expression);
+        match provider.bind(&column_types(columns)) {
+            Ok(_) => provider,
+            Err(e) => panic!("Expression should be valid: {}", e),
+        }
+    }
+
+    fn expecting_bind_err(expression: &str, columns: &[(&str, Value, bool)]) {
+        let mut provider = generate_provider(expression);
+        if provider.bind(&column_types(columns)).is_ok() {
+            panic!("{} should not be valid", expression)
+        }
+    }
+
+    fn date(value: &str) -> Value {
+        Value::Date(
+            NaiveDate::parse_from_str(value, "%Y-%m-%d").unwrap(),
+            "%Y-%m-%d".to_string(),
+        )
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_no_expression_should_return_err() {
+        let yaml = YamlLoader::load_from_str("name: derived").unwrap();
+        assert!(new_from_yaml(&yaml[0]).is_err());
+    }
+
+    #[test]
+    fn given_wrong_expression_should_return_err() {
+        let yaml = YamlLoader::load_from_str("name: derived\nexpression: price *").unwrap();
+        assert!(new_from_yaml(&yaml[0]).is_err());
+    }
+
+    #[test]
+    fn given_number_expression_should_return_provider() {
+        let yaml = YamlLoader::load_from_str("name: derived\nexpression: 42").unwrap();
+        let mut provider = new_from_yaml(&yaml[0]).unwrap();
+        provider.bind(&HashMap::new()).unwrap();
+        assert_eq!(provider.value(0), Value::Int32(42));
+    }
+
+    #[test]
+    fn given_expression_should_depend_on_its_columns() {
+        let provider = generate_provider("concat(first_name, '.', last_name)");
+        assert_eq!(provider.dependencies(), vec!["first_name", "last_name"]);
+    }
+
+    // Validate types
+    #[test]
+    fn given_types_should_infer_output_type() {
+        let columns = [
+            ("price", Value::Float64(1.0), false),
+            ("quantity", Value::Int32(1), false),
+            ("name", Value::String(String::new()), false),
+            ("created", date("2020-01-01"), false),
+        ];
+        let cases = [
+            ("price * quantity", Type::Float),
+            ("quantity * 2", Type::Int),
+            ("quantity / 2", Type::Float),
+            ("concat(name, quantity)", Type::String),
+            ("date_add(created, 3, 'day')", Type::Date),
+            ("quantity > 2 and price < 10", Type::Bool),
+            ("if(quantity > 2, quantity, price)", Type::Float),
+            ("length(upper(name))", Type::Int),
+        ];
+        for (expression, expected) in cases {
+            let provider = bound_provider(expression, &columns);
+            assert_eq!(
+                provider.sample_value(),
+                expected.sample(),
+                "{} has a wrong type",
+                expression
+            );
+        }
+    }
+
+    #[test]
+    fn given_wrong_types_should_return_err() {
+        let columns = [
+            ("quantity", Value::Int32(1), false),
+            ("name", Value::String(String::new()), false),
+            ("created", date("2020-01-01"), false),
+        ];
+        let expressions = [
+            "quantity + name",
+            "not quantity",
+            "name > 2",
+            "quantity and true",
+            "upper(quantity)",
+            "date_add(created, 3, 'hour')",
+            "date_add(created, 3, name)",
+            "if(quantity, 1, 2)",
+            "unknown_function(quantity)",
+            "unknown_column + 1",
+            "round()",
+        ];
+        for expression in expressions {
+            expecting_bind_err(expression, &columns);
+        }
+    }
+
+    #[test]
+    fn given_nullable_column_should_be_nullable() {
+        let provider = bound_provider("quantity * 2", &[("quantity", Value::Int32(1), true)]);
+        assert!(provider.can_be_null());
+
+        let provider = bound_provider(
+            "coalesce(quantity, 0)",
+            &[("quantity", Value::Int32(1), true)],
+        );
+        assert!(!provider.can_be_null());
+
+        // a division by zero has no result
+        let provider = bound_provider(
+            "price / quantity",
+            &[
+                ("price", Value::Float64(1.0), false),
+                ("quantity", Value::Int32(1), false),
+            ],
+        );
+        assert!(provider.can_be_null());
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_row_should_compute_value() {
+        let provider = bound_provider(
+            "price * quantity",
+            &[
+                ("price", Value::Float64(1.0), false),
+                ("quantity", Value::Int32(1), false),
+            ],
+        );
+
+        row::set("price", Some(Value::Float64(2.5)));
+        row::set("quantity", Some(Value::Int32(4)));
+        assert_eq!(provider.value(0), Value::Float64(10.0));
+    }
+
+    #[test]
+    fn given_names_should_compute_email() {
+        let provider = bound_provider(
+            "lower(concat(first_name, '.', last_name, '@corp.com'))",
+            &[
+                ("first_name", Value::String(String::new()), false),
+                ("last_name", Value::String(String::new()), false),
+            ],
+        );
+
+        row::set("first_name", Some(Value::String("Jean".to_string())));
+        row::set("last_name", Some(Value::String("Dupont".to_string())));
+        assert_eq!(
+            provider.value(0),
+            Value::String("jean.dupont@corp.com".to_string())
+        );
+    }
+
+    #[test]
+    fn given_date_should_add_days_and_months() {
+        let columns = [("created", date("2020-01-31"), false)];
+
+        row::set("created", Some(date("2020-01-31")));
+        let provider = bound_provider("date_add(created, 3, 'day')", &columns);
+        assert_eq!(provider.value(0), date("2020-02-03"));
+
+        let provider = bound_provider("date_add(created, 1, 'month')", &columns);
+        assert_eq!(provider.value(0), date("2020-02-29"));
+
+        let provider = bound_provider("date_add(created, -1, 'year')", &columns);
+        assert_eq!(provider.value(0), date("2019-01-31"));
+    }
+
+    #[test]
+    fn given_null_column_should_return_null() {
+        let columns = [("discount", Value::Float64(1.0), true)];
+        row::set("discount", None);
+
+        let provider = bound_provider("discount * 2", &columns);
+        assert_eq!(provider.value(0), Value::Null);
+
+        let provider = bound_provider("coalesce(discount, 0)", &columns);
+        assert_eq!(provider.value(0), Value::Float64(0.0));
+    }
+
+    #[test]
+    fn given_division_by_zero_should_return_null() {
+        let provider = bound_provider(
+            "price / quantity",
+            &[
+                ("price", Value::Float64(1.0), false),
+                ("quantity", Value::Int32(1), false),
+            ],
+        );
+
+        row::set("price", Some(Value::Float64(2.5)));
+        row::set("quantity", Some(Value::Int32(0)));
+        assert_eq!(provider.value(0), Value::Null);
+
+        row::set("quantity", Some(Value::Int32(2)));
+        assert_eq!(provider.value(0), Value::Float64(1.25));
+    }
+
+    #[test]
+    fn given_operations_should_compute_them() {
+        let cases = [
+            ("7 % 3", Value::Int32(1)),
+            ("7 % 0", Value::Null),
+            ("7 / 2", Value::Float64(3.5)),
+            ("7 / 0", Value::Null),
+            ("7.5 / 0.0", Value::Null),
+            ("0 / 0", Value::Null),
+            ("-(2 + 3) * 2", Value::Int32(-10)),
+            ("round(2.345, 2)", Value::Float64(2.35)),
+            ("int(2.9) + abs(-1)", Value::Int32(3)),
+            ("1 == 1.0", Value::Bool(true)),
+            ("'b' > 'a' and not false", Value::Bool(true)),
+            ("trim('  a ')", Value::String("a".to_string())),
+        ];
+        for (expression, expected) in cases {
+            let provider = bound_provider(expression, &[]);
+            assert_eq!(provider.value(0), expected, "{} is wrong", expression);
+        }
+    }
+
+    #[test]
+    fn given_provider_should_corrupted_return_other_value_of_same_type() {
+        let provider = ExpressionProvider {
+            expression: crate::providers::expression::parser::parse("41 + 1").unwrap(),
+            output_type: Some(Type::Int),
+            nullable: false,
+        };
+
+        for i in 0..100 {
+            match provider.corrupted_value(i) {
+                Value::Int32(value) => assert_ne!(value, 42),
+                _ => panic!("Wrong type"),
+            }
+        }
+    }
+}
@@ -0,0 +1,6 @@
+pub mod builder;
+
+pub mod evaluation;
+#[allow(clippy::module_inception)]
+pub mod expression;
+pub mod parser;
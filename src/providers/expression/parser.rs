@@ -0,0 +1,493 @@
+use crate::providers::provider::Value;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expr {
+    Literal(Value),
+    Column(String),
+    Negate(Box<Expr>),
+    Not(Box<Expr>),
+    Binary(Operator, Box<Expr>, Box<Expr>),
+    Call(String, Vec<Expr>),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Modulo,
+    Equal,
+    NotEqual,
+    Lower,
+    LowerOrEqual,
+    Greater,
+    GreaterOrEqual,
+    And,
+    Or,
+}
+
+impl Operator {
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Operator::Add => "+",
+            Operator::Subtract => "-",
+            Operator::Multiply => "*",
+            Operator::Divide => "/",
+            Operator::Modulo => "%",
+            Operator::Equal => "==",
+            Operator::NotEqual => "!=",
+            Operator::Lower => "<",
+            Operator::LowerOrEqual => "<=",
+            Operator::Greater => ">",
+            Operator::GreaterOrEqual => ">=",
+            Operator::And => "and",
+            Operator::Or => "or",
+        }
+    }
+}
+
+impl Expr {
+    /// Names of the columns read by the expression, without duplicates
+    pub fn columns(&self) -> Vec<String> {
+        let mut columns = Vec::new();
+        self.collect_columns(&mut columns);
+        columns
+    }
+
+    fn collect_columns(&self, columns: &mut Vec<String>) {
+        match self {
+            Expr::Literal(_) => (),
+            Expr::Column(name) => {
+                if !columns.contains(name) {
+                    columns.push(name.clone())
+                }
+            }
+            Expr::Negate(expr) | Expr::Not(expr) => expr.collect_columns(columns),
+            Expr::Binary(_, left, right) => {
+                left.collect_columns(columns);
+                right.collect_columns(columns);
+            }
+            Expr::Call(_, args) => args.iter().for_each(|arg| arg.collect_columns(columns)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    /// Integer without its sign, checked to be an i32 once the sign is known
+    Integer(i64),
+    Float(f64),
+    String(String),
+    Identifier(String),
+    /// Column name between backquotes, never a keyword
+    QuotedIdentifier(String),
+    Symbol(&'static str),
+}
+
+const SYMBOLS: [&str; 18] = [
+    "==", "!=", "<=", ">=", "&&", "||", "<", ">", "+", "-", "*", "/", "%", "!", "(", ")", ",", "=",
+];
+
+fn integer_too_large(number: &str) -> String {
+    format!("Integer {} is too large.", number)
+}
+
+/// Literal of an integer, which should be an i32
+fn integer_literal(value: i64) -> Result<Expr, String> {
+    match i32::try_from(value) {
+        Ok(value) => Ok(Expr::Literal(Value::Int32(value))),
+        Err(_) => Err(integer_too_large(&value.to_string())),
+    }
+}
+
+fn tokenize(expression: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = expression.chars().collect();
+    let mut tokens = Vec::new();
+    let mut position = 0;
+
+    while position < chars.len() {
+        let c = chars[position];
+        if c.is_whitespace() {
+            position += 1;
+        } else if c.is_ascii_digit() {
+            let start = position;
+            while position < chars.len() && chars[position].is_ascii_digit() {
+                position += 1;
+            }
+            let is_float = position + 1 < chars.len()
+                && chars[position] == '.'
+                && chars[position + 1].is_ascii_digit();
+            if is_float {
+                position += 1;
+                while position < chars.len() && chars[position].is_ascii_digit() {
+                    position += 1;
+                }
+            }
+
+            let number: String = chars[start..position].iter().collect();
+            match is_float {
+                true => tokens.push(Token::Float(number.parse().unwrap())),
+                false => match number.parse() {
+                    Ok(value) => tokens.push(Token::Integer(value)),
+                    Err(_) => return Err(integer_too_large(&number)),
+                },
+            }
+        } else if c == '\'' || c == '"' || c == '`' {
+            let mut value = String::new();
+            position += 1;
+            loop {
+                match chars.get(position) {
+                    None => return Err(format!("Missing closing {} at the end.", c)),
+                    Some('\\') if position + 1 < chars.len() => {
+                        value.push(chars[position + 1]);
+                        position += 2;
+                    }
+                    Some(&next) if next == c => {
+                        position += 1;
+                        break;
+                    }
+                    Some(&next) => {
+                        value.push(next);
+                        position += 1;
+                    }
+                }
+            }
+            match c {
+                '`' => tokens.push(Token::QuotedIdentifier(value)),
+                _ => tokens.push(Token::String(value)),
+            }
+        } else if c.is_alphabetic() || c == '_' {
+            let start = position;
+            while position < chars.len()
+                && (chars[position].is_alphanumeric() || chars[position] == '_')
+            {
+                position += 1;
+            }
+            tokens.push(Token::Identifier(chars[start..position].iter().collect()));
+        } else {
+            let rest: String = chars[position..].iter().take(2).collect();
+            match SYMBOLS.iter().find(|symbol| rest.starts_with(*symbol)) {
+                Some(&"=") => return Err("Unexpected =, use == to compare.".to_string()),
+                Some(symbol) => {
+                    tokens.push(Token::Symbol(symbol));
+                    position += symbol.len();
+                }
+                None => return Err(format!("Unexpected character {}.", c)),
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn next_is_symbol(&mut self, symbols: &[&str]) -> Option<&'static str> {
+        match self.peek() {
+            Some(Token::Symbol(symbol)) if symbols.contains(symbol) => {
+                let symbol = *symbol;
+                self.position += 1;
+                Some(symbol)
+            }
+            _ => None,
+        }
+    }
+
+    fn next_is_keyword(&mut self, keyword: &str) -> bool {
+        match self.peek() {
+            Some(Token::Identifier(name)) if name.eq_ignore_ascii_case(keyword) => {
+                self.position += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn expect_symbol(&mut self, symbol: &str) -> Result<(), String> {
+        match self.next_is_symbol(&[symbol]) {
+            Some(_) => Ok(()),
+            None => Err(format!("Expected {}.", symbol)),
+        }
+    }
+
+    fn or(&mut self) -> Result<Expr, String> {
+        let mut expr = self.and()?;
+        while self.next_is_keyword("or") || self.next_is_symbol(&["||"]).is_some() {
+            expr = Expr::Binary(Operator::Or, Box::new(expr), Box::new(self.and()?));
+        }
+        Ok(expr)
+    }
+
+    fn and(&mut self) -> Result<Expr, String> {
+        let mut expr = self.not()?;
+        while self.next_is_keyword("and") || self.next_is_symbol(&["&&"]).is_some() {
+            expr = Expr::Binary(Operator::And, Box::new(expr), Box::new(self.not()?));
+        }
+        Ok(expr)
+    }
+
+    fn not(&mut self) -> Result<Expr, String> {
+        if self.next_is_keyword("not") || self.next_is_symbol(&["!"]).is_some() {
+            return Ok(Expr::Not(Box::new(self.not()?)));
+        }
+        self.comparison()
+    }
+
+    fn comparison(&mut self) -> Result<Expr, String> {
+        let expr = self.additive()?;
+        let operator = match self.next_is_symbol(&["==", "!=", "<=", ">=", "<", ">"]) {
+            Some("==") => Operator::Equal,
+            Some("!=") => Operator::NotEqual,
+            Some("<=") => Operator::LowerOrEqual,
+            Some(">=") => Operator::GreaterOrEqual,
+            Some("<") => Operator::Lower,
+            Some(">") => Operator::Greater,
+            _ => return Ok(expr),
+        };
+        Ok(Expr::Binary(
+            operator,
+            Box::new(expr),
+            Box::new(self.additive()?),
+        ))
+    }
+
+    fn additive(&mut self) -> Result<Expr, String> {
+        let mut expr = self.multiplicative()?;
+        while let Some(symbol) = self.next_is_symbol(&["+", "-"]) {
+            let operator = match symbol {
+                "+" => Operator::Add,
+                _ => Operator::Subtract,
+            };
+            expr = Expr::Binary(operator, Box::new(expr), Box::new(self.multiplicative()?));
+        }
+        Ok(expr)
+    }
+
+    fn multiplicative(&mut self) -> Result<Expr, String> {
+        let mut expr = self.unary()?;
+        while let Some(symbol) = self.next_is_symbol(&["*", "/", "%"]) {
+            let operator = match symbol {
+                "*" => Operator::Multiply,
+                "/" => Operator::Divide,
+                _ => Operator::Modulo,
+            };
+            expr = Expr::Binary(operator, Box::new(expr), Box::new(self.unary()?));
+        }
+        Ok(expr)
+    }
+
+    fn unary(&mut self) -> Result<Expr, String> {
+        if self.next_is_symbol(&["-"]).is_some() {
+            // the minus is part of an integer literal, so that i32::MIN can be written
+            if let Some(Token::Integer(value)) = self.peek() {
+                let value = -*value;
+                self.position += 1;
+                return integer_literal(value);
+            }
+            return Ok(Expr::Negate(Box::new(self.unary()?)));
+        }
+        self.primary()
+    }
+
+    fn primary(&mut self) -> Result<Expr, String> {
+        match self.next() {
+            Some(Token::Integer(value)) => integer_literal(value),
+            Some(Token::Float(value)) => Ok(Expr::Literal(Value::Float64(value))),
+            Some(Token::String(value)) => Ok(Expr::Literal(Value::String(value))),
+            Some(Token::QuotedIdentifier(name)) => Ok(Expr::Column(name)),
+            Some(Token::Identifier(name)) => {
+                if name.eq_ignore_ascii_case("true") {
+                    return Ok(Expr::Literal(Value::Bool(true)));
+                }
+                if name.eq_ignore_ascii_case("false") {
+                    return Ok(Expr::Literal(Value::Bool(false)));
+                }
+                if self.next_is_symbol(&["("]).is_none() {
+                    return Ok(Expr::Column(name));
+                }
+
+                let mut args = Vec::new();
+                if self.next_is_symbol(&[")"]).is_none() {
+                    loop {
+                        args.push(self.or()?);
+                        if self.next_is_symbol(&[","]).is_none() {
+                            break;
+                        }
+                    }
+                    self.expect_symbol(")")?;
+                }
+                Ok(Expr::Call(name.to_lowercase(), args))
+            }
+            Some(Token::Symbol("(")) => {
+                let expr = self.or()?;
+                self.expect_symbol(")")?;
+                Ok(expr)
+            }
+            Some(Token::Symbol(symbol)) => Err(format!("Unexpected {}.", symbol)),
+            None => Err("Unexpected end of the expression.".to_string()),
+        }
+    }
+}
+
+pub fn parse(expression: &str) -> Result<Expr, String> {
+    let mut parser = Parser {
+        tokens: tokenize(expression)?,
+        position: 0,
+    };
+
+    let expr = parser.or()?;
+    match parser.peek() {
+        None => Ok(expr),
+        Some(token) => Err(format!("Unexpected {:?} after the expression.", token)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, Expr, Operator};
+    use crate::providers::provider::Value;
+
+    fn column(name: &str) -> Box<Expr> {
+        Box::new(Expr::Column(name.to_string()))
+    }
+
+    fn int(value: i32) -> Box<Expr> {
+        Box::new(Expr::Literal(Value::Int32(value)))
+    }
+
+    #[test]
+    fn given_product_should_parse_binary() {
+        assert_eq!(
+            parse("price * quantity").unwrap(),
+            Expr::Binary(Operator::Multiply, column("price"), column("quantity"))
+        );
+    }
+
+    #[test]
+    fn given_operators_should_respect_precedence() {
+        assert_eq!(
+            parse("a + b * 2 > 10 and not c").unwrap(),
+            Expr::Binary(
+                Operator::And,
+                Box::new(Expr::Binary(
+                    Operator::Greater,
+                    Box::new(Expr::Binary(
+                        Operator::Add,
+                        column("a"),
+                        Box::new(Expr::Binary(Operator::Multiply, column("b"), int(2)))
+                    )),
+                    int(10)
+                )),
+                Box::new(Expr::Not(column("c")))
+            )
+        );
+    }
+
+    #[test]
+    fn given_parenthesis_should_parse_them_first() {
+        assert_eq!(
+            parse("(a + b) * 2").unwrap(),
+            Expr::Binary(
+                Operator::Multiply,
+                Box::new(Expr::Binary(Operator::Add, column("a"), column("b"))),
+                int(2)
+            )
+        );
+    }
+
+    #[test]
+    fn given_function_should_parse_call() {
+        assert_eq!(
+            parse("CONCAT(first_name, '.', last_name, \"@corp.com\")").unwrap(),
+            Expr::Call(
+                "concat".to_string(),
+                vec![
+                    Expr::Column("first_name".to_string()),
+                    Expr::Literal(Value::String(".".to_string())),
+                    Expr::Column("last_name".to_string()),
+                    Expr::Literal(Value::String("@corp.com".to_string())),
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn given_literals_should_parse_them() {
+        assert_eq!(parse("42").unwrap(), *int(42));
+        assert_eq!(parse("4.2").unwrap(), Expr::Literal(Value::Float64(4.2)));
+        assert_eq!(parse("True").unwrap(), Expr::Literal(Value::Bool(true)));
+        assert_eq!(
+            parse("'it\\'s'").unwrap(),
+            Expr::Literal(Value::String("it's".to_string()))
+        );
+        assert_eq!(parse("-1").unwrap(), *int(-1));
+        assert_eq!(parse("-a").unwrap(), Expr::Negate(column("a")));
+        assert_eq!(parse("--1").unwrap(), Expr::Negate(int(-1)));
+    }
+
+    #[test]
+    fn given_i32_limits_should_parse_them() {
+        assert_eq!(parse("-2147483648").unwrap(), *int(i32::MIN));
+        assert_eq!(parse("2147483647").unwrap(), *int(i32::MAX));
+        assert_eq!(
+            parse("1 - -2147483648").unwrap(),
+            Expr::Binary(Operator::Subtract, int(1), int(i32::MIN))
+        );
+        for expression in [
+            "2147483648",
+            "1 - 2147483648",
+            "-2147483649",
+            "99999999999999999999",
+        ] {
+            assert!(parse(expression).is_err(), "{}", expression);
+        }
+    }
+
+    #[test]
+    fn given_quoted_identifier_should_parse_column() {
+        assert_eq!(parse("`and`").unwrap(), *column("and"));
+        assert_eq!(parse("`unit price`").unwrap(), *column("unit price"));
+    }
+
+    #[test]
+    fn given_expression_should_list_columns_once() {
+        let expr = parse("concat(a, b, a) == c").unwrap();
+        assert_eq!(expr.columns(), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn given_wrong_expression_should_return_err() {
+        let expressions = [
+            "",
+            "a +",
+            "(a + b",
+            "a b",
+            "concat(a,",
+            "a = b",
+            "'not closed",
+            "a $ b",
+            "99999999999",
+            ")",
+        ];
+        for expression in expressions {
+            if parse(expression).is_ok() {
+                panic!("{} should not be parsed", expression)
+            }
+        }
+    }
+}
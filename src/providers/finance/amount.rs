@@ -0,0 +1,135 @@
+use crate::errors::FakeLakeError;
+use crate::providers::parameters::f64::F64Parameter;
+use crate::providers::parameters::get_column_name;
+use crate::providers::provider::{Provider, Value};
+use crate::rng;
+
+use yaml_rust::Yaml;
+
+const DEFAULT_MIN: f64 = 0.0;
+const DEFAULT_MAX: f64 = 1000.0;
+const DEFAULT_DECIMALS: u32 = 2;
+const MAX_DECIMALS: u32 = 10;
+
+fn round(value: f64, decimals: u32) -> f64 {
+    let factor = 10_f64.powi(decimals as i32);
+    (value * factor).round() / factor
+}
+
+#[derive(Clone)]
+pub struct AmountProvider {
+    pub min: f64,
+    pub max: f64,
+    pub decimals: u32,
+}
+
+impl Provider for AmountProvider {
+    fn value(&self, _: u32) -> Value {
+        let amount = round(rng::f64_range(self.min..self.max), self.decimals);
+        // rounding can reach the max, which is excluded
+        Value::Float64(match amount >= self.max {
+            true => self.min,
+            false => amount,
+        })
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        // amounts below the min, negative ones by default
+        let below = rng::f64_range(1.0..(self.max - self.min).max(1.0) + 1.0);
+        Value::Float64(round(self.min - below, self.decimals))
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml) -> Result<Box<AmountProvider>, FakeLakeError> {
+    let column_name = get_column_name(column);
+
+    let min = F64Parameter::from_yaml(column, "min", DEFAULT_MIN)?.value;
+    let max = F64Parameter::from_yaml(column, "max", DEFAULT_MAX)?.value;
+    if min >= max {
+        return Err(FakeLakeError::BadYAMLFormat(format!(
+            "Column {} param min should be less than max.",
+            column_name
+        )));
+    }
+
+    let decimals = match &column["decimals"] {
+        Yaml::BadValue => DEFAULT_DECIMALS,
+        Yaml::Integer(value) if (0..=MAX_DECIMALS as i64).contains(value) => *value as u32,
+        _ => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} param decimals should be an integer between 0 and {}.",
+                column_name, MAX_DECIMALS
+            )))
+        }
+    };
+
+    Ok(Box::new(AmountProvider { min, max, decimals }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_from_yaml, round, AmountProvider};
+    use crate::providers::provider::{Provider, Value};
+
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider(yaml_str: &str) -> Box<AmountProvider> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        new_from_yaml(&yaml[0]).unwrap()
+    }
+
+    fn float_value(value: Value) -> f64 {
+        match value {
+            Value::Float64(value) => value,
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_params_should_use_them() {
+        let provider = generate_provider("name: amount");
+        assert_eq!(
+            (provider.min, provider.max, provider.decimals),
+            (0.0, 1000.0, 2)
+        );
+
+        let provider = generate_provider("name: amount\nmin: 10\nmax: 99.5\ndecimals: 0");
+        assert_eq!(
+            (provider.min, provider.max, provider.decimals),
+            (10.0, 99.5, 0)
+        );
+    }
+
+    #[test]
+    fn given_wrong_params_should_return_err() {
+        for params in ["min: 10\nmax: 5", "max: a", "decimals: 11", "decimals: -1"] {
+            let yaml = YamlLoader::load_from_str(&format!("name: amount\n{}", params)).unwrap();
+            assert!(
+                new_from_yaml(&yaml[0]).is_err(),
+                "{} should be wrong",
+                params
+            );
+        }
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_decimals_should_return_rounded_amounts_in_range() {
+        let provider = generate_provider("name: amount\nmin: 5\nmax: 6\ndecimals: 1");
+        for i in 0..100 {
+            let value = float_value(provider.value(i));
+            assert!((5.0..6.0).contains(&value), "{}", value);
+            assert_eq!(value, round(value, 1));
+        }
+    }
+
+    #[test]
+    fn given_corrupted_should_return_amounts_below_min() {
+        let provider = generate_provider("name: amount");
+        for i in 0..100 {
+            let value = float_value(provider.corrupted_value(i));
+            assert!(value < 0.0, "{}", value);
+            assert_eq!(value, round(value, 2));
+        }
+    }
+}
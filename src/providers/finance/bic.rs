@@ -0,0 +1,126 @@
+use crate::errors::FakeLakeError;
+use crate::providers::parameters::country::countries_from_yaml;
+use crate::providers::parameters::get_column_name;
+use crate::providers::parameters::wstring::{weighted_random, WStringParameter};
+use crate::providers::provider::{Provider, Value};
+use crate::providers::utils::checksum::from_mask;
+use crate::rng;
+
+use yaml_rust::Yaml;
+
+#[derive(Clone)]
+pub struct BicProvider {
+    pub countries: Vec<WStringParameter>,
+    /// Whether the 3 characters of the branch code follow the 8 characters of the bank
+    pub branch: bool,
+}
+
+impl BicProvider {
+    /// Bank, country and location codes, followed by the branch code
+    fn bic(&self) -> String {
+        let mut bic = format!(
+            "{}{}{}",
+            from_mask("????"),
+            weighted_random(&self.countries),
+            from_mask("**")
+        );
+        if self.branch {
+            bic.push_str(&from_mask("***"));
+        }
+        bic
+    }
+}
+
+impl Provider for BicProvider {
+    fn value(&self, _: u32) -> Value {
+        Value::String(self.bic())
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        // digits in the bank code, or a length that is neither 8 nor 11
+        let mut bic = self.bic();
+        match rng::bool() {
+            true => bic.replace_range(..4, &from_mask("####")),
+            false => bic.truncate(bic.len() - 1),
+        }
+        Value::String(bic)
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml) -> Result<Box<BicProvider>, FakeLakeError> {
+    let countries = countries_from_yaml(column, None)?;
+
+    let branch = match &column["branch"] {
+        Yaml::BadValue => false,
+        Yaml::Boolean(value) => *value,
+        _ => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} param branch should be a boolean.",
+                get_column_name(column)
+            )))
+        }
+    };
+
+    Ok(Box::new(BicProvider { countries, branch }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_from_yaml, BicProvider};
+    use crate::providers::provider::{Provider, Value};
+
+    use regex::Regex;
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider(yaml_str: &str) -> Box<BicProvider> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        new_from_yaml(&yaml[0]).unwrap()
+    }
+
+    fn string_value(value: Value) -> String {
+        match value {
+            Value::String(value) => value,
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_no_params_should_use_country_of_locale() {
+        let provider = generate_provider("name: bic\nlocale: en_US");
+        assert_eq!(provider.countries[0].value, "US");
+        assert!(!provider.branch);
+    }
+
+    #[test]
+    fn given_wrong_params_should_return_err() {
+        for params in ["country: FRA", "branch: 3"] {
+            let yaml = YamlLoader::load_from_str(&format!("name: bic\n{}", params)).unwrap();
+            assert!(
+                new_from_yaml(&yaml[0]).is_err(),
+                "{} should be wrong",
+                params
+            );
+        }
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_params_should_return_bics() {
+        let re = Regex::new(r"^[A-Z]{4}(FR|DE)[0-9A-Z]{2}([0-9A-Z]{3})?$").unwrap();
+        for params in ["country: [FR, DE]", "country: [FR, DE]\nbranch: true"] {
+            let provider = generate_provider(&format!("name: bic\n{}", params));
+            let length = match provider.branch {
+                true => 11,
+                false => 8,
+            };
+            for i in 0..50 {
+                let value = string_value(provider.value(i));
+                assert!(re.is_match(&value), "{}", value);
+                assert_eq!(value.len(), length);
+
+                let value = string_value(provider.corrupted_value(i));
+                assert!(!re.is_match(&value), "{} should be invalid", value);
+            }
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::errors::FakeLakeError;
+use crate::providers::provider::{unknown_provider, Provider};
+
+use super::{amount, bic, credit_card, currency, iban};
+
+use yaml_rust::Yaml;
+
+const AVAILABLE: &[&str] = &[
+    "finance.amount",
+    "finance.bic",
+    "finance.credit_card",
+    "finance.currency_code",
+    "finance.iban",
+];
+
+pub fn get_corresponding_provider(
+    mut provider_split: std::str::Split<'_, char>,
+    column: &Yaml,
+) -> Result<Box<dyn Provider>, FakeLakeError> {
+    match provider_split.next() {
+        Some("amount") => Ok(amount::new_from_yaml(column)?),
+        Some("bic") => Ok(bic::new_from_yaml(column)?),
+        Some("credit_card") => Ok(credit_card::new_from_yaml(column)?),
+        Some("currency_code") => Ok(currency::new_from_yaml(column)?),
+        Some("iban") => Ok(iban::new_from_yaml(column)?),
+        other => Err(unknown_provider("finance", other, AVAILABLE)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::get_corresponding_provider;
+
+    use yaml_rust::YamlLoader;
+
+    #[test]
+    fn given_finance_providers_should_return_provider() {
+        for provider_name in ["amount", "bic", "credit_card", "currency_code", "iban"] {
+            let yaml_str = format!("name: finance{}provider: {}", '\n', provider_name);
+            let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+            let provider_split = provider_name.split('.');
+            match get_corresponding_provider(provider_split, column) {
+                Ok(_) => (),
+                _ => panic!("{} should return a provider", provider_name),
+            }
+        }
+    }
+
+    #[test]
+    fn given_wrong_provider_should_return_error() {
+        let provider_name = "not_a_provider";
+        let yaml_str = format!("name: finance{}provider: {}", '\n', provider_name);
+        let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+        let provider_split = provider_name.split('.');
+        match get_corresponding_provider(provider_split, column) {
+            Err(_) => (),
+            _ => panic!(),
+        }
+    }
+}
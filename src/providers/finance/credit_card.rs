@@ -0,0 +1,264 @@
+use crate::errors::FakeLakeError;
+use crate::providers::parameters::get_column_name;
+use crate::providers::parameters::wstring::{weighted_random, WStringParameter};
+use crate::providers::provider::{Provider, Value};
+use crate::providers::utils::checksum::{luhn_digit, other_digit};
+use crate::rng;
+
+use yaml_rust::Yaml;
+
+pub struct Brand {
+    pub name: &'static str,
+    /// Ranges of the first digits of the numbers, the max being included
+    pub prefixes: &'static [(u32, u32)],
+    pub length: usize,
+    /// Digits of each group when the number is written with spaces
+    pub groups: &'static [usize],
+    /// Weight of the brand when no brand is given
+    pub weight: u32,
+}
+
+pub const BRANDS: &[Brand] = &[
+    Brand {
+        name: "visa",
+        prefixes: &[(4, 4)],
+        length: 16,
+        groups: &[4, 4, 4, 4],
+        weight: 50,
+    },
+    Brand {
+        name: "mastercard",
+        prefixes: &[(51, 55), (2221, 2720)],
+        length: 16,
+        groups: &[4, 4, 4, 4],
+        weight: 30,
+    },
+    Brand {
+        name: "amex",
+        prefixes: &[(34, 34), (37, 37)],
+        length: 15,
+        groups: &[4, 6, 5],
+        weight: 10,
+    },
+    Brand {
+        name: "discover",
+        prefixes: &[(6011, 6011), (644, 649), (65, 65)],
+        length: 16,
+        groups: &[4, 4, 4, 4],
+        weight: 4,
+    },
+    Brand {
+        name: "jcb",
+        prefixes: &[(3528, 3589)],
+        length: 16,
+        groups: &[4, 4, 4, 4],
+        weight: 4,
+    },
+    Brand {
+        name: "diners",
+        prefixes: &[(300, 305), (36, 36), (38, 38)],
+        length: 14,
+        groups: &[4, 6, 4],
+        weight: 2,
+    },
+];
+
+fn brand(name: &str) -> Option<&'static Brand> {
+    BRANDS.iter().find(|brand| brand.name == name)
+}
+
+impl Brand {
+    /// Digits of a number of the brand, without the check digit
+    fn random_digits(&self) -> String {
+        let (min, max) = self.prefixes[rng::usize(..self.prefixes.len())];
+        let mut digits = rng::u32(min..max + 1).to_string();
+        while digits.len() < self.length - 1 {
+            digits.push(char::from(b'0' + rng::u32(0..10) as u8));
+        }
+        digits
+    }
+
+    fn with_spaces(&self, number: &str) -> String {
+        let mut groups = Vec::new();
+        let mut start = 0;
+        for group in self.groups {
+            groups.push(&number[start..start + group]);
+            start += group;
+        }
+        groups.join(" ")
+    }
+}
+
+#[derive(Clone)]
+pub struct CreditCardProvider {
+    /// Brands of the numbers, all of them weighted by their market share if empty
+    pub brands: Vec<WStringParameter>,
+    /// Whether the number is written by groups of digits
+    pub spaces: bool,
+}
+
+impl CreditCardProvider {
+    fn brand(&self) -> &'static Brand {
+        match self.brands.is_empty() {
+            true => {
+                let mut random = rng::u32(0..BRANDS.iter().map(|brand| brand.weight).sum());
+                for brand in BRANDS {
+                    if random < brand.weight {
+                        return brand;
+                    }
+                    random -= brand.weight;
+                }
+                panic!("Random value can't be greater than the weights")
+            }
+            false => brand(weighted_random(&self.brands)).unwrap(),
+        }
+    }
+
+    fn number(&self, check_digit: impl Fn(u32) -> u32) -> String {
+        let brand = self.brand();
+        let digits = brand.random_digits();
+        let number = format!("{}{}", digits, check_digit(luhn_digit(&digits)));
+        match self.spaces {
+            true => brand.with_spaces(&number),
+            false => number,
+        }
+    }
+}
+
+impl Provider for CreditCardProvider {
+    fn value(&self, _: u32) -> Value {
+        Value::String(self.number(|digit| digit))
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        // numbers of the brand failing the Luhn check
+        Value::String(self.number(other_digit))
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml) -> Result<Box<CreditCardProvider>, FakeLakeError> {
+    let column_name = get_column_name(column);
+
+    let mut brands = match &column["brand"] {
+        Yaml::BadValue => Vec::new(),
+        _ => WStringParameter::new(column, "brand", ""),
+    };
+    for parameter in brands.iter_mut() {
+        parameter.value = parameter.value.to_lowercase();
+        if brand(&parameter.value).is_none() {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} param brand {} should be one of {}.",
+                column_name,
+                parameter.value,
+                BRANDS
+                    .iter()
+                    .map(|brand| brand.name)
+                    .collect::<Vec<&str>>()
+                    .join(", ")
+            )));
+        }
+    }
+
+    let spaces = match &column["spaces"] {
+        Yaml::BadValue => false,
+        Yaml::Boolean(value) => *value,
+        _ => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} param spaces should be a boolean.",
+                column_name
+            )))
+        }
+    };
+
+    Ok(Box::new(CreditCardProvider { brands, spaces }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_from_yaml, CreditCardProvider};
+    use crate::providers::provider::{Provider, Value};
+    use crate::providers::utils::checksum::is_luhn_valid;
+
+    use regex::Regex;
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider(yaml_str: &str) -> Box<CreditCardProvider> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        new_from_yaml(&yaml[0]).unwrap()
+    }
+
+    fn string_value(value: Value) -> String {
+        match value {
+            Value::String(value) => value,
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_params_should_use_them() {
+        let provider = generate_provider("name: card");
+        assert!(provider.brands.is_empty());
+        assert!(!provider.spaces);
+
+        let provider = generate_provider("name: card\nbrand: [Visa, amex]\nspaces: true");
+        assert_eq!(provider.brands[0].value, "visa");
+        assert_eq!(provider.brands[1].value, "amex");
+        assert!(provider.spaces);
+    }
+
+    #[test]
+    fn given_wrong_params_should_return_err() {
+        for params in ["brand: maestro", "brand: [visa, 12]", "spaces: yes please"] {
+            let yaml = YamlLoader::load_from_str(&format!("name: card\n{}", params)).unwrap();
+            assert!(
+                new_from_yaml(&yaml[0]).is_err(),
+                "{} should be wrong",
+                params
+            );
+        }
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_brands_should_return_valid_numbers_of_them() {
+        for (brand, pattern) in [
+            ("visa", r"^4\d{15}$"),
+            (
+                "mastercard",
+                r"^(5[1-5]\d{14}|2(22[1-9]|2[3-9]\d|[3-6]\d{2}|7[01]\d|720)\d{12})$",
+            ),
+            ("amex", r"^3[47]\d{13}$"),
+            ("discover", r"^(6011|64[4-9]\d|65\d{2})\d{12}$"),
+            ("jcb", r"^35(2[89]|[3-8]\d)\d{12}$"),
+            ("diners", r"^(30[0-5]\d|3[68]\d{2})\d{10}$"),
+        ] {
+            let provider = generate_provider(&format!("name: card\nbrand: {}", brand));
+            let re = Regex::new(pattern).unwrap();
+            for i in 0..100 {
+                let value = string_value(provider.value(i));
+                assert!(re.is_match(&value), "{} is not a {} number", value, brand);
+                assert!(is_luhn_valid(&value), "{} should be valid", value);
+            }
+        }
+    }
+
+    #[test]
+    fn given_spaces_should_return_groups_of_digits() {
+        let provider = generate_provider("name: card\nbrand: amex\nspaces: true");
+        let re = Regex::new(r"^3[47]\d{2} \d{6} \d{5}$").unwrap();
+        for i in 0..20 {
+            let value = string_value(provider.value(i));
+            assert!(re.is_match(&value), "{}", value);
+            assert!(is_luhn_valid(&value));
+        }
+    }
+
+    #[test]
+    fn given_corrupted_should_return_numbers_failing_luhn() {
+        let provider = generate_provider("name: card");
+        for i in 0..100 {
+            let value = string_value(provider.corrupted_value(i));
+            assert!(!is_luhn_valid(&value), "{} should be invalid", value);
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use crate::errors::FakeLakeError;
+use crate::providers::parameters::get_column_name;
+use crate::providers::parameters::wstring::{weighted_random, WStringParameter};
+use crate::providers::provider::{Provider, Value};
+use crate::rng;
+
+use yaml_rust::Yaml;
+
+/// ISO 4217 codes of the most traded currencies and their weights
+const DEFAULT_CURRENCIES: &[(&str, u32)] = &[
+    ("USD", 30),
+    ("EUR", 25),
+    ("JPY", 8),
+    ("GBP", 8),
+    ("CNY", 6),
+    ("AUD", 3),
+    ("CAD", 3),
+    ("CHF", 3),
+    ("BRL", 2),
+    ("INR", 2),
+    ("HKD", 1),
+    ("KRW", 1),
+    ("MXN", 1),
+    ("NOK", 1),
+    ("NZD", 1),
+    ("PLN", 1),
+    ("SEK", 1),
+    ("SGD", 1),
+    ("TRY", 1),
+    ("ZAR", 1),
+];
+
+#[derive(Clone)]
+pub struct CurrencyProvider {
+    pub currencies: Vec<WStringParameter>,
+}
+
+impl Provider for CurrencyProvider {
+    fn value(&self, _: u32) -> Value {
+        Value::String(weighted_random(&self.currencies).to_string())
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        // lowercase codes, or codes of two or four letters
+        let code = weighted_random(&self.currencies);
+        let corrupted = match rng::u32(0..3) {
+            0 => code.to_lowercase(),
+            1 => code[..2].to_string(),
+            _ => format!("{}{}", code, char::from(b'A' + rng::u32(0..26) as u8)),
+        };
+        Value::String(corrupted)
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml) -> Result<Box<CurrencyProvider>, FakeLakeError> {
+    if column["currencies"].is_badvalue() {
+        return Ok(Box::new(CurrencyProvider {
+            currencies: DEFAULT_CURRENCIES
+                .iter()
+                .map(|(code, weight)| WStringParameter {
+                    value: code.to_string(),
+                    weight: *weight,
+                })
+                .collect(),
+        }));
+    }
+
+    let mut currencies = WStringParameter::new(column, "currencies", "EUR");
+    for currency in currencies.iter_mut() {
+        currency.value = currency.value.to_uppercase();
+        if currency.value.len() != 3 || !currency.value.chars().all(|c| c.is_ascii_uppercase()) {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} param currencies should be codes of three letters, not {}.",
+                get_column_name(column),
+                currency.value
+            )));
+        }
+    }
+
+    Ok(Box::new(CurrencyProvider { currencies }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_from_yaml, CurrencyProvider, DEFAULT_CURRENCIES};
+    use crate::providers::provider::{Provider, Value};
+
+    use regex::Regex;
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider(yaml_str: &str) -> Box<CurrencyProvider> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        new_from_yaml(&yaml[0]).unwrap()
+    }
+
+    fn string_value(value: Value) -> String {
+        match value {
+            Value::String(value) => value,
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_params_should_use_them() {
+        let provider = generate_provider("name: currency");
+        assert_eq!(provider.currencies.len(), DEFAULT_CURRENCIES.len());
+
+        let provider =
+            generate_provider("name: currency\ncurrencies: [eur, {value: USD, weight: 3}]");
+        assert_eq!(provider.currencies[0].value, "EUR");
+        assert_eq!(provider.currencies[1].weight, 3);
+    }
+
+    #[test]
+    fn given_wrong_params_should_return_err() {
+        for params in ["currencies: EURO", "currencies: [EUR, 12]"] {
+            let yaml = YamlLoader::load_from_str(&format!("name: currency\n{}", params)).unwrap();
+            assert!(
+                new_from_yaml(&yaml[0]).is_err(),
+                "{} should be wrong",
+                params
+            );
+        }
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_currencies_should_return_them() {
+        let provider = generate_provider("name: currency\ncurrencies: [EUR, CHF]");
+        for i in 0..50 {
+            let value = string_value(provider.value(i));
+            assert!(value == "EUR" || value == "CHF", "{}", value);
+        }
+    }
+
+    #[test]
+    fn given_corrupted_should_return_wrong_codes() {
+        let provider = generate_provider("name: currency");
+        let re = Regex::new(r"^[A-Z]{3}$").unwrap();
+        for i in 0..50 {
+            assert!(re.is_match(&string_value(provider.value(i))));
+            assert!(!re.is_match(&string_value(provider.corrupted_value(i))));
+        }
+    }
+}
@@ -0,0 +1,348 @@
+use crate::errors::FakeLakeError;
+use crate::providers::parameters::country::countries_from_yaml;
+use crate::providers::parameters::get_column_name;
+use crate::providers::parameters::wstring::{weighted_random, WStringParameter};
+use crate::providers::provider::{Provider, Value};
+use crate::providers::utils::checksum::{from_mask, mod97};
+use crate::rng;
+
+use yaml_rust::Yaml;
+
+/// Countries and the mask of their account numbers, where # is a digit, ? an uppercase letter
+/// and * either of them. The national check digits are then computed for BE, ES, FR, IT,
+/// PL and PT.
+const BBANS: &[(&str, &str)] = &[
+    ("AT", "################"),
+    ("BE", "############"),
+    ("BR", "#######################?*"),
+    ("CH", "#####************"),
+    ("DE", "##################"),
+    ("ES", "####################"),
+    ("FR", "##########***********##"),
+    ("GB", "????##############"),
+    ("IE", "????##############"),
+    ("IT", "?##########************"),
+    ("LU", "###*************"),
+    ("NL", "????##########"),
+    ("PL", "########################"),
+    ("PT", "#####################"),
+];
+
+/// Odd position values of the Italian CIN, for the digits 0 to 9 and the letters A to Z
+const CIN_ODD_VALUES: [u32; 26] = [
+    1, 0, 5, 7, 9, 13, 15, 17, 19, 21, 2, 4, 18, 20, 11, 3, 6, 8, 12, 14, 16, 10, 22, 25, 24, 23,
+];
+
+/// Weights of the Spanish control digits, from the leftmost digit
+const SPANISH_WEIGHTS: [u32; 10] = [1, 2, 4, 8, 5, 10, 9, 7, 3, 6];
+
+/// Weights of the Polish bank code check digit, from the leftmost digit
+const POLISH_WEIGHTS: [u32; 7] = [3, 9, 7, 1, 3, 9, 7];
+
+/// Belgian check digits, the remainder of the division by 97, 97 instead of 0
+fn belgian_key(digits: &str) -> u32 {
+    match mod97(digits) {
+        0 => 97,
+        key => key,
+    }
+}
+
+/// Spanish control digit of ten digits
+fn spanish_digit(digits: &str) -> u32 {
+    let sum: u32 = digits
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .zip(SPANISH_WEIGHTS)
+        .map(|(digit, weight)| digit * weight)
+        .sum();
+    match 11 - sum % 11 {
+        11 => 0,
+        10 => 1,
+        digit => digit,
+    }
+}
+
+/// French RIB key of the bank, branch and account number, letters being read as digits
+fn rib_key(number: &str) -> u32 {
+    let digits: String = number
+        .chars()
+        .map(|c| match c {
+            'A'..='I' => char::from(c as u8 - b'A' + b'1'),
+            'J'..='R' => char::from(c as u8 - b'J' + b'1'),
+            'S'..='Z' => char::from(c as u8 - b'S' + b'2'),
+            _ => c,
+        })
+        .collect();
+    97 - mod97(&format!("{}00", digits))
+}
+
+/// Italian CIN letter of the bank, branch and account number
+fn cin(number: &str) -> char {
+    let sum: u32 = number
+        .chars()
+        .filter_map(|c| match c.to_digit(10) {
+            Some(digit) => Some(digit as usize),
+            None => c.is_ascii_uppercase().then(|| (c as u8 - b'A') as usize),
+        })
+        .enumerate()
+        .map(|(i, value)| match i % 2 {
+            0 => CIN_ODD_VALUES[value],
+            _ => value as u32,
+        })
+        .sum();
+    char::from(b'A' + (sum % 26) as u8)
+}
+
+/// Polish check digit of the first seven digits of the bank code
+fn polish_digit(digits: &str) -> u32 {
+    let sum: u32 = digits
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .zip(POLISH_WEIGHTS)
+        .map(|(digit, weight)| digit * weight)
+        .sum();
+    (10 - sum % 10) % 10
+}
+
+/// Portuguese NIB check digits, 98 minus the remainder of the division by 97
+fn nib_key(digits: &str) -> u32 {
+    98 - mod97(&format!("{}00", digits))
+}
+
+/// Account number with the national check digits of the country computed from the others,
+/// for the countries having some
+pub fn with_national_check(country: &str, bban: &str) -> String {
+    match country {
+        "BE" => format!("{}{:02}", &bban[..10], belgian_key(&bban[..10])),
+        "ES" => format!(
+            "{}{}{}{}",
+            &bban[..8],
+            spanish_digit(&format!("00{}", &bban[..8])),
+            spanish_digit(&bban[10..]),
+            &bban[10..]
+        ),
+        "FR" => format!("{}{:02}", &bban[..21], rib_key(&bban[..21])),
+        "IT" => format!("{}{}", cin(&bban[1..]), &bban[1..]),
+        "PL" => format!("{}{}{}", &bban[..7], polish_digit(&bban[..7]), &bban[8..]),
+        "PT" => format!("{}{:02}", &bban[..19], nib_key(&bban[..19])),
+        _ => bban.to_string(),
+    }
+}
+
+/// Check digits of the IBAN of the country and the account number
+pub fn check_digits(country: &str, bban: &str) -> u32 {
+    98 - mod97(&format!("{}{}00", bban, country))
+}
+
+/// Whether the check digits of the IBAN are right
+#[cfg(test)]
+pub fn is_valid(iban: &str) -> bool {
+    let iban: String = iban.chars().filter(|c| *c != ' ').collect();
+    iban.len() > 4 && mod97(&format!("{}{}", &iban[4..], &iban[..4])) == 1
+}
+
+/// IBAN written by groups of four characters
+pub fn with_spaces(iban: &str) -> String {
+    iban.chars()
+        .collect::<Vec<char>>()
+        .chunks(4)
+        .map(|chunk| chunk.iter().collect::<String>())
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn bban_mask(country: &str) -> &'static str {
+    BBANS
+        .iter()
+        .find(|(code, _)| *code == country)
+        .map(|(_, mask)| *mask)
+        .unwrap_or_else(|| panic!("Country {} should have an IBAN", country))
+}
+
+#[derive(Clone)]
+pub struct IbanProvider {
+    pub countries: Vec<WStringParameter>,
+    /// Whether the IBAN is written by groups of four characters
+    pub spaces: bool,
+}
+
+impl IbanProvider {
+    fn iban(&self, check_digits: impl Fn(&str, &str) -> u32) -> String {
+        let country = weighted_random(&self.countries);
+        let bban = with_national_check(country, &from_mask(bban_mask(country)));
+        let iban = format!("{}{:02}{}", country, check_digits(country, &bban), bban);
+        match self.spaces {
+            true => with_spaces(&iban),
+            false => iban,
+        }
+    }
+}
+
+impl Provider for IbanProvider {
+    fn value(&self, _: u32) -> Value {
+        Value::String(self.iban(check_digits))
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        // wrong check digits, still between 02 and 98
+        Value::String(self.iban(|country, bban| {
+            let valid = check_digits(country, bban);
+            2 + (valid - 2 + rng::u32(1..97)) % 97
+        }))
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml) -> Result<Box<IbanProvider>, FakeLakeError> {
+    let supported: Vec<&str> = BBANS.iter().map(|(code, _)| *code).collect();
+    let countries = countries_from_yaml(column, Some(&supported))?;
+
+    let spaces = match &column["spaces"] {
+        Yaml::BadValue => false,
+        Yaml::Boolean(value) => *value,
+        _ => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} param spaces should be a boolean.",
+                get_column_name(column)
+            )))
+        }
+    };
+
+    Ok(Box::new(IbanProvider { countries, spaces }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        check_digits, is_valid, new_from_yaml, with_national_check, with_spaces, IbanProvider,
+        BBANS,
+    };
+    use crate::providers::provider::{Provider, Value};
+
+    use regex::Regex;
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider(yaml_str: &str) -> Box<IbanProvider> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        new_from_yaml(&yaml[0]).unwrap()
+    }
+
+    fn string_value(value: Value) -> String {
+        match value {
+            Value::String(value) => value,
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_no_params_should_use_country_of_locale() {
+        let provider = generate_provider("name: iban\nlocale: de_DE");
+        assert_eq!(provider.countries.len(), 1);
+        assert_eq!(provider.countries[0].value, "DE");
+        assert!(!provider.spaces);
+
+        let provider = generate_provider("name: iban\nlocale: ja_JP");
+        assert_eq!(provider.countries.len(), BBANS.len());
+    }
+
+    #[test]
+    fn given_wrong_params_should_return_err() {
+        for params in ["country: US", "country: [FR, XX]", "spaces: 1"] {
+            let yaml = YamlLoader::load_from_str(&format!("name: iban\n{}", params)).unwrap();
+            assert!(
+                new_from_yaml(&yaml[0]).is_err(),
+                "{} should be wrong",
+                params
+            );
+        }
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_known_ibans_should_validate_them() {
+        assert_eq!(check_digits("GB", "WEST12345698765432"), 82);
+        assert_eq!(check_digits("DE", "370400440532013000"), 89);
+        assert!(is_valid("GB82 WEST 1234 5698 7654 32"));
+        assert!(is_valid("FR1420041010050500013M02606"));
+        assert!(!is_valid("FR1520041010050500013M02606"));
+        assert_eq!(
+            with_spaces("DE89370400440532013000"),
+            "DE89 3704 0044 0532 0130 00"
+        );
+    }
+
+    #[test]
+    fn given_known_ibans_should_validate_their_national_check_digits() {
+        let ibans = [
+            "BE68539007547034",
+            "ES9121000418450200051332",
+            "FR1420041010050500013M02606",
+            "IT60X0542811101000000123456",
+            "PL61109010140000071219812874",
+            "PT50000201231234567890154",
+        ];
+        for iban in ibans {
+            let (country, bban) = (&iban[..2], &iban[4..]);
+            assert_eq!(with_national_check(country, bban), bban, "{}", iban);
+            assert!(is_valid(iban), "{}", iban);
+        }
+
+        // a changed check digit is not valid
+        assert_ne!(
+            with_national_check("FR", "20041010050500013M02607"),
+            "20041010050500013M02607"
+        );
+        assert_ne!(
+            with_national_check("ES", "21000418460200051332"),
+            "21000418460200051332"
+        );
+    }
+
+    #[test]
+    fn given_countries_with_national_check_should_return_ibans_valid_for_both() {
+        for country in ["BE", "ES", "FR", "IT", "PL", "PT"] {
+            let provider = generate_provider(&format!("name: iban\ncountry: {}", country));
+            for i in 0..200 {
+                let value = string_value(provider.value(i));
+                let bban = &value[4..];
+                assert_eq!(with_national_check(country, bban), bban, "{}", value);
+                assert!(is_valid(&value), "{} should be valid", value);
+            }
+        }
+    }
+
+    #[test]
+    fn given_countries_should_return_valid_ibans_of_them() {
+        let provider = generate_provider("name: iban\ncountry: [FR, GB, IT, BR]");
+        let re = Regex::new(
+            r"^(FR\d{12}[0-9A-Z]{11}\d{2}|GB\d{2}[A-Z]{4}\d{14}|IT\d{2}[A-Z]\d{10}[0-9A-Z]{12}|BR\d{25}[A-Z][0-9A-Z])$",
+        )
+        .unwrap();
+        for i in 0..100 {
+            let value = string_value(provider.value(i));
+            assert!(re.is_match(&value), "{}", value);
+            assert!(is_valid(&value), "{} should be valid", value);
+        }
+    }
+
+    #[test]
+    fn given_spaces_should_return_groups_of_four() {
+        let provider = generate_provider("name: iban\ncountry: DE\nspaces: true");
+        let re = Regex::new(r"^DE\d{2}( \d{4}){4} \d{2}$").unwrap();
+        for i in 0..20 {
+            let value = string_value(provider.value(i));
+            assert!(re.is_match(&value), "{}", value);
+            assert!(is_valid(&value));
+        }
+    }
+
+    #[test]
+    fn given_corrupted_should_return_ibans_with_wrong_check_digits() {
+        let provider = generate_provider("name: iban\ncountry: [ES, NL, PL]");
+        for i in 0..100 {
+            let value = string_value(provider.corrupted_value(i));
+            let check: u32 = value[2..4].parse().unwrap();
+            assert!((2..=98).contains(&check));
+            assert!(!is_valid(&value), "{} should be invalid", value);
+        }
+    }
+}
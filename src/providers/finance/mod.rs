@@ -0,0 +1,7 @@
+pub mod builder;
+
+pub mod amount;
+pub mod bic;
+pub mod credit_card;
+pub mod currency;
+pub mod iban;
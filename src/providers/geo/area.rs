@@ -0,0 +1,351 @@
+use crate::errors::FakeLakeError;
+use crate::providers::parameters::f64::number;
+use crate::providers::parameters::get_column_name;
+use crate::rng;
+
+use yaml_rust::Yaml;
+
+const EARTH_RADIUS_KM: f64 = 6371.0;
+const DEFAULT_RADIUS_KM: f64 = 10.0;
+
+/// Point around which coordinates are drawn, a center with a higher weight being drawn more often
+#[derive(Clone, Debug, PartialEq)]
+pub struct Center {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub weight: f64,
+}
+
+/// Where the coordinates are drawn
+#[derive(Clone, Debug, PartialEq)]
+pub enum Area {
+    /// Longitudes from west to east, the box crossing the antimeridian when west is greater than east
+    BoundingBox {
+        west: f64,
+        south: f64,
+        east: f64,
+        north: f64,
+    },
+    Around {
+        centers: Vec<Center>,
+        radius_km: f64,
+    },
+}
+
+/// Longitude between -180 and 180
+pub fn normalize_longitude(longitude: f64) -> f64 {
+    (longitude + 540.0).rem_euclid(360.0) - 180.0
+}
+
+/// Point at the distance of the start, going in the direction of the bearing in radians
+pub fn destination(latitude: f64, longitude: f64, bearing: f64, distance_km: f64) -> (f64, f64) {
+    let (latitude, longitude) = (latitude.to_radians(), longitude.to_radians());
+    let angle = distance_km / EARTH_RADIUS_KM;
+
+    let destination_latitude =
+        (latitude.sin() * angle.cos() + latitude.cos() * angle.sin() * bearing.cos()).asin();
+    let destination_longitude = longitude
+        + (bearing.sin() * angle.sin() * latitude.cos())
+            .atan2(angle.cos() - latitude.sin() * destination_latitude.sin());
+
+    (
+        destination_latitude.to_degrees(),
+        normalize_longitude(destination_longitude.to_degrees()),
+    )
+}
+
+/// Distance in kilometers between two points, along the surface of the earth
+#[cfg(test)]
+pub fn distance_km(from: (f64, f64), to: (f64, f64)) -> f64 {
+    let (latitude1, latitude2) = (from.0.to_radians(), to.0.to_radians());
+    let half_latitude = (latitude2 - latitude1) / 2.0;
+    let half_longitude = (to.1 - from.1).to_radians() / 2.0;
+    let a = half_latitude.sin().powi(2)
+        + latitude1.cos() * latitude2.cos() * half_longitude.sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+}
+
+impl Area {
+    fn weighted_center(centers: &[Center]) -> &Center {
+        let sum: f64 = centers.iter().map(|center| center.weight).sum();
+        let mut random = rng::f64() * sum;
+        for center in centers {
+            if random < center.weight {
+                return center;
+            }
+            random -= center.weight;
+        }
+        &centers[centers.len() - 1]
+    }
+
+    /// Random latitude and longitude of the area
+    pub fn random_point(&self) -> (f64, f64) {
+        match self {
+            Area::BoundingBox {
+                west,
+                south,
+                east,
+                north,
+            } => {
+                let width = match west <= east {
+                    true => east - west,
+                    false => east + 360.0 - west,
+                };
+                (
+                    south + rng::f64() * (north - south),
+                    normalize_longitude(west + rng::f64() * width),
+                )
+            }
+            Area::Around { centers, radius_km } => {
+                // the distance is uniform so that points cluster around the center
+                let center = Area::weighted_center(centers);
+                destination(
+                    center.latitude,
+                    center.longitude,
+                    rng::f64_range(0.0..std::f64::consts::TAU),
+                    rng::f64() * radius_km,
+                )
+            }
+        }
+    }
+}
+
+fn is_latitude(value: f64) -> bool {
+    (-90.0..=90.0).contains(&value)
+}
+
+fn is_longitude(value: f64) -> bool {
+    (-180.0..=180.0).contains(&value)
+}
+
+fn center_from_yaml(value: &Yaml) -> Option<Center> {
+    let center = Center {
+        latitude: number(&value["latitude"])?,
+        longitude: number(&value["longitude"])?,
+        weight: match &value["weight"] {
+            Yaml::BadValue => 1.0,
+            weight => number(weight)?,
+        },
+    };
+    (is_latitude(center.latitude) && is_longitude(center.longitude) && center.weight >= 0.0)
+        .then_some(center)
+}
+
+fn bounding_box_from_yaml(value: &Yaml) -> Option<Area> {
+    let values: Vec<f64> = value.as_vec()?.iter().map(number).collect::<Option<_>>()?;
+    match values[..] {
+        [west, south, east, north]
+            if is_longitude(west)
+                && is_longitude(east)
+                && is_latitude(south)
+                && is_latitude(north)
+                && south <= north =>
+        {
+            Some(Area::BoundingBox {
+                west,
+                south,
+                east,
+                north,
+            })
+        }
+        _ => None,
+    }
+}
+
+impl Area {
+    /// Area of a bbox, a center or a list of centers, the whole world without them
+    pub fn new_from_yaml(column: &Yaml) -> Result<Area, FakeLakeError> {
+        let column_name = get_column_name(column);
+        let wrong_param = |message: &str| {
+            FakeLakeError::BadYAMLFormat(format!("Column {} {}", column_name, message))
+        };
+
+        let radius_km = match &column["radius_km"] {
+            Yaml::BadValue => DEFAULT_RADIUS_KM,
+            value => match number(value) {
+                Some(value) if value > 0.0 => value,
+                _ => return Err(wrong_param("param radius_km should be a positive number.")),
+            },
+        };
+
+        match (&column["bbox"], &column["center"], &column["centers"]) {
+            (Yaml::BadValue, Yaml::BadValue, Yaml::BadValue) => Ok(Area::BoundingBox {
+                west: -180.0,
+                south: -90.0,
+                east: 180.0,
+                north: 90.0,
+            }),
+            (bbox, Yaml::BadValue, Yaml::BadValue) => bounding_box_from_yaml(bbox).ok_or_else(|| {
+                wrong_param(
+                    "param bbox should be a list of min longitude, min latitude, max longitude and max latitude.",
+                )
+            }),
+            (Yaml::BadValue, center, Yaml::BadValue) => match center_from_yaml(center) {
+                Some(center) => Ok(Area::Around {
+                    centers: vec![center],
+                    radius_km,
+                }),
+                None => Err(wrong_param(
+                    "param center should have a latitude and a longitude.",
+                )),
+            },
+            (Yaml::BadValue, Yaml::BadValue, centers) => {
+                let centers: Option<Vec<Center>> = centers
+                    .as_vec()
+                    .and_then(|centers| centers.iter().map(center_from_yaml).collect());
+                match centers {
+                    Some(centers)
+                        if !centers.is_empty()
+                            && centers.iter().map(|center| center.weight).sum::<f64>() > 0.0 =>
+                    {
+                        Ok(Area::Around { centers, radius_km })
+                    }
+                    _ => Err(wrong_param(
+                        "param centers should be a list of centers with a latitude, a longitude and an optional weight.",
+                    )),
+                }
+            }
+            _ => Err(wrong_param(
+                "should specify only one of bbox, center and centers.",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{destination, distance_km, normalize_longitude, Area, Center};
+
+    use yaml_rust::YamlLoader;
+
+    fn generate_area(yaml_str: &str) -> Area {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        Area::new_from_yaml(&yaml[0]).unwrap()
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_no_params_should_return_whole_world() {
+        assert_eq!(
+            generate_area("name: a"),
+            Area::BoundingBox {
+                west: -180.0,
+                south: -90.0,
+                east: 180.0,
+                north: 90.0
+            }
+        );
+    }
+
+    #[test]
+    fn given_params_should_return_area() {
+        assert_eq!(
+            generate_area("name: a\nbbox: [2.2, 48, 2.5, 49.0]"),
+            Area::BoundingBox {
+                west: 2.2,
+                south: 48.0,
+                east: 2.5,
+                north: 49.0
+            }
+        );
+        assert_eq!(
+            generate_area("name: a\ncenter: {latitude: 48.85, longitude: 2.35}\nradius_km: 5"),
+            Area::Around {
+                centers: vec![Center {
+                    latitude: 48.85,
+                    longitude: 2.35,
+                    weight: 1.0
+                }],
+                radius_km: 5.0
+            }
+        );
+        match generate_area(
+            "name: a\ncenters:\n  - {latitude: 48.85, longitude: 2.35, weight: 3}\n  - {latitude: 45.76, longitude: 4.84}",
+        ) {
+            Area::Around { centers, radius_km } => {
+                assert_eq!(centers.len(), 2);
+                assert_eq!(centers[0].weight, 3.0);
+                assert_eq!(radius_km, 10.0);
+            }
+            _ => panic!("Wrong area"),
+        }
+    }
+
+    #[test]
+    fn given_wrong_params_should_return_err() {
+        for params in [
+            "bbox: [1, 2, 3]",
+            "bbox: [2.5, 49, 2.2, 48]",
+            "bbox: [0, 0, 200, 10]",
+            "bbox: a",
+            "center: {latitude: 95, longitude: 2}",
+            "center: {latitude: 45}",
+            "center: {latitude: 45, longitude: 2}\nradius_km: -1",
+            "centers: []",
+            "centers: [{latitude: 45, longitude: 2, weight: 0}]",
+            "centers: [{latitude: 45, longitude: 2, weight: a}]",
+            "bbox: [0, 0, 1, 1]\ncenter: {latitude: 45, longitude: 2}",
+        ] {
+            let yaml = YamlLoader::load_from_str(&format!("name: a\n{}", params)).unwrap();
+            assert!(
+                Area::new_from_yaml(&yaml[0]).is_err(),
+                "{} should be wrong",
+                params
+            );
+        }
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_longitudes_should_normalize_them() {
+        assert_eq!(normalize_longitude(10.0), 10.0);
+        assert_eq!(normalize_longitude(190.0), -170.0);
+        assert_eq!(normalize_longitude(-190.0), 170.0);
+    }
+
+    #[test]
+    fn given_distance_should_return_destination_at_this_distance() {
+        let paris = (48.8566, 2.3522);
+        for bearing in [0.0, 1.0, 3.0, 5.0] {
+            let point = destination(paris.0, paris.1, bearing, 12.5);
+            assert!((distance_km(paris, point) - 12.5).abs() < 1e-6);
+        }
+        let (latitude, longitude) = destination(0.0, 179.99, std::f64::consts::FRAC_PI_2, 10.0);
+        assert!(latitude.abs() < 1e-9);
+        assert!(longitude < -179.9);
+    }
+
+    #[test]
+    fn given_bbox_should_return_points_inside() {
+        let area = generate_area("name: a\nbbox: [2.2, 48, 2.5, 49]");
+        for _ in 0..100 {
+            let (latitude, longitude) = area.random_point();
+            assert!((48.0..=49.0).contains(&latitude));
+            assert!((2.2..=2.5).contains(&longitude));
+        }
+
+        let area = generate_area("name: a\nbbox: [170, -10, -170, 10]");
+        for _ in 0..100 {
+            let (_, longitude) = area.random_point();
+            assert!(longitude >= 170.0 || longitude <= -170.0, "{}", longitude);
+        }
+    }
+
+    #[test]
+    fn given_centers_should_return_points_around_them() {
+        let area = generate_area(
+            "name: a\ncenters:\n  - {latitude: 48.85, longitude: 2.35, weight: 3}\n  - {latitude: 45.76, longitude: 4.84, weight: 1}\nradius_km: 5",
+        );
+        let mut around_paris = 0;
+        for _ in 0..1000 {
+            let point = area.random_point();
+            let paris = distance_km((48.85, 2.35), point);
+            let lyon = distance_km((45.76, 4.84), point);
+            assert!(paris <= 5.0 + 1e-9 || lyon <= 5.0 + 1e-9);
+            if paris <= 5.0 + 1e-9 {
+                around_paris += 1;
+            }
+        }
+        assert!((650..850).contains(&around_paris), "{}", around_paris);
+    }
+}
@@ -0,0 +1,63 @@
+use crate::errors::FakeLakeError;
+use crate::providers::provider::{unknown_provider, Provider};
+
+use super::point::{self, Kind};
+use super::polygon;
+
+use yaml_rust::Yaml;
+
+const AVAILABLE: &[&str] = &[
+    "geo.latitude",
+    "geo.longitude",
+    "geo.point",
+    "geo.wkt_point",
+    "geo.wkt_polygon",
+];
+
+pub fn get_corresponding_provider(
+    mut provider_split: std::str::Split<'_, char>,
+    column: &Yaml,
+) -> Result<Box<dyn Provider>, FakeLakeError> {
+    match provider_split.next() {
+        Some("latitude") => Ok(point::new_from_yaml(column, Kind::Latitude)?),
+        Some("longitude") => Ok(point::new_from_yaml(column, Kind::Longitude)?),
+        Some("point") => Ok(point::new_from_yaml(column, Kind::Point)?),
+        Some("wkt_point") => Ok(point::new_from_yaml(column, Kind::WktPoint)?),
+        Some("wkt_polygon") => Ok(polygon::new_from_yaml(column)?),
+        other => Err(unknown_provider("geo", other, AVAILABLE)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::get_corresponding_provider;
+
+    use yaml_rust::YamlLoader;
+
+    #[test]
+    fn given_geo_providers_should_return_provider() {
+        for provider_name in ["latitude", "longitude", "point", "wkt_point", "wkt_polygon"] {
+            let yaml_str = format!("name: geo{}provider: {}", '\n', provider_name);
+            let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+            let provider_split = provider_name.split('.');
+            match get_corresponding_provider(provider_split, column) {
+                Ok(_) => (),
+                _ => panic!("{} should return a provider", provider_name),
+            }
+        }
+    }
+
+    #[test]
+    fn given_wrong_provider_should_return_error() {
+        let provider_name = "not_a_provider";
+        let yaml_str = format!("name: geo{}provider: {}", '\n', provider_name);
+        let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+        let provider_split = provider_name.split('.');
+        match get_corresponding_provider(provider_split, column) {
+            Err(_) => (),
+            _ => panic!(),
+        }
+    }
+}
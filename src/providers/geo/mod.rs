@@ -0,0 +1,5 @@
+pub mod builder;
+
+pub mod area;
+pub mod point;
+pub mod polygon;
@@ -0,0 +1,229 @@
+use crate::errors::FakeLakeError;
+use crate::providers::parameters::get_column_name;
+use crate::providers::provider::{Provider, Value};
+use crate::rng;
+
+use super::area::Area;
+
+use yaml_rust::Yaml;
+
+/// Decimals of the coordinates, about 10 centimeters
+const DEFAULT_PRECISION: u32 = 6;
+const MAX_PRECISION: u32 = 15;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Kind {
+    Latitude,
+    Longitude,
+    /// Latitude and longitude, written by the output format
+    Point,
+    /// Point written as Well-Known Text
+    WktPoint,
+}
+
+/// Point written as Well-Known Text, the longitude coming first
+pub fn wkt(latitude: f64, longitude: f64) -> String {
+    format!("POINT ({} {})", longitude, latitude)
+}
+
+/// Point written as little endian Well-Known Binary
+pub fn wkb(latitude: f64, longitude: f64) -> Vec<u8> {
+    let mut bytes = vec![1];
+    bytes.extend_from_slice(&1_u32.to_le_bytes());
+    bytes.extend_from_slice(&longitude.to_le_bytes());
+    bytes.extend_from_slice(&latitude.to_le_bytes());
+    bytes
+}
+
+pub fn round(value: f64, precision: u32) -> f64 {
+    let factor = 10_f64.powi(precision as i32);
+    (value * factor).round() / factor
+}
+
+/// Latitude above 90 or below -90
+pub fn invalid_latitude() -> f64 {
+    let latitude = 90.0 + rng::f64_range(1.0..90.0);
+    match rng::bool() {
+        true => latitude,
+        false => -latitude,
+    }
+}
+
+/// Longitude above 180 or below -180
+fn invalid_longitude() -> f64 {
+    let longitude = 180.0 + rng::f64_range(1.0..180.0);
+    match rng::bool() {
+        true => longitude,
+        false => -longitude,
+    }
+}
+
+pub fn precision_from_yaml(column: &Yaml) -> Result<u32, FakeLakeError> {
+    match &column["precision"] {
+        Yaml::BadValue => Ok(DEFAULT_PRECISION),
+        Yaml::Integer(value) if (0..=MAX_PRECISION as i64).contains(value) => Ok(*value as u32),
+        _ => Err(FakeLakeError::BadYAMLFormat(format!(
+            "Column {} param precision should be an integer between 0 and {}.",
+            get_column_name(column),
+            MAX_PRECISION
+        ))),
+    }
+}
+
+#[derive(Clone)]
+pub struct PointProvider {
+    pub kind: Kind,
+    pub area: Area,
+    /// Decimals of the coordinates
+    pub precision: u32,
+}
+
+impl PointProvider {
+    fn value_of(&self, latitude: f64, longitude: f64) -> Value {
+        let (latitude, longitude) = (
+            round(latitude, self.precision),
+            round(longitude, self.precision),
+        );
+        match self.kind {
+            Kind::Latitude => Value::Float64(latitude),
+            Kind::Longitude => Value::Float64(longitude),
+            Kind::Point => Value::Point(latitude, longitude),
+            Kind::WktPoint => Value::String(wkt(latitude, longitude)),
+        }
+    }
+}
+
+impl Provider for PointProvider {
+    fn value(&self, _: u32) -> Value {
+        let (latitude, longitude) = self.area.random_point();
+        self.value_of(latitude, longitude)
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        // coordinates out of the range of latitudes or longitudes
+        let (latitude, longitude) = self.area.random_point();
+        match self.kind {
+            Kind::Longitude => self.value_of(latitude, invalid_longitude()),
+            _ => self.value_of(invalid_latitude(), longitude),
+        }
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml, kind: Kind) -> Result<Box<PointProvider>, FakeLakeError> {
+    Ok(Box::new(PointProvider {
+        kind,
+        area: Area::new_from_yaml(column)?,
+        precision: precision_from_yaml(column)?,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_from_yaml, round, wkb, wkt, Kind, PointProvider};
+    use crate::providers::geo::area::{distance_km, Area};
+    use crate::providers::provider::{Provider, Value};
+
+    use regex::Regex;
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider(yaml_str: &str, kind: Kind) -> Box<PointProvider> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        new_from_yaml(&yaml[0], kind).unwrap()
+    }
+
+    fn float_value(value: Value) -> f64 {
+        match value {
+            Value::Float64(value) => value,
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_no_params_should_use_defaults() {
+        let provider = generate_provider("name: a", Kind::Point);
+        assert_eq!(provider.precision, 6);
+        assert!(matches!(provider.area, Area::BoundingBox { .. }));
+    }
+
+    #[test]
+    fn given_wrong_params_should_return_err() {
+        for params in ["precision: 16", "precision: -1", "precision: a", "bbox: 1"] {
+            let yaml = YamlLoader::load_from_str(&format!("name: a\n{}", params)).unwrap();
+            assert!(
+                new_from_yaml(&yaml[0], Kind::Point).is_err(),
+                "{} should be wrong",
+                params
+            );
+        }
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_point_should_return_wkt_and_wkb() {
+        assert_eq!(wkt(48.8566, 2.3522), "POINT (2.3522 48.8566)");
+
+        let bytes = wkb(48.8566, 2.3522);
+        assert_eq!(bytes.len(), 21);
+        assert_eq!(bytes[..5], [1, 1, 0, 0, 0]);
+        assert_eq!(f64::from_le_bytes(bytes[5..13].try_into().unwrap()), 2.3522);
+        assert_eq!(f64::from_le_bytes(bytes[13..].try_into().unwrap()), 48.8566);
+    }
+
+    #[test]
+    fn given_precision_should_round_coordinates() {
+        assert_eq!(round(48.856_613, 2), 48.86);
+        let provider = generate_provider("name: a\nprecision: 2", Kind::Latitude);
+        for i in 0..50 {
+            let value = float_value(provider.value(i));
+            assert_eq!(value, round(value, 2));
+        }
+    }
+
+    #[test]
+    fn given_kinds_should_return_coordinates_of_area() {
+        let yaml = "name: a\nbbox: [2.2, 48, 2.5, 49]";
+        let latitude = generate_provider(yaml, Kind::Latitude);
+        let longitude = generate_provider(yaml, Kind::Longitude);
+        for i in 0..50 {
+            assert!((48.0..=49.0).contains(&float_value(latitude.value(i))));
+            assert!((2.2..=2.5).contains(&float_value(longitude.value(i))));
+        }
+
+        let provider = generate_provider(
+            "name: a\ncenter: {latitude: 48.85, longitude: 2.35}\nradius_km: 3",
+            Kind::Point,
+        );
+        for i in 0..50 {
+            match provider.value(i) {
+                Value::Point(latitude, longitude) => {
+                    assert!(distance_km((48.85, 2.35), (latitude, longitude)) < 3.01)
+                }
+                _ => panic!("Wrong type"),
+            }
+        }
+
+        let provider = generate_provider(yaml, Kind::WktPoint);
+        let re = Regex::new(r"^POINT \(2\.[2-5]\d* 4[89](\.\d+)?\)$").unwrap();
+        for i in 0..50 {
+            match provider.value(i) {
+                Value::String(value) => assert!(re.is_match(&value), "{}", value),
+                _ => panic!("Wrong type"),
+            }
+        }
+    }
+
+    #[test]
+    fn given_corrupted_should_return_coordinates_out_of_range() {
+        let latitude = generate_provider("name: a", Kind::Latitude);
+        let longitude = generate_provider("name: a", Kind::Longitude);
+        let point = generate_provider("name: a", Kind::Point);
+        for i in 0..50 {
+            assert!(float_value(latitude.corrupted_value(i)).abs() > 90.0);
+            assert!(float_value(longitude.corrupted_value(i)).abs() > 180.0);
+            match point.corrupted_value(i) {
+                Value::Point(latitude, _) => assert!(latitude.abs() > 90.0),
+                _ => panic!("Wrong type"),
+            }
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use crate::errors::FakeLakeError;
+use crate::providers::parameters::get_column_name;
+use crate::providers::parameters::urange::URangeParameter;
+use crate::providers::provider::{Provider, Value};
+use crate::rng;
+
+use super::area::{destination, Area};
+use super::point::{precision_from_yaml, round};
+
+use std::f64::consts::TAU;
+use yaml_rust::Yaml;
+
+/// Vertices of a polygon, the max being excluded
+const DEFAULT_VERTICES: (u32, u32) = (5, 9);
+const DEFAULT_SIZE_KM: f64 = 1.0;
+
+/// Polygon written as Well-Known Text, the ring being closed by its first point
+pub fn wkt(ring: &[(f64, f64)]) -> String {
+    let points: Vec<String> = ring
+        .iter()
+        .map(|(latitude, longitude)| format!("{} {}", longitude, latitude))
+        .collect();
+    format!("POLYGON (({}))", points.join(", "))
+}
+
+#[derive(Clone)]
+pub struct PolygonProvider {
+    /// Where the centers of the polygons are drawn
+    pub area: Area,
+    pub precision: u32,
+    /// Range of the number of vertices, the max being excluded
+    pub vertices: (u32, u32),
+    /// Distance between the center and the farthest vertices
+    pub size_km: f64,
+}
+
+impl PolygonProvider {
+    /// Closed ring of vertices around a random center, counterclockwise
+    fn ring(&self) -> Vec<(f64, f64)> {
+        let (latitude, longitude) = self.area.random_point();
+        let vertices = rng::u32(self.vertices.0..self.vertices.1);
+        let sector = TAU / vertices as f64;
+
+        // one vertex per sector keeps the polygon simple
+        let mut ring: Vec<(f64, f64)> = (0..vertices)
+            .map(|i| {
+                let bearing = TAU - (i as f64 + rng::f64()) * sector;
+                let distance = self.size_km * rng::f64_range(0.5..1.0);
+                let (latitude, longitude) = destination(latitude, longitude, bearing, distance);
+                (
+                    round(latitude, self.precision),
+                    round(longitude, self.precision),
+                )
+            })
+            .collect();
+        ring.push(ring[0]);
+        ring
+    }
+}
+
+impl Provider for PolygonProvider {
+    fn value(&self, _: u32) -> Value {
+        Value::String(wkt(&self.ring()))
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        // the ring is not closed
+        let mut ring = self.ring();
+        ring.pop();
+        Value::String(wkt(&ring))
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml) -> Result<Box<PolygonProvider>, FakeLakeError> {
+    let column_name = get_column_name(column);
+
+    let vertices =
+        URangeParameter::with_default_range(column, "vertices", DEFAULT_VERTICES).range();
+    if vertices.0 < 3 {
+        return Err(FakeLakeError::BadYAMLFormat(format!(
+            "Column {} param vertices should be at least 3.",
+            column_name
+        )));
+    }
+
+    let size_km = match &column["size_km"] {
+        Yaml::BadValue => Some(DEFAULT_SIZE_KM),
+        Yaml::Real(value) => value.parse::<f64>().ok(),
+        Yaml::Integer(value) => Some(*value as f64),
+        _ => None,
+    };
+    let size_km = match size_km {
+        Some(value) if value > 0.0 => value,
+        _ => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} param size_km should be a positive number.",
+                column_name
+            )))
+        }
+    };
+
+    Ok(Box::new(PolygonProvider {
+        area: Area::new_from_yaml(column)?,
+        precision: precision_from_yaml(column)?,
+        vertices,
+        size_km,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_from_yaml, wkt, PolygonProvider};
+    use crate::providers::geo::area::distance_km;
+    use crate::providers::provider::{Provider, Value};
+
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider(yaml_str: &str) -> Box<PolygonProvider> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        new_from_yaml(&yaml[0]).unwrap()
+    }
+
+    fn string_value(value: Value) -> String {
+        match value {
+            Value::String(value) => value,
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    /// Latitudes and longitudes of a WKT polygon
+    fn points(value: &str) -> Vec<(f64, f64)> {
+        value
+            .trim_start_matches("POLYGON ((")
+            .trim_end_matches("))")
+            .split(", ")
+            .map(|point| {
+                let (longitude, latitude) = point.split_once(' ').unwrap();
+                (latitude.parse().unwrap(), longitude.parse().unwrap())
+            })
+            .collect()
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_no_params_should_use_defaults() {
+        let provider = generate_provider("name: a");
+        assert_eq!(provider.vertices, (5, 9));
+        assert_eq!(provider.size_km, 1.0);
+    }
+
+    #[test]
+    fn given_wrong_params_should_return_err() {
+        for params in ["vertices: 2", "size_km: 0", "size_km: a", "precision: 20"] {
+            let yaml = YamlLoader::load_from_str(&format!("name: a\n{}", params)).unwrap();
+            assert!(
+                new_from_yaml(&yaml[0]).is_err(),
+                "{} should be wrong",
+                params
+            );
+        }
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_ring_should_return_wkt() {
+        assert_eq!(
+            wkt(&[(1.0, 2.0), (1.5, 3.0), (0.5, 2.5), (1.0, 2.0)]),
+            "POLYGON ((2 1, 3 1.5, 2.5 0.5, 2 1))"
+        );
+    }
+
+    #[test]
+    fn given_params_should_return_closed_polygons_around_center() {
+        let provider = generate_provider(
+            "name: a\ncenter: {latitude: 48.85, longitude: 2.35}\nradius_km: 2\nvertices: 4..7\nsize_km: 0.5",
+        );
+        for i in 0..50 {
+            let value = string_value(provider.value(i));
+            let points = points(&value);
+            assert!((5..8).contains(&points.len()), "{}", value);
+            assert_eq!(points[0], points[points.len() - 1]);
+            for point in points {
+                assert!(distance_km((48.85, 2.35), point) < 2.51);
+            }
+        }
+    }
+
+    #[test]
+    fn given_corrupted_should_return_unclosed_polygons() {
+        let provider = generate_provider("name: a");
+        for i in 0..50 {
+            let points = points(&string_value(provider.corrupted_value(i)));
+            assert_ne!(points[0], points[points.len() - 1]);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use crate::errors::FakeLakeError;
+use crate::providers::provider::{unknown_provider, Provider};
+
+use super::hash::{self, Algorithm};
+
+use yaml_rust::Yaml;
+
+const AVAILABLE: &[&str] = &["hash.md5", "hash.sha256"];
+
+pub fn get_corresponding_provider(
+    mut provider_split: std::str::Split<'_, char>,
+    column: &Yaml,
+) -> Result<Box<dyn Provider>, FakeLakeError> {
+    match provider_split.next() {
+        Some("md5") => Ok(hash::new_from_yaml(column, Algorithm::Md5)?),
+        Some("sha256") => Ok(hash::new_from_yaml(column, Algorithm::Sha256)?),
+        other => Err(unknown_provider("hash", other, AVAILABLE)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::get_corresponding_provider;
+
+    use yaml_rust::YamlLoader;
+
+    #[test]
+    fn given_hash_providers_should_return_provider() {
+        for provider_name in ["md5", "sha256"] {
+            let yaml_str = format!("name: checksum{}provider: {}", '\n', provider_name);
+            let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+            let provider_split = provider_name.split('.');
+            match get_corresponding_provider(provider_split, column) {
+                Ok(_) => (),
+                _ => panic!("{} should return a provider", provider_name),
+            }
+        }
+    }
+
+    #[test]
+    fn given_wrong_provider_should_return_error() {
+        let provider_name = "sha1";
+        let yaml_str = format!("name: checksum{}provider: {}", '\n', provider_name);
+        let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+        let provider_split = provider_name.split('.');
+        match get_corresponding_provider(provider_split, column) {
+            Err(_) => (),
+            _ => panic!(),
+        }
+    }
+}
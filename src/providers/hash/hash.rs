@@ -0,0 +1,276 @@
+use crate::errors::FakeLakeError;
+use crate::providers::expression::evaluation::to_string;
+use crate::providers::parameters::encoding::Encoding;
+use crate::providers::parameters::get_column_name;
+use crate::providers::provider::{ColumnType, Provider, Value};
+use crate::row;
+
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use yaml_rust::Yaml;
+
+/// Length of the random input hashed when there is no column
+const RANDOM_INPUT_LENGTH: usize = 32;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Algorithm {
+    Md5,
+    Sha256,
+}
+
+impl Algorithm {
+    pub fn digest(&self, input: &[u8]) -> Vec<u8> {
+        match self {
+            Algorithm::Md5 => md5::compute(input).0.to_vec(),
+            Algorithm::Sha256 => Sha256::digest(input).to_vec(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct HashProvider {
+    pub algorithm: Algorithm,
+    /// Column of the same row whose value is hashed, random bytes being hashed when None
+    pub column: Option<String>,
+    pub encoding: Encoding,
+    /// Whether the hashed column can be null, its hash being null too
+    pub nullable: bool,
+}
+
+impl HashProvider {
+    /// Bytes to hash for the current row, None if the hashed column is null.
+    /// Bytes are hashed as they are, the other values as their text.
+    fn input(&self) -> Option<Vec<u8>> {
+        match &self.column {
+            Some(column) => match row::get(column)? {
+                Value::Bytes(bytes, _) => Some(bytes),
+                Value::Null => None,
+                value => Some(to_string(&value).into_bytes()),
+            },
+            None => Some(crate::rng::bytes(RANDOM_INPUT_LENGTH)),
+        }
+    }
+}
+
+impl Provider for HashProvider {
+    fn value(&self, _: u32) -> Value {
+        match self.input() {
+            Some(input) => self.encoding.value(self.algorithm.digest(&input), false),
+            None => Value::Null,
+        }
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        // digests missing their last byte
+        let input = self
+            .input()
+            .unwrap_or_else(|| crate::rng::bytes(RANDOM_INPUT_LENGTH));
+        let mut digest = self.algorithm.digest(&input);
+        digest.pop();
+        self.encoding.value(digest, false)
+    }
+    fn sample_value(&self) -> Value {
+        self.encoding.value(self.algorithm.digest(&[]), false)
+    }
+    fn dependencies(&self) -> Vec<String> {
+        match &self.column {
+            Some(column) => vec![column.clone()],
+            None => Vec::new(),
+        }
+    }
+    fn bind(&mut self, columns: &HashMap<String, ColumnType>) -> Result<(), FakeLakeError> {
+        if let Some(column) = &self.column {
+            self.nullable = columns.get(column).is_none_or(|column| column.nullable);
+        }
+        Ok(())
+    }
+    fn can_be_null(&self) -> bool {
+        self.nullable
+    }
+}
+
+pub fn new_from_yaml(
+    column: &Yaml,
+    algorithm: Algorithm,
+) -> Result<Box<HashProvider>, FakeLakeError> {
+    let hashed_column = match &column["column"] {
+        Yaml::BadValue => None,
+        Yaml::String(name) => Some(name.clone()),
+        _ => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} param column should be the name of a column.",
+                get_column_name(column)
+            )))
+        }
+    };
+
+    Ok(Box::new(HashProvider {
+        algorithm,
+        column: hashed_column,
+        encoding: Encoding::from_yaml(column, Encoding::Hex)?,
+        nullable: false,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_from_yaml, Algorithm, HashProvider};
+    use crate::providers::parameters::encoding::Encoding;
+    use crate::providers::provider::{ColumnType, Provider, Value};
+    use crate::row;
+
+    use regex::Regex;
+    use std::collections::HashMap;
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider(yaml_str: &str, algorithm: Algorithm) -> Box<HashProvider> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        new_from_yaml(&yaml[0], algorithm).unwrap()
+    }
+
+    fn string_value(value: Value) -> String {
+        match value {
+            Value::String(value) => value,
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    // Parquet type
+    #[test]
+    fn given_nothing_should_return_string_type() {
+        let provider = generate_provider("name: checksum", Algorithm::Md5);
+        assert!(matches!(provider.value(0), Value::String(_)));
+        assert!(matches!(provider.sample_value(), Value::String(_)));
+    }
+
+    #[test]
+    fn given_raw_encoding_should_return_bytes_type() {
+        let provider = generate_provider("name: checksum\nencoding: raw", Algorithm::Sha256);
+        assert!(matches!(provider.sample_value(), Value::Bytes(_, false)));
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_no_params_should_hash_random_input_in_hex() {
+        let provider = generate_provider("name: checksum", Algorithm::Sha256);
+        assert_eq!(provider.column, None);
+        assert_eq!(provider.encoding, Encoding::Hex);
+        assert!(provider.dependencies().is_empty());
+        assert!(!provider.can_be_null());
+    }
+
+    #[test]
+    fn given_params_should_use_them() {
+        let provider = generate_provider(
+            "name: checksum\ncolumn: email\nencoding: base64",
+            Algorithm::Md5,
+        );
+        assert_eq!(provider.column, Some("email".to_string()));
+        assert_eq!(provider.encoding, Encoding::Base64);
+        assert_eq!(provider.dependencies(), vec!["email"]);
+    }
+
+    #[test]
+    fn given_wrong_params_should_return_err() {
+        for params in ["column: 1", "column: [a]", "encoding: sha"] {
+            let yaml = YamlLoader::load_from_str(&format!("name: checksum\n{}", params)).unwrap();
+            assert!(
+                new_from_yaml(&yaml[0], Algorithm::Md5).is_err(),
+                "{}",
+                params
+            );
+        }
+    }
+
+    #[test]
+    fn given_nullable_column_should_be_nullable() {
+        let mut provider = generate_provider("name: checksum\ncolumn: email", Algorithm::Md5);
+        let mut columns = HashMap::new();
+        columns.insert(
+            "email".to_string(),
+            ColumnType {
+                sample: Value::String("a@b.c".to_string()),
+                nullable: false,
+            },
+        );
+        provider.bind(&columns).unwrap();
+        assert!(!provider.can_be_null());
+
+        columns.get_mut("email").unwrap().nullable = true;
+        provider.bind(&columns).unwrap();
+        assert!(provider.can_be_null());
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_column_should_hash_its_value() {
+        let md5 = generate_provider("name: checksum\ncolumn: hash_email", Algorithm::Md5);
+        let sha256 = generate_provider("name: checksum\ncolumn: hash_email", Algorithm::Sha256);
+
+        row::set("hash_email", Some(Value::String("abc".to_string())));
+        assert_eq!(
+            string_value(md5.value(0)),
+            "900150983cd24fb0d6963f7d28e17f72"
+        );
+        assert_eq!(
+            string_value(sha256.value(0)),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+
+        // bytes are hashed as they are, other values as their text
+        row::set("hash_email", Some(Value::Bytes(b"abc".to_vec(), false)));
+        assert_eq!(
+            string_value(md5.value(0)),
+            "900150983cd24fb0d6963f7d28e17f72"
+        );
+        row::set("hash_email", Some(Value::Int32(42)));
+        assert_eq!(
+            string_value(md5.value(0)),
+            "a1d0c6e83f027327d8461063f4ac58a6"
+        );
+
+        row::set("hash_email", None);
+        assert_eq!(md5.value(0), Value::Null);
+    }
+
+    #[test]
+    fn given_encoding_should_encode_digest() {
+        row::set("hash_payload", Some(Value::String("abc".to_string())));
+        let provider = generate_provider(
+            "name: checksum\ncolumn: hash_payload\nencoding: base64",
+            Algorithm::Md5,
+        );
+        assert_eq!(string_value(provider.value(0)), "kAFQmDzST7DWlj99KOF/cg==");
+
+        let provider = generate_provider(
+            "name: checksum\ncolumn: hash_payload\nencoding: raw",
+            Algorithm::Sha256,
+        );
+        match provider.value(0) {
+            Value::Bytes(value, _) => assert_eq!(value.len(), 32),
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    #[test]
+    fn given_random_input_should_return_different_digests() {
+        let provider = generate_provider("name: checksum", Algorithm::Sha256);
+        let re = Regex::new(r"^[0-9a-f]{64}$").unwrap();
+        let first = string_value(provider.value(0));
+        assert!(re.is_match(&first), "{}", first);
+        assert_ne!(first, string_value(provider.value(1)));
+    }
+
+    // Corrupted value
+    #[test]
+    fn given_corrupted_should_return_truncated_digest() {
+        row::set("hash_corrupted", Some(Value::String("abc".to_string())));
+        let provider = generate_provider("name: checksum\ncolumn: hash_corrupted", Algorithm::Md5);
+        assert_eq!(
+            string_value(provider.corrupted_value(0)),
+            "900150983cd24fb0d6963f7d28e17f"
+        );
+
+        let provider = generate_provider("name: checksum", Algorithm::Sha256);
+        assert_eq!(string_value(provider.corrupted_value(0)).len(), 62);
+    }
+}
@@ -0,0 +1,175 @@
+use crate::errors::FakeLakeError;
+use crate::providers::parameters::country::countries_from_yaml;
+use crate::providers::parameters::wstring::{weighted_random, WStringParameter};
+use crate::providers::provider::{Provider, Value};
+use crate::providers::utils::checksum::{from_mask, gtin_digit, other_digit};
+use crate::rng;
+
+use yaml_rust::Yaml;
+
+/// GS1 prefixes of the countries, the max being included
+const GS1_PREFIXES: &[(&str, &[(u32, u32)])] = &[
+    ("BR", &[(789, 790)]),
+    ("CN", &[(690, 699)]),
+    ("DE", &[(400, 440)]),
+    ("ES", &[(840, 849)]),
+    ("FR", &[(300, 379)]),
+    ("GB", &[(500, 509)]),
+    ("IT", &[(800, 839)]),
+    ("JP", &[(450, 459), (490, 499)]),
+    ("US", &[(0, 19), (30, 39), (60, 139)]),
+];
+
+/// First digits of the UPC, the number system
+const UPC_NUMBER_SYSTEMS: &[char] = &['0', '1', '6', '7', '8', '9'];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Kind {
+    Ean13,
+    Upc,
+    Isbn13,
+}
+
+fn gs1_prefix(country: &str) -> u32 {
+    let ranges = GS1_PREFIXES
+        .iter()
+        .find(|(code, _)| *code == country)
+        .map(|(_, ranges)| *ranges)
+        .unwrap_or_else(|| panic!("Country {} should have GS1 prefixes", country));
+    let (min, max) = ranges[rng::usize(..ranges.len())];
+    rng::u32(min..max + 1)
+}
+
+#[derive(Clone)]
+pub struct BarcodeProvider {
+    pub kind: Kind,
+    /// Countries of the GS1 prefixes of the EAN-13
+    pub countries: Vec<WStringParameter>,
+}
+
+impl BarcodeProvider {
+    /// Digits of the barcode followed by the check digit, or another digit
+    fn barcode(&self, valid: bool) -> String {
+        let digits = match self.kind {
+            Kind::Ean13 => format!(
+                "{:03}{}",
+                gs1_prefix(weighted_random(&self.countries)),
+                from_mask("#########")
+            ),
+            Kind::Upc => format!(
+                "{}{}",
+                UPC_NUMBER_SYSTEMS[rng::usize(..UPC_NUMBER_SYSTEMS.len())],
+                from_mask("##########")
+            ),
+            Kind::Isbn13 => format!("97{}{}", rng::u32(8..10), from_mask("#########")),
+        };
+        let check = match valid {
+            true => gtin_digit(&digits),
+            false => other_digit(gtin_digit(&digits)),
+        };
+        format!("{}{}", digits, check)
+    }
+}
+
+impl Provider for BarcodeProvider {
+    fn value(&self, _: u32) -> Value {
+        Value::String(self.barcode(true))
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        // barcodes with a wrong check digit
+        Value::String(self.barcode(false))
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml, kind: Kind) -> Result<Box<BarcodeProvider>, FakeLakeError> {
+    let countries = match kind {
+        Kind::Ean13 => {
+            let supported: Vec<&str> = GS1_PREFIXES.iter().map(|(code, _)| *code).collect();
+            countries_from_yaml(column, Some(&supported))?
+        }
+        Kind::Upc | Kind::Isbn13 => Vec::new(),
+    };
+    Ok(Box::new(BarcodeProvider { kind, countries }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_from_yaml, BarcodeProvider, Kind};
+    use crate::providers::provider::{Provider, Value};
+    use crate::providers::utils::checksum::gtin_digit;
+
+    use regex::Regex;
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider(yaml_str: &str, kind: Kind) -> Box<BarcodeProvider> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        new_from_yaml(&yaml[0], kind).unwrap()
+    }
+
+    fn string_value(value: Value) -> String {
+        match value {
+            Value::String(value) => value,
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    fn is_valid(value: &str) -> bool {
+        let (digits, check) = value.split_at(value.len() - 1);
+        gtin_digit(digits) == check.parse::<u32>().unwrap()
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_no_country_should_use_country_of_locale() {
+        let provider = generate_provider("name: ean\nlocale: ja_JP", Kind::Ean13);
+        assert_eq!(provider.countries[0].value, "JP");
+
+        let provider = generate_provider("name: upc\ncountry: XX", Kind::Upc);
+        assert!(provider.countries.is_empty());
+    }
+
+    #[test]
+    fn given_wrong_country_should_return_err() {
+        let yaml = YamlLoader::load_from_str("name: ean\ncountry: NL").unwrap();
+        assert!(new_from_yaml(&yaml[0], Kind::Ean13).is_err());
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_known_barcodes_should_validate_them() {
+        assert!(is_valid("4006381333931"));
+        assert!(is_valid("036000291452"));
+        assert!(is_valid("9780306406157"));
+        assert!(!is_valid("9780306406158"));
+    }
+
+    #[test]
+    fn given_kinds_should_return_valid_barcodes() {
+        for (params, kind, pattern) in [
+            (
+                "country: [FR, DE]",
+                Kind::Ean13,
+                r"^(3[0-7]\d|4[0-3]\d|440)\d{10}$",
+            ),
+            (
+                "country: US",
+                Kind::Ean13,
+                r"^(0[0-1]\d|03\d|0[6-9]\d|1[0-3]\d)\d{10}$",
+            ),
+            ("", Kind::Upc, r"^[016-9]\d{11}$"),
+            ("", Kind::Isbn13, r"^97[89]\d{10}$"),
+        ] {
+            let provider = generate_provider(&format!("name: code\n{}", params), kind);
+            let re = Regex::new(pattern).unwrap();
+            for i in 0..100 {
+                let value = string_value(provider.value(i));
+                assert!(re.is_match(&value), "{} does not match {}", value, pattern);
+                assert!(is_valid(&value), "{} should be valid", value);
+
+                let value = string_value(provider.corrupted_value(i));
+                assert!(re.is_match(&value), "{} does not match {}", value, pattern);
+                assert!(!is_valid(&value), "{} should be invalid", value);
+            }
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use crate::errors::FakeLakeError;
+use crate::providers::provider::{unknown_provider, Provider};
+
+use super::{barcode, company, national};
+
+use yaml_rust::Yaml;
+
+const AVAILABLE: &[&str] = &[
+    "id.ean13",
+    "id.isbn13",
+    "id.national",
+    "id.siren",
+    "id.siret",
+    "id.upc",
+];
+
+pub fn get_corresponding_provider(
+    mut provider_split: std::str::Split<'_, char>,
+    column: &Yaml,
+) -> Result<Box<dyn Provider>, FakeLakeError> {
+    match provider_split.next() {
+        Some("ean13") => Ok(barcode::new_from_yaml(column, barcode::Kind::Ean13)?),
+        Some("isbn13") => Ok(barcode::new_from_yaml(column, barcode::Kind::Isbn13)?),
+        Some("national") => Ok(national::new_from_yaml(column)?),
+        Some("siren") => Ok(company::new_from_yaml(column, company::Kind::Siren)),
+        Some("siret") => Ok(company::new_from_yaml(column, company::Kind::Siret)),
+        Some("upc") => Ok(barcode::new_from_yaml(column, barcode::Kind::Upc)?),
+        other => Err(unknown_provider("id", other, AVAILABLE)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::get_corresponding_provider;
+
+    use yaml_rust::YamlLoader;
+
+    #[test]
+    fn given_id_providers_should_return_provider() {
+        for provider_name in ["ean13", "isbn13", "national", "siren", "siret", "upc"] {
+            let yaml_str = format!("name: id{}provider: {}", '\n', provider_name);
+            let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+            let provider_split = provider_name.split('.');
+            match get_corresponding_provider(provider_split, column) {
+                Ok(_) => (),
+                _ => panic!("{} should return a provider", provider_name),
+            }
+        }
+    }
+
+    #[test]
+    fn given_wrong_provider_should_return_error() {
+        let provider_name = "not_a_provider";
+        let yaml_str = format!("name: id{}provider: {}", '\n', provider_name);
+        let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+        let provider_split = provider_name.split('.');
+        match get_corresponding_provider(provider_split, column) {
+            Err(_) => (),
+            _ => panic!(),
+        }
+    }
+}
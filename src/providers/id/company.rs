@@ -0,0 +1,93 @@
+use crate::providers::provider::{Provider, Value};
+use crate::providers::utils::checksum::{from_mask, luhn_digit, other_digit};
+
+use yaml_rust::Yaml;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Kind {
+    /// French company number of 9 digits
+    Siren,
+    /// SIREN followed by the 5 digits of an establishment
+    Siret,
+}
+
+#[derive(Clone)]
+pub struct CompanyProvider {
+    pub kind: Kind,
+}
+
+impl CompanyProvider {
+    /// Number whose last digit is the Luhn check digit, or another digit
+    fn number(&self, valid: bool) -> String {
+        let siren = from_mask("########");
+        let digits = match self.kind {
+            Kind::Siren => siren,
+            // the SIREN of a SIRET is valid too
+            Kind::Siret => format!("{}{}{}", siren, luhn_digit(&siren), from_mask("####")),
+        };
+        let check = match valid {
+            true => luhn_digit(&digits),
+            false => other_digit(luhn_digit(&digits)),
+        };
+        format!("{}{}", digits, check)
+    }
+}
+
+impl Provider for CompanyProvider {
+    fn value(&self, _: u32) -> Value {
+        Value::String(self.number(true))
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        // numbers failing the Luhn check
+        Value::String(self.number(false))
+    }
+}
+
+pub fn new_from_yaml(_: &Yaml, kind: Kind) -> Box<CompanyProvider> {
+    Box::new(CompanyProvider { kind })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_from_yaml, Kind};
+    use crate::providers::provider::{Provider, Value};
+    use crate::providers::utils::checksum::is_luhn_valid;
+
+    use yaml_rust::YamlLoader;
+
+    fn string_value(value: Value) -> String {
+        match value {
+            Value::String(value) => value,
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_known_numbers_should_validate_them() {
+        assert!(is_luhn_valid("732829320"));
+        assert!(is_luhn_valid("73282932000074"));
+        assert!(!is_luhn_valid("73282932000075"));
+    }
+
+    #[test]
+    fn given_kinds_should_return_valid_numbers() {
+        let yaml = YamlLoader::load_from_str("name: company").unwrap();
+        for (kind, length) in [(Kind::Siren, 9), (Kind::Siret, 14)] {
+            let provider = new_from_yaml(&yaml[0], kind);
+            for i in 0..100 {
+                let value = string_value(provider.value(i));
+                assert_eq!(value.len(), length);
+                assert!(value.chars().all(|c| c.is_ascii_digit()));
+                assert!(is_luhn_valid(&value), "{} should be valid", value);
+                if kind == Kind::Siret {
+                    assert!(is_luhn_valid(&value[..9]), "{} should have a SIREN", value);
+                }
+
+                let value = string_value(provider.corrupted_value(i));
+                assert_eq!(value.len(), length);
+                assert!(!is_luhn_valid(&value), "{} should be invalid", value);
+            }
+        }
+    }
+}
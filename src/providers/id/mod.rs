@@ -0,0 +1,5 @@
+pub mod builder;
+
+pub mod barcode;
+pub mod company;
+pub mod national;
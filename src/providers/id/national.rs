@@ -0,0 +1,277 @@
+use crate::errors::FakeLakeError;
+use crate::providers::parameters::country::countries_from_yaml;
+use crate::providers::parameters::wstring::{weighted_random, WStringParameter};
+use crate::providers::provider::{Provider, Value};
+use crate::providers::utils::checksum::{from_mask, mod11_10_digit, mod97, other_digit};
+use crate::rng;
+
+use yaml_rust::Yaml;
+
+const COUNTRIES: &[&str] = &["DE", "FR", "GB", "US"];
+
+/// Letters that can't be the first or the second one of a NINO prefix
+const NINO_FIRST_EXCLUDED: &str = "DFIQUV";
+const NINO_SECOND_EXCLUDED: &str = "DFIOQUV";
+const NINO_PREFIXES_EXCLUDED: &[&str] = &["BG", "GB", "KN", "NK", "NT", "TN", "ZZ"];
+
+/// French NIR without its key: sex, year and month of birth, department, commune and order
+fn nir_number() -> String {
+    let department = match rng::u32(1..95) {
+        // 20 was split into the 2A and 2B departments of Corsica
+        20 => 95,
+        department => department,
+    };
+    format!(
+        "{}{:02}{:02}{:02}{:03}{:03}",
+        rng::u32(1..3),
+        rng::u32(0..100),
+        rng::u32(1..13),
+        department,
+        rng::u32(1..991),
+        rng::u32(1..1000)
+    )
+}
+
+/// Key of a NIR, 97 minus the remainder of its division by 97
+pub fn nir_key(number: &str) -> u32 {
+    97 - mod97(number)
+}
+
+/// US SSN with an area, a group and a serial number
+fn ssn(valid: bool) -> String {
+    let area = match rng::u32(1..899) {
+        666 => 667,
+        area => area,
+    };
+    let (mut area, mut group, mut serial) = (area, rng::u32(1..100), rng::u32(1..10000));
+    if !valid {
+        // an area, a group or a serial number that is never assigned
+        match rng::u32(0..4) {
+            0 => area = 0,
+            1 => area = 666,
+            2 => group = 0,
+            _ => serial = 0,
+        }
+    }
+    format!("{:03}-{:02}-{:04}", area, group, serial)
+}
+
+fn nino_letter(excluded: &str) -> char {
+    loop {
+        let letter = char::from(b'A' + rng::u32(0..26) as u8);
+        if !excluded.contains(letter) {
+            return letter;
+        }
+    }
+}
+
+/// Whether the prefix of a NINO is one that can be assigned
+pub fn is_nino_prefix(prefix: &str) -> bool {
+    let mut letters = prefix.chars();
+    match (letters.next(), letters.next(), letters.next()) {
+        (Some(first), Some(second), None) => {
+            first.is_ascii_uppercase()
+                && second.is_ascii_uppercase()
+                && !NINO_FIRST_EXCLUDED.contains(first)
+                && !NINO_SECOND_EXCLUDED.contains(second)
+                && !NINO_PREFIXES_EXCLUDED.contains(&prefix)
+        }
+        _ => false,
+    }
+}
+
+/// UK National Insurance number with a prefix, six digits and a suffix from A to D
+fn nino(valid: bool) -> String {
+    let prefix = loop {
+        let prefix = format!(
+            "{}{}",
+            nino_letter(NINO_FIRST_EXCLUDED),
+            nino_letter(NINO_SECOND_EXCLUDED)
+        );
+        if is_nino_prefix(&prefix) {
+            break prefix;
+        }
+    };
+    let suffix = char::from(b'A' + rng::u32(0..4) as u8);
+    match (valid, rng::bool()) {
+        (true, _) => format!("{}{}{}", prefix, from_mask("######"), suffix),
+        // a prefix that is never assigned
+        (false, true) => format!(
+            "{}{}{}",
+            NINO_PREFIXES_EXCLUDED[rng::usize(..NINO_PREFIXES_EXCLUDED.len())],
+            from_mask("######"),
+            suffix
+        ),
+        // a suffix after D
+        (false, false) => format!(
+            "{}{}{}",
+            prefix,
+            from_mask("######"),
+            char::from(b'E' + rng::u32(0..22) as u8)
+        ),
+    }
+}
+
+/// First 10 digits of a German tax ID, one digit appearing twice and another one missing
+fn steuer_id_number() -> String {
+    let mut digits: Vec<char> = ('0'..='9').collect();
+    rng::with_rng(|rng| rng.shuffle(&mut digits));
+    digits.pop();
+    if digits[0] == '0' {
+        digits.swap(0, 1 + rng::usize(..8));
+    }
+    let twice = digits[rng::usize(..9)];
+    digits.insert(1 + rng::usize(..9), twice);
+    digits.into_iter().collect()
+}
+
+/// Identifier of a person in a country
+fn national_id(country: &str, valid: bool) -> String {
+    match country {
+        "DE" => {
+            let number = steuer_id_number();
+            let check = mod11_10_digit(&number);
+            let check = match valid {
+                true => check,
+                false => other_digit(check),
+            };
+            format!("{}{}", number, check)
+        }
+        "FR" => {
+            let number = nir_number();
+            let key = nir_key(&number);
+            let key = match valid {
+                true => key,
+                // another key, still between 01 and 97
+                false => 1 + (key - 1 + rng::u32(1..97)) % 97,
+            };
+            format!("{}{:02}", number, key)
+        }
+        "GB" => nino(valid),
+        "US" => ssn(valid),
+        _ => panic!("Country {} should have a national identifier", country),
+    }
+}
+
+#[derive(Clone)]
+pub struct NationalProvider {
+    pub countries: Vec<WStringParameter>,
+}
+
+impl Provider for NationalProvider {
+    fn value(&self, _: u32) -> Value {
+        Value::String(national_id(weighted_random(&self.countries), true))
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        // identifiers with a wrong check digit or key, or a part that is never assigned
+        Value::String(national_id(weighted_random(&self.countries), false))
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml) -> Result<Box<NationalProvider>, FakeLakeError> {
+    Ok(Box::new(NationalProvider {
+        countries: countries_from_yaml(column, Some(COUNTRIES))?,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_nino_prefix, new_from_yaml, nir_key, NationalProvider, COUNTRIES};
+    use crate::providers::provider::{Provider, Value};
+    use crate::providers::utils::checksum::mod11_10_digit;
+
+    use regex::Regex;
+    use std::collections::HashMap;
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider(yaml_str: &str) -> Box<NationalProvider> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        new_from_yaml(&yaml[0]).unwrap()
+    }
+
+    fn string_value(value: Value) -> String {
+        match value {
+            Value::String(value) => value,
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    fn is_valid_nir(value: &str) -> bool {
+        value.len() == 15 && nir_key(&value[..13]) == value[13..].parse::<u32>().unwrap()
+    }
+
+    fn is_valid_steuer_id(value: &str) -> bool {
+        let mut counts: HashMap<char, u32> = HashMap::new();
+        for c in value[..10].chars() {
+            *counts.entry(c).or_default() += 1;
+        }
+        value.len() == 11
+            && !value.starts_with('0')
+            && counts.values().filter(|count| **count == 2).count() == 1
+            && counts.len() == 9
+            && mod11_10_digit(&value[..10]) == value[10..].parse::<u32>().unwrap()
+    }
+
+    fn is_valid_nino(value: &str) -> bool {
+        let re = Regex::new(r"^[A-Z]{2}\d{6}[A-D]$").unwrap();
+        re.is_match(value) && is_nino_prefix(&value[..2])
+    }
+
+    fn is_valid_ssn(value: &str) -> bool {
+        let re = Regex::new(r"^\d{3}-\d{2}-\d{4}$").unwrap();
+        re.is_match(value)
+            && !["000", "666"].contains(&&value[..3])
+            && !value.starts_with('9')
+            && &value[4..6] != "00"
+            && &value[7..] != "0000"
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_no_country_should_use_country_of_locale() {
+        let provider = generate_provider("name: id\nlocale: en_GB");
+        assert_eq!(provider.countries[0].value, "GB");
+
+        let provider = generate_provider("name: id\nlocale: it_IT");
+        assert_eq!(provider.countries.len(), COUNTRIES.len());
+    }
+
+    #[test]
+    fn given_wrong_country_should_return_err() {
+        let yaml = YamlLoader::load_from_str("name: id\ncountry: IT").unwrap();
+        assert!(new_from_yaml(&yaml[0]).is_err());
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_known_identifiers_should_validate_them() {
+        assert!(is_valid_nir("269054958815780"));
+        assert!(!is_valid_nir("269054958815781"));
+        assert!(is_valid_steuer_id("86095742719"));
+        assert!(!is_valid_steuer_id("86095742718"));
+        assert!(is_valid_nino("AB123456C"));
+        assert!(!is_valid_nino("QQ123456C"));
+        assert!(!is_valid_nino("GB123456A"));
+        assert!(is_valid_ssn("123-45-6789"));
+        assert!(!is_valid_ssn("666-45-6789"));
+    }
+
+    #[test]
+    fn given_countries_should_return_valid_identifiers() {
+        for (country, is_valid) in [
+            ("FR", is_valid_nir as fn(&str) -> bool),
+            ("DE", is_valid_steuer_id),
+            ("GB", is_valid_nino),
+            ("US", is_valid_ssn),
+        ] {
+            let provider = generate_provider(&format!("name: id\ncountry: {}", country));
+            for i in 0..200 {
+                let value = string_value(provider.value(i));
+                assert!(is_valid(&value), "{} should be valid", value);
+
+                let value = string_value(provider.corrupted_value(i));
+                assert!(!is_valid(&value), "{} should be invalid", value);
+            }
+        }
+    }
+}
@@ -1,18 +1,26 @@
 use crate::errors::FakeLakeError;
 use crate::providers::provider::{unknown_provider, Provider};
 
-use super::integer;
+use super::{datetime, integer, string};
 
 use yaml_rust::Yaml;
 
-const AVAILABLE: &[&str] = &["increment.integer"];
+const AVAILABLE: &[&str] = &[
+    "increment.date",
+    "increment.datetime",
+    "increment.integer",
+    "increment.string",
+];
 
 pub fn get_corresponding_provider(
     mut provider_split: std::str::Split<'_, char>,
     column: &Yaml,
 ) -> Result<Box<dyn Provider>, FakeLakeError> {
     match provider_split.next() {
-        Some("integer") => Ok(integer::new_from_yaml(column)),
+        Some("date") => Ok(datetime::new_from_yaml(column, datetime::Kind::Date)?),
+        Some("datetime") => Ok(datetime::new_from_yaml(column, datetime::Kind::Datetime)?),
+        Some("integer") => Ok(integer::new_from_yaml(column)?),
+        Some("string") => Ok(string::new_from_yaml(column)?),
         other => Err(unknown_provider("increment", other, AVAILABLE)),
     }
 }
@@ -36,6 +44,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn given_date_datetime_and_string_should_return_provider() {
+        for provider_name in ["date", "datetime", "string"] {
+            let yaml_str = format!("name: id{}provider: {}", '\n', provider_name);
+            let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+            let provider_split = provider_name.split('.');
+            match get_corresponding_provider(provider_split, column) {
+                Ok(_) => (),
+                _ => panic!("{} should return a provider", provider_name),
+            }
+        }
+    }
+
     #[test]
     fn given_wrong_provider_should_return_error() {
         let provider_name = "not_a_provider";
@@ -0,0 +1,388 @@
+use crate::errors::FakeLakeError;
+use crate::providers::parameters::duration::Duration;
+use crate::providers::parameters::get_column_name;
+use crate::providers::parameters::timestamp::TimestampType;
+use crate::providers::provider::{Provider, Value};
+
+use chrono::{NaiveDate, NaiveDateTime, TimeDelta};
+use yaml_rust::Yaml;
+
+const DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d";
+const DEFAULT_DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+const DEFAULT_DATE_START: &str = "2024-01-01";
+const DEFAULT_DATETIME_START: &str = "2024-01-01 00:00:00";
+const DEFAULT_DATE_STEP: Duration = Duration::Seconds(86400);
+const DEFAULT_DATETIME_STEP: Duration = Duration::Seconds(3600);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Kind {
+    Date,
+    Datetime,
+}
+
+#[derive(Clone)]
+pub struct IncrementDatetimeProvider {
+    pub kind: Kind,
+    pub format: String,
+    pub start: NaiveDateTime,
+    pub step: Duration,
+    /// Max seconds randomly added to each value, in the direction of the step
+    pub jitter: i64,
+    /// Unit and timezone of datetimes, start being read in the timezone
+    pub timestamp_type: TimestampType,
+}
+
+impl IncrementDatetimeProvider {
+    /// Start plus some times the step, the min or max datetime when out of range
+    fn datetime(&self, times: i64) -> NaiveDateTime {
+        match self.step.add_to(self.start, times) {
+            Some(value) => value,
+            None if (self.step.min_seconds() >= 0) == (times >= 0) => NaiveDateTime::MAX,
+            None => NaiveDateTime::MIN,
+        }
+    }
+
+    fn jitter(&self) -> TimeDelta {
+        let jitter = match self.kind {
+            Kind::Date => 86400 * crate::rng::i64(0..self.jitter / 86400 + 1),
+            Kind::Datetime => crate::rng::i64(0..self.jitter + 1),
+        };
+        // part of a second in the unit of the timestamps, below the jitter
+        let per_second = self.timestamp_type.precision.per_second();
+        let units = match per_second > 1 && jitter < self.jitter {
+            true => crate::rng::i64(0..per_second),
+            false => 0,
+        };
+        let jitter = TimeDelta::seconds(jitter)
+            + TimeDelta::nanoseconds(units * (1_000_000_000 / per_second));
+        match self.step.min_seconds() < 0 {
+            true => -jitter,
+            false => jitter,
+        }
+    }
+
+    fn to_value(&self, datetime: NaiveDateTime) -> Value {
+        match self.kind {
+            Kind::Date => Value::Date(datetime.date(), self.format.clone()),
+            Kind::Datetime => Value::Timestamp(
+                self.timestamp_type.timestamp_of_local(datetime),
+                self.format.clone(),
+                self.timestamp_type,
+            ),
+        }
+    }
+}
+
+impl Provider for IncrementDatetimeProvider {
+    fn value(&self, index: u32) -> Value {
+        let datetime = self.datetime(index as i64);
+        let datetime = datetime
+            .checked_add_signed(self.jitter())
+            .unwrap_or(datetime);
+        self.to_value(datetime)
+    }
+    fn corrupted_value(&self, index: u32) -> Value {
+        // the value of an earlier row, out of order
+        let times = match index {
+            0 => -1,
+            _ => crate::rng::i64(0..index as i64),
+        };
+        self.to_value(self.datetime(times))
+    }
+}
+
+pub fn new_from_yaml(
+    column: &Yaml,
+    kind: Kind,
+) -> Result<Box<IncrementDatetimeProvider>, FakeLakeError> {
+    let column_name = get_column_name(column);
+    let wrong_param = |message: String| {
+        FakeLakeError::BadYAMLFormat(format!("Column {} param {}", column_name, message))
+    };
+
+    let (default_format, default_start, default_step) = match kind {
+        Kind::Date => (DEFAULT_DATE_FORMAT, DEFAULT_DATE_START, DEFAULT_DATE_STEP),
+        Kind::Datetime => (
+            DEFAULT_DATETIME_FORMAT,
+            DEFAULT_DATETIME_START,
+            DEFAULT_DATETIME_STEP,
+        ),
+    };
+
+    let timestamp_type = match kind {
+        Kind::Date => TimestampType::default(),
+        Kind::Datetime => TimestampType::from_yaml(column)?,
+    };
+
+    // the default format reads the start without fractional seconds nor offset
+    let (format, start_format) = match &column["format"] {
+        Yaml::BadValue => (
+            timestamp_type.default_format(default_format),
+            default_format.to_string(),
+        ),
+        Yaml::String(value) => (value.clone(), value.clone()),
+        _ => return Err(wrong_param("format should be a string.".to_string())),
+    };
+
+    let start = match &column["start"] {
+        Yaml::BadValue => Some(default_start),
+        value => value.as_str(),
+    };
+    let start = start.and_then(|start| match kind {
+        Kind::Date => NaiveDate::parse_from_str(start, &start_format)
+            .ok()
+            .and_then(|date| date.and_hms_opt(0, 0, 0)),
+        Kind::Datetime => NaiveDateTime::parse_from_str(start, &start_format).ok(),
+    });
+    let start = match start {
+        Some(value) => value,
+        None => {
+            return Err(wrong_param(format!(
+                "start should be written with the format {}.",
+                start_format
+            )))
+        }
+    };
+
+    let step = Duration::from_yaml(column, "step")?.unwrap_or(default_step);
+    if kind == Kind::Date && !step.is_whole_days() {
+        return Err(wrong_param("step should be whole days.".to_string()));
+    }
+
+    let jitter = match Duration::from_yaml(column, "jitter")? {
+        None => 0,
+        Some(Duration::Seconds(jitter))
+            if jitter >= 0
+                && jitter < step.min_seconds().abs()
+                && (kind == Kind::Datetime || jitter % 86400 == 0) =>
+        {
+            jitter
+        }
+        Some(_) => {
+            return Err(wrong_param(
+                "jitter should be positive and shorter than the step, in whole days for dates."
+                    .to_string(),
+            ))
+        }
+    };
+
+    Ok(Box::new(IncrementDatetimeProvider {
+        kind,
+        format,
+        start,
+        step,
+        jitter,
+        timestamp_type,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_from_yaml, IncrementDatetimeProvider, Kind};
+    use crate::providers::parameters::duration::Duration;
+    use crate::providers::parameters::timestamp::Precision;
+    use crate::providers::provider::{Provider, Value};
+
+    use chrono::{NaiveDate, NaiveDateTime};
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider(yaml_str: &str, kind: Kind) -> Box<IncrementDatetimeProvider> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        new_from_yaml(&yaml[0], kind).unwrap()
+    }
+
+    fn datetime(value: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    fn timestamp_value(value: Value) -> NaiveDateTime {
+        match value {
+            Value::Timestamp(value, ..) => value.naive_utc(),
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    fn date_value(value: Value) -> NaiveDate {
+        match value {
+            Value::Date(value, _) => value,
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    // Parquet type
+    #[test]
+    fn given_kinds_should_return_dates_and_timestamps() {
+        let provider = generate_provider("name: a", Kind::Date);
+        assert!(matches!(provider.value(0), Value::Date(_, _)));
+        let provider = generate_provider("name: a", Kind::Datetime);
+        assert!(matches!(provider.value(0), Value::Timestamp(..)));
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_no_params_should_use_defaults() {
+        let provider = generate_provider("name: a", Kind::Date);
+        assert_eq!(provider.format, "%Y-%m-%d");
+        assert_eq!(provider.start, datetime("2024-01-01 00:00:00"));
+        assert_eq!(provider.step, Duration::Seconds(86400));
+        assert_eq!(provider.jitter, 0);
+
+        let provider = generate_provider("name: a", Kind::Datetime);
+        assert_eq!(provider.format, "%Y-%m-%d %H:%M:%S");
+        assert_eq!(provider.step, Duration::Seconds(3600));
+    }
+
+    #[test]
+    fn given_params_should_use_them() {
+        let provider = generate_provider(
+            "name: a\nformat: \"%d/%m/%Y %H:%M\"\nstart: 15/03/2024 08:30\nstep: 15 minutes\njitter: 10 minutes",
+            Kind::Datetime,
+        );
+        assert_eq!(provider.start, datetime("2024-03-15 08:30:00"));
+        assert_eq!(provider.step, Duration::Seconds(900));
+        assert_eq!(provider.jitter, 600);
+    }
+
+    #[test]
+    fn given_precision_and_timezone_should_use_them() {
+        let provider = generate_provider(
+            "name: a\nstart: 2024-01-01 02:00:00\nprecision: ms\ntimezone: +02:00",
+            Kind::Datetime,
+        );
+        assert_eq!(provider.format, "%Y-%m-%d %H:%M:%S%.3f%:z");
+        assert_eq!(provider.timestamp_type.precision, Precision::Milliseconds);
+        match provider.value(0) {
+            Value::Timestamp(value, format, timestamp_type) => {
+                // start is read in the timezone
+                assert_eq!(value.naive_utc(), datetime("2024-01-01 00:00:00"));
+                assert_eq!(
+                    timestamp_type.format(&value, &format),
+                    "2024-01-01 02:00:00.000+02:00"
+                );
+            }
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    #[test]
+    fn given_wrong_params_should_return_err() {
+        for (params, kind) in [
+            ("start: 2024-13-01", Kind::Date),
+            ("start: 2024-01-01", Kind::Datetime),
+            ("format: 1", Kind::Date),
+            ("step: 1", Kind::Datetime),
+            ("step: 1 hour", Kind::Date),
+            ("step: 1 hour\njitter: 1 hour", Kind::Datetime),
+            ("step: 1 hour\njitter: -1 minute", Kind::Datetime),
+            ("step: 1 year\njitter: 1 month", Kind::Datetime),
+            ("step: 1 week\njitter: 12 hours", Kind::Date),
+            ("precision: m", Kind::Datetime),
+            ("timezone: Paris", Kind::Datetime),
+        ] {
+            let yaml = YamlLoader::load_from_str(&format!("name: a\n{}", params)).unwrap();
+            assert!(
+                new_from_yaml(&yaml[0], kind).is_err(),
+                "{} should be wrong",
+                params
+            );
+        }
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_step_should_increment_from_start() {
+        let provider = generate_provider(
+            "name: a\nstart: 2024-01-01 23:00:00\nstep: 15 minutes",
+            Kind::Datetime,
+        );
+        assert_eq!(
+            timestamp_value(provider.value(0)),
+            datetime("2024-01-01 23:00:00")
+        );
+        assert_eq!(
+            timestamp_value(provider.value(5)),
+            datetime("2024-01-02 00:15:00")
+        );
+
+        let provider = generate_provider("name: a\nstart: 2024-01-31\nstep: 1 month", Kind::Date);
+        assert_eq!(
+            date_value(provider.value(1)),
+            NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()
+        );
+        assert_eq!(
+            date_value(provider.value(2)),
+            NaiveDate::from_ymd_opt(2024, 3, 31).unwrap()
+        );
+
+        let provider = generate_provider("name: a\nstart: 2024-01-01\nstep: -1 week", Kind::Date);
+        assert_eq!(
+            date_value(provider.value(2)),
+            NaiveDate::from_ymd_opt(2023, 12, 18).unwrap()
+        );
+    }
+
+    #[test]
+    fn given_jitter_should_stay_monotonic() {
+        let provider = generate_provider(
+            "name: a\nstep: 1 minute\njitter: 59 seconds",
+            Kind::Datetime,
+        );
+        let values: Vec<NaiveDateTime> = (0..500)
+            .map(|i| timestamp_value(provider.value(i)))
+            .collect();
+        for (i, pair) in values.windows(2).enumerate() {
+            assert!(pair[0] < pair[1]);
+            let base = datetime("2024-01-01 00:00:00") + chrono::Duration::minutes(i as i64);
+            assert!(pair[0] >= base && pair[0] < base + chrono::Duration::minutes(1));
+        }
+        assert!(values
+            .iter()
+            .any(|value| value.and_utc().timestamp() % 60 != 0));
+    }
+
+    #[test]
+    fn given_precision_should_jitter_below_the_second() {
+        let provider = generate_provider(
+            "name: a\nstep: 1 second\njitter: 0 seconds\nprecision: us",
+            Kind::Datetime,
+        );
+        assert_eq!(
+            timestamp_value(provider.value(3)),
+            datetime("2024-01-01 00:00:03")
+        );
+
+        let provider = generate_provider(
+            "name: a\nstep: 2 seconds\njitter: 1 second\nprecision: us",
+            Kind::Datetime,
+        );
+        let values: Vec<NaiveDateTime> = (0..500)
+            .map(|i| timestamp_value(provider.value(i)))
+            .collect();
+        for (i, value) in values.iter().enumerate() {
+            let base = datetime("2024-01-01 00:00:00") + chrono::Duration::seconds(2 * i as i64);
+            assert!(*value >= base && *value <= base + chrono::Duration::seconds(1));
+        }
+        assert!(values
+            .iter()
+            .any(|value| value.and_utc().timestamp_subsec_micros() != 0));
+    }
+
+    #[test]
+    fn given_out_of_range_should_saturate() {
+        let provider = generate_provider("name: a\nstep: 1000 years", Kind::Datetime);
+        assert_eq!(timestamp_value(provider.value(1000)), NaiveDateTime::MAX);
+    }
+
+    // Corrupted value
+    #[test]
+    fn given_corrupted_should_return_earlier_values() {
+        let provider = generate_provider("name: a\nstart: 2024-01-01\nstep: 1 day", Kind::Date);
+        assert_eq!(
+            date_value(provider.corrupted_value(0)),
+            NaiveDate::from_ymd_opt(2023, 12, 31).unwrap()
+        );
+        for i in 1..50 {
+            assert!(date_value(provider.corrupted_value(i)) < date_value(provider.value(i)));
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use crate::errors::FakeLakeError;
+use crate::providers::parameters::get_column_name;
 use crate::providers::parameters::i32::I32Parameter;
 use crate::providers::provider::{Provider, Value};
 
@@ -6,37 +8,99 @@ use yaml_rust::Yaml;
 const DEFAULT_START: i32 = 0;
 const DEFAULT_STEP: i32 = 1;
 
+/// Number of values of the sequence before it goes out of the i32 range, None if it never does
+/// within the u32 rows
+fn overflow_cycle(start: i32, step: i32) -> Option<u32> {
+    let values = match step {
+        0 => return None,
+        step if step > 0 => (i32::MAX as i64 - start as i64) / step as i64 + 1,
+        step => (start as i64 - i32::MIN as i64) / -(step as i64) + 1,
+    };
+    u32::try_from(values).ok()
+}
+
 #[derive(Clone)]
 pub struct IncrementIntegerProvider {
     pub start: i32,
     pub step: i32,
+    /// Number of values before starting again from the start
+    pub cycle_after: Option<u32>,
 }
 
 impl Provider for IncrementIntegerProvider {
     fn value(&self, index: u32) -> Value {
-        Value::Int32(self.start + ((index as i32) * self.step))
+        let position = match self.cycle_after {
+            Some(cycle) => index % cycle,
+            None => index,
+        };
+        Value::Int32((self.start as i64 + position as i64 * self.step as i64) as i32)
     }
     fn corrupted_value(&self, _: u32) -> Value {
         // return random i32
         Value::Int32(crate::rng::i32(i32::MIN..i32::MAX))
     }
+    fn max_rows(&self) -> Option<u32> {
+        // without a cycle, the sequence should not go out of the i32 range
+        match self.cycle_after {
+            Some(_) => None,
+            None => overflow_cycle(self.start, self.step),
+        }
+    }
 }
 
-pub fn new_from_yaml(column: &Yaml) -> Box<IncrementIntegerProvider> {
-    let start_param = I32Parameter::new(column, "start", DEFAULT_START);
-    let step_param = I32Parameter::new(column, "step", DEFAULT_STEP);
+pub fn new_from_yaml(column: &Yaml) -> Result<Box<IncrementIntegerProvider>, FakeLakeError> {
+    let column_name = get_column_name(column);
+    let start = I32Parameter::new(column, "start", DEFAULT_START).value;
+    let step = I32Parameter::new(column, "step", DEFAULT_STEP).value;
 
-    Box::new(IncrementIntegerProvider {
-        start: start_param.value,
-        step: step_param.value,
-    })
+    let cycle_after = match (&column["cycle_after"], &column["max"]) {
+        (Yaml::BadValue, Yaml::BadValue) => None,
+        (Yaml::Integer(cycle_after), Yaml::BadValue) => {
+            // the last value of the cycle should not overflow
+            let last = |cycle_after: u32| start as i64 + (cycle_after as i64 - 1) * step as i64;
+            match u32::try_from(*cycle_after) {
+                Ok(cycle_after) if cycle_after > 0 && i32::try_from(last(cycle_after)).is_ok() => {
+                    Some(cycle_after)
+                }
+                _ => {
+                    return Err(FakeLakeError::BadYAMLFormat(format!(
+                        "Column {} param cycle_after should be a positive number of values, all of them being i32.",
+                        column_name
+                    )))
+                }
+            }
+        }
+        (Yaml::BadValue, Yaml::Integer(max)) => {
+            match step > 0 && *max >= start as i64 && *max <= i32::MAX as i64 {
+                true => u32::try_from((max - start as i64) / step as i64 + 1).ok(),
+                false => {
+                    return Err(FakeLakeError::BadYAMLFormat(format!(
+                        "Column {} param max should be an i32 not less than start, with a positive step.",
+                        column_name
+                    )))
+                }
+            }
+        }
+        _ => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} should specify either an integer cycle_after or an integer max.",
+                column_name
+            )))
+        }
+    };
+
+    Ok(Box::new(IncrementIntegerProvider {
+        start,
+        step,
+        cycle_after,
+    }))
 }
 
 #[cfg(test)]
 mod tests {
     use core::panic;
 
-    use super::{IncrementIntegerProvider, DEFAULT_START, DEFAULT_STEP};
+    use super::{new_from_yaml, IncrementIntegerProvider, DEFAULT_START, DEFAULT_STEP};
     use crate::providers::provider::{Provider, Value};
 
     use yaml_rust::YamlLoader;
@@ -54,7 +118,7 @@ mod tests {
         let yaml_str = format!("name: id{}{}", yaml_start, yaml_step);
 
         let yaml = YamlLoader::load_from_str(yaml_str.as_str()).unwrap();
-        super::new_from_yaml(&yaml[0])
+        super::new_from_yaml(&yaml[0]).unwrap()
     }
 
     // Parquet type
@@ -109,10 +173,49 @@ mod tests {
         }
     }
 
+    #[test]
+    fn given_cycle_after_or_max_should_give_cycle() {
+        for (params, expected) in [
+            ("", None),
+            ("\ncycle_after: 7", Some(7)),
+            ("\nstart: 1\nstep: 2\nmax: 10", Some(5)),
+            ("\nstart: 1\nstep: 2\nmax: 9", Some(5)),
+        ] {
+            let yaml = YamlLoader::load_from_str(&format!("name: id{}", params)).unwrap();
+            let provider = new_from_yaml(&yaml[0]).unwrap();
+            assert_eq!(provider.cycle_after, expected, "{}", params);
+        }
+    }
+
+    #[test]
+    fn given_wrong_cycle_after_or_max_should_return_err() {
+        for params in [
+            "cycle_after: 0",
+            "cycle_after: -3",
+            "cycle_after: a",
+            "start: 2147483600\ncycle_after: 100",
+            "start: 10\nmax: 5",
+            "step: -1\nmax: 5",
+            "max: 3000000000",
+            "cycle_after: 3\nmax: 5",
+        ] {
+            let yaml = YamlLoader::load_from_str(&format!("name: id\n{}", params)).unwrap();
+            assert!(
+                new_from_yaml(&yaml[0]).is_err(),
+                "{} should be wrong",
+                params
+            );
+        }
+    }
+
     // Validate value calculation
     #[test]
     fn given_start_0_and_index_x_and_step_1_should_return_x() {
-        let provider = IncrementIntegerProvider { start: 0, step: 1 };
+        let provider = IncrementIntegerProvider {
+            start: 0,
+            step: 1,
+            cycle_after: None,
+        };
 
         let values_to_check = [0, 4, 50];
         for value in values_to_check {
@@ -127,7 +230,11 @@ mod tests {
         let values_to_check = [0, 4, 50];
 
         for start in start_to_check {
-            let provider = IncrementIntegerProvider { start, step: 1 };
+            let provider = IncrementIntegerProvider {
+                start,
+                step: 1,
+                cycle_after: None,
+            };
             for value in values_to_check {
                 let calculated = provider.value(value);
                 assert_eq!(calculated, Value::Int32(start + value as i32));
@@ -143,7 +250,11 @@ mod tests {
 
         for start in start_to_check {
             for step in step_to_check {
-                let provider = IncrementIntegerProvider { start, step };
+                let provider = IncrementIntegerProvider {
+                    start,
+                    step,
+                    cycle_after: None,
+                };
                 for value in values_to_check {
                     let calculated = provider.value(value);
                     assert_eq!(calculated, Value::Int32(start + (value as i32 * step)));
@@ -152,6 +263,49 @@ mod tests {
         }
     }
 
+    #[test]
+    fn given_cycle_after_should_start_again() {
+        let provider = IncrementIntegerProvider {
+            start: 10,
+            step: -5,
+            cycle_after: Some(3),
+        };
+        let values: Vec<Value> = (0..7).map(|i| provider.value(i)).collect();
+        assert_eq!(values, [10, 5, 0, 10, 5, 0, 10].map(Value::Int32).to_vec());
+    }
+
+    #[test]
+    fn given_no_cycle_should_give_max_rows_before_overflow() {
+        let provider = IncrementIntegerProvider {
+            start: i32::MAX - 10,
+            step: 4,
+            cycle_after: None,
+        };
+        assert_eq!(provider.max_rows(), Some(3));
+        assert_eq!(provider.value(2), Value::Int32(i32::MAX - 2));
+
+        let provider = IncrementIntegerProvider {
+            start: 0,
+            step: -1_000_000_000,
+            cycle_after: None,
+        };
+        assert_eq!(provider.max_rows(), Some(3));
+
+        let provider = IncrementIntegerProvider {
+            start: 0,
+            step: 0,
+            cycle_after: None,
+        };
+        assert_eq!(provider.max_rows(), None);
+
+        let provider = IncrementIntegerProvider {
+            start: i32::MAX - 10,
+            step: 4,
+            cycle_after: Some(2),
+        };
+        assert_eq!(provider.max_rows(), None);
+    }
+
     // Corrupted value
     #[test]
     fn given_increment_integer_provider_should_corrupted_return_random_int() {
@@ -161,7 +315,11 @@ mod tests {
 
         for start in start_to_check {
             for step in step_to_check {
-                let provider = IncrementIntegerProvider { start, step };
+                let provider = IncrementIntegerProvider {
+                    start,
+                    step,
+                    cycle_after: None,
+                };
 
                 let mut is_random_int = false;
                 for value in values_to_check {
@@ -1,3 +1,5 @@
 pub mod builder;
 
+pub mod datetime;
 pub mod integer;
+pub mod string;
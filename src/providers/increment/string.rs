@@ -0,0 +1,157 @@
+use crate::errors::FakeLakeError;
+use crate::providers::parameters::get_column_name;
+use crate::providers::provider::{Provider, Value};
+use crate::providers::utils::string::random_alphanumeric;
+
+use super::integer::{self, IncrementIntegerProvider};
+
+use yaml_rust::Yaml;
+
+const MAX_PAD: u32 = 32;
+
+#[derive(Clone)]
+pub struct IncrementStringProvider {
+    pub prefix: String,
+    pub suffix: String,
+    /// Min number of digits of the number, padded with zeros
+    pub pad: u32,
+    pub integer: IncrementIntegerProvider,
+}
+
+impl IncrementStringProvider {
+    fn key(&self, number: &str) -> String {
+        format!("{}{}{}", self.prefix, number, self.suffix)
+    }
+
+    fn number(&self, index: u32) -> String {
+        match self.integer.value(index) {
+            Value::Int32(value) => format!("{:0width$}", value, width = self.pad as usize),
+            _ => panic!("Increment integer values should be i32"),
+        }
+    }
+}
+
+impl Provider for IncrementStringProvider {
+    fn value(&self, index: u32) -> Value {
+        Value::String(self.key(&self.number(index)))
+    }
+    fn corrupted_value(&self, index: u32) -> Value {
+        // random characters instead of the number
+        let length = self.number(index).len() as u32;
+        Value::String(self.key(&random_alphanumeric(length)))
+    }
+    fn max_rows(&self) -> Option<u32> {
+        self.integer.max_rows()
+    }
+}
+
+fn string_param(column: &Yaml, param_name: &str) -> Result<String, FakeLakeError> {
+    match &column[param_name] {
+        Yaml::BadValue => Ok(String::new()),
+        Yaml::String(value) => Ok(value.clone()),
+        Yaml::Integer(value) => Ok(value.to_string()),
+        _ => Err(FakeLakeError::BadYAMLFormat(format!(
+            "Column {} param {} should be a string.",
+            get_column_name(column),
+            param_name
+        ))),
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml) -> Result<Box<IncrementStringProvider>, FakeLakeError> {
+    let pad = match &column["pad"] {
+        Yaml::BadValue => 0,
+        Yaml::Integer(value) if (0..=MAX_PAD as i64).contains(value) => *value as u32,
+        _ => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} param pad should be an integer between 0 and {}.",
+                get_column_name(column),
+                MAX_PAD
+            )))
+        }
+    };
+
+    Ok(Box::new(IncrementStringProvider {
+        prefix: string_param(column, "prefix")?,
+        suffix: string_param(column, "suffix")?,
+        pad,
+        integer: *integer::new_from_yaml(column)?,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_from_yaml, IncrementStringProvider};
+    use crate::providers::provider::{Provider, Value};
+
+    use regex::Regex;
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider(yaml_str: &str) -> Box<IncrementStringProvider> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        new_from_yaml(&yaml[0]).unwrap()
+    }
+
+    fn string_value(value: Value) -> String {
+        match value {
+            Value::String(value) => value,
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_no_params_should_use_defaults() {
+        let provider = generate_provider("name: key");
+        assert_eq!(provider.prefix, "");
+        assert_eq!(provider.suffix, "");
+        assert_eq!(provider.pad, 0);
+        assert_eq!((provider.integer.start, provider.integer.step), (0, 1));
+    }
+
+    #[test]
+    fn given_wrong_params_should_return_err() {
+        for params in ["pad: -1", "pad: 33", "pad: a", "prefix: [a]", "max: -5"] {
+            let yaml = YamlLoader::load_from_str(&format!("name: key\n{}", params)).unwrap();
+            assert!(
+                new_from_yaml(&yaml[0]).is_err(),
+                "{} should be wrong",
+                params
+            );
+        }
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_prefix_suffix_and_pad_should_format_number() {
+        let provider =
+            generate_provider("name: key\nprefix: CUST-\nsuffix: -FR\npad: 6\nstart: 42");
+        assert_eq!(string_value(provider.value(0)), "CUST-000042-FR");
+        assert_eq!(string_value(provider.value(958)), "CUST-001000-FR");
+
+        // numbers longer than the pad are kept whole
+        let provider = generate_provider("name: key\nprefix: B\npad: 2\nstart: 99");
+        assert_eq!(string_value(provider.value(1)), "B100");
+    }
+
+    #[test]
+    fn given_cycle_after_should_repeat_keys() {
+        let provider = generate_provider("name: key\nprefix: bucket_\nstart: 1\ncycle_after: 3");
+        let values: Vec<String> = (0..5).map(|i| string_value(provider.value(i))).collect();
+        assert_eq!(
+            values,
+            ["bucket_1", "bucket_2", "bucket_3", "bucket_1", "bucket_2"]
+        );
+    }
+
+    // Corrupted value
+    #[test]
+    fn given_corrupted_should_keep_prefix_and_suffix_around_random_characters() {
+        let provider = generate_provider("name: key\nprefix: CUST-\npad: 6");
+        let re = Regex::new(r"^CUST-[a-zA-Z0-9]{6}$").unwrap();
+        for i in 0..50 {
+            let value = string_value(provider.corrupted_value(i));
+            assert!(re.is_match(&value), "{}", value);
+        }
+    }
+}
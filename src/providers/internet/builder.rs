@@ -0,0 +1,74 @@
+use crate::errors::FakeLakeError;
+use crate::providers::provider::{unknown_provider, Provider};
+
+use super::{domain, http_status, ip, mac, url, user_agent};
+
+use yaml_rust::Yaml;
+
+const AVAILABLE: &[&str] = &[
+    "internet.domain",
+    "internet.http_status",
+    "internet.ipv4",
+    "internet.ipv6",
+    "internet.mac",
+    "internet.url",
+    "internet.user_agent",
+];
+
+pub fn get_corresponding_provider(
+    mut provider_split: std::str::Split<'_, char>,
+    column: &Yaml,
+) -> Result<Box<dyn Provider>, FakeLakeError> {
+    match provider_split.next() {
+        Some("domain") => Ok(domain::new_from_yaml(column)),
+        Some("http_status") => Ok(http_status::new_from_yaml(column)?),
+        Some("ipv4") => Ok(ip::ipv4_from_yaml(column)?),
+        Some("ipv6") => Ok(ip::ipv6_from_yaml(column)),
+        Some("mac") => Ok(mac::new_from_yaml(column)),
+        Some("url") => Ok(url::new_from_yaml(column)),
+        Some("user_agent") => Ok(user_agent::new_from_yaml(column)),
+        other => Err(unknown_provider("internet", other, AVAILABLE)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::get_corresponding_provider;
+
+    use yaml_rust::YamlLoader;
+
+    #[test]
+    fn given_internet_providers_should_return_provider() {
+        for provider_name in [
+            "domain",
+            "http_status",
+            "ipv4",
+            "ipv6",
+            "mac",
+            "url",
+            "user_agent",
+        ] {
+            let yaml_str = format!("name: internet{}provider: {}", '\n', provider_name);
+            let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+            let provider_split = provider_name.split('.');
+            match get_corresponding_provider(provider_split, column) {
+                Ok(_) => (),
+                _ => panic!("{} should return a provider", provider_name),
+            }
+        }
+    }
+
+    #[test]
+    fn given_wrong_provider_should_return_error() {
+        let provider_name = "not_a_provider";
+        let yaml_str = format!("name: internet{}provider: {}", '\n', provider_name);
+        let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+        let provider_split = provider_name.split('.');
+        match get_corresponding_provider(provider_split, column) {
+            Err(_) => (),
+            _ => panic!(),
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use crate::providers::parameters::wstring::{weighted_random, WStringParameter};
+use crate::providers::provider::{Provider, Value};
+use crate::rng;
+
+use yaml_rust::Yaml;
+
+const WORDS: &[&str] = &[
+    "acme", "alpha", "blue", "box", "bright", "cloud", "data", "digital", "fresh", "global",
+    "green", "happy", "hub", "lab", "link", "market", "media", "micro", "net", "north", "nova",
+    "open", "pixel", "prime", "quick", "river", "shop", "smart", "soft", "star", "stone", "studio",
+    "sun", "systems", "tech", "urban", "web", "works", "zone",
+];
+
+/// Top level domains, the most common first
+const TLDS: &[(&str, u32)] = &[
+    ("com", 50),
+    ("net", 10),
+    ("org", 10),
+    ("io", 5),
+    ("fr", 5),
+    ("de", 5),
+    ("co.uk", 5),
+    ("info", 4),
+    ("dev", 3),
+    ("app", 3),
+];
+
+fn pick_word() -> &'static str {
+    WORDS[rng::usize(..WORDS.len())]
+}
+
+fn pick_tld() -> &'static str {
+    let mut random = rng::u32(0..TLDS.iter().map(|(_, weight)| weight).sum());
+    for (tld, weight) in TLDS {
+        if random < *weight {
+            return tld;
+        }
+        random -= weight;
+    }
+    panic!("Random value can't be greater than the weights")
+}
+
+/// A random domain like smartcloud.com or blue-river.io
+pub fn random_domain() -> String {
+    let name = match rng::u32(0..3) {
+        0 => pick_word().to_string(),
+        1 => format!("{}{}", pick_word(), pick_word()),
+        _ => format!("{}-{}", pick_word(), pick_word()),
+    };
+    format!("{}.{}", name, pick_tld())
+}
+
+/// A string that is not a domain, with spaces, double dots or no top level domain
+pub fn corrupted_domain() -> String {
+    match rng::u32(0..3) {
+        0 => format!("{} {}.{}", pick_word(), pick_word(), pick_tld()),
+        1 => format!("{}..{}", pick_word(), pick_tld()),
+        _ => format!("-{}", pick_word()),
+    }
+}
+
+#[derive(Clone)]
+pub struct DomainProvider {
+    /// Top level domains, random ones if empty
+    pub tlds: Vec<WStringParameter>,
+}
+
+impl Provider for DomainProvider {
+    fn value(&self, _: u32) -> Value {
+        let domain = match self.tlds.is_empty() {
+            true => random_domain(),
+            false => {
+                let name = random_domain();
+                let name = name.split('.').next().unwrap_or_default();
+                format!("{}.{}", name, weighted_random(&self.tlds))
+            }
+        };
+        Value::String(domain)
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        Value::String(corrupted_domain())
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml) -> Box<DomainProvider> {
+    let tlds = match &column["tld"] {
+        Yaml::BadValue => Vec::new(),
+        _ => WStringParameter::new(column, "tld", "com"),
+    };
+    Box::new(DomainProvider { tlds })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{corrupted_domain, new_from_yaml, random_domain};
+    use crate::providers::provider::{Provider, Value};
+
+    use regex::Regex;
+    use yaml_rust::YamlLoader;
+
+    const DOMAIN: &str = r"^[a-z]+(-[a-z]+)?\.[a-z]+(\.[a-z]+)?$";
+
+    #[test]
+    fn given_nothing_should_return_domains() {
+        let re = Regex::new(DOMAIN).unwrap();
+        for _ in 0..100 {
+            let domain = random_domain();
+            assert!(re.is_match(&domain), "{} is not a domain", domain);
+            let domain = corrupted_domain();
+            assert!(!re.is_match(&domain), "{} is a domain", domain);
+        }
+    }
+
+    #[test]
+    fn given_tld_should_return_domains_of_it() {
+        let yaml = YamlLoader::load_from_str("name: domain\ntld: [fr, be]").unwrap();
+        let provider = new_from_yaml(&yaml[0]);
+        for i in 0..50 {
+            match provider.value(i) {
+                Value::String(value) => {
+                    assert!(
+                        value.ends_with(".fr") || value.ends_with(".be"),
+                        "{}",
+                        value
+                    )
+                }
+                _ => panic!("Wrong type"),
+            }
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use crate::errors::FakeLakeError;
+use crate::providers::parameters::get_column_name;
+use crate::providers::parameters::wstring::WStringParameter;
+use crate::providers::provider::{Provider, Value};
+use crate::rng;
+
+use yaml_rust::Yaml;
+
+/// Status codes of a web server and their weights
+const DEFAULT_STATUSES: &[(i32, u32)] = &[
+    (200, 800),
+    (201, 20),
+    (204, 10),
+    (301, 20),
+    (302, 30),
+    (304, 40),
+    (400, 15),
+    (401, 10),
+    (403, 10),
+    (404, 30),
+    (429, 5),
+    (500, 6),
+    (502, 2),
+    (503, 2),
+];
+
+#[derive(Clone)]
+pub struct HttpStatusProvider {
+    pub statuses: Vec<(i32, u32)>,
+}
+
+impl Provider for HttpStatusProvider {
+    fn value(&self, _: u32) -> Value {
+        let sum: u32 = self.statuses.iter().map(|(_, weight)| weight).sum();
+        let mut random = rng::u32(0..sum);
+        for (status, weight) in &self.statuses {
+            if random < *weight {
+                return Value::Int32(*status);
+            }
+            random -= weight;
+        }
+        panic!("Random value can't be greater than the weights")
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        // codes outside of the 100 to 599 range
+        match rng::bool() {
+            true => Value::Int32(rng::i32(0..100)),
+            false => Value::Int32(rng::i32(600..1000)),
+        }
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml) -> Result<Box<HttpStatusProvider>, FakeLakeError> {
+    if column["statuses"].is_badvalue() {
+        return Ok(Box::new(HttpStatusProvider {
+            statuses: DEFAULT_STATUSES.to_vec(),
+        }));
+    }
+
+    let mut statuses = Vec::new();
+    for parameter in WStringParameter::new(column, "statuses", "200") {
+        match parameter.value.parse::<i32>() {
+            Ok(status) if (100..600).contains(&status) => statuses.push((status, parameter.weight)),
+            _ => {
+                return Err(FakeLakeError::BadYAMLFormat(format!(
+                    "Column {} param statuses should be codes from 100 to 599, not {}.",
+                    get_column_name(column),
+                    parameter.value
+                )))
+            }
+        }
+    }
+    if statuses.iter().all(|(_, weight)| *weight == 0) {
+        return Err(FakeLakeError::BadYAMLFormat(format!(
+            "Column {} param statuses should have a positive weight.",
+            get_column_name(column)
+        )));
+    }
+
+    Ok(Box::new(HttpStatusProvider { statuses }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_from_yaml, DEFAULT_STATUSES};
+    use crate::providers::provider::{Provider, Value};
+
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider(yaml_str: &str) -> Box<super::HttpStatusProvider> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        new_from_yaml(&yaml[0]).unwrap()
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_no_statuses_should_use_default() {
+        assert_eq!(
+            generate_provider("name: status").statuses,
+            DEFAULT_STATUSES.to_vec()
+        );
+    }
+
+    #[test]
+    fn given_statuses_should_use_them() {
+        let provider = generate_provider(
+            "name: status\nstatuses:\n  - value: 200\n    weight: 9\n  - value: 404",
+        );
+        assert_eq!(provider.statuses, vec![(200, 9), (404, 1)]);
+    }
+
+    #[test]
+    fn given_wrong_statuses_should_return_err() {
+        for statuses in ["[200, 700]", "[ok]", "\n  - value: 200\n    weight: 0"] {
+            let yaml = YamlLoader::load_from_str(&format!("name: status\nstatuses: {}", statuses))
+                .unwrap();
+            assert!(
+                new_from_yaml(&yaml[0]).is_err(),
+                "{} should be wrong",
+                statuses
+            );
+        }
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_statuses_should_return_them_accordingly() {
+        let provider = generate_provider("name: status");
+        let mut ok = 0;
+        for i in 0..1000 {
+            match provider.value(i) {
+                Value::Int32(200) => ok += 1,
+                Value::Int32(value) => assert!((100..600).contains(&value)),
+                _ => panic!("Wrong type"),
+            }
+            match provider.corrupted_value(i) {
+                Value::Int32(value) => assert!(!(100..600).contains(&value)),
+                _ => panic!("Wrong type"),
+            }
+        }
+        assert!((700..900).contains(&ok));
+    }
+}
@@ -0,0 +1,172 @@
+use crate::errors::FakeLakeError;
+use crate::providers::parameters::get_column_name;
+use crate::providers::provider::{Provider, Value};
+use crate::rng;
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+use yaml_rust::Yaml;
+
+#[derive(Clone)]
+pub struct Ipv4Provider {
+    /// First address of the network
+    pub network: u32,
+    /// Number of bits of the network part
+    pub prefix: u32,
+}
+
+impl Provider for Ipv4Provider {
+    fn value(&self, _: u32) -> Value {
+        let host_bits = 32 - self.prefix;
+        let host = rng::i64(0..1 << host_bits) as u32;
+        Value::String(Ipv4Addr::from(self.network | host).to_string())
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        // addresses with an octet above 255 or a missing octet
+        let octets: Vec<String> = (0..4).map(|_| rng::u32(0..256).to_string()).collect();
+        let value = match rng::bool() {
+            true => format!("{}.{}", octets[..3].join("."), rng::u32(256..1000)),
+            false => octets[..3].join("."),
+        };
+        Value::String(value)
+    }
+}
+
+/// Network and prefix of a CIDR like 192.168.0.0/16
+fn parse_cidr(cidr: &str) -> Option<(u32, u32)> {
+    let (address, prefix) = cidr.split_once('/')?;
+    let address: Ipv4Addr = address.trim().parse().ok()?;
+    let prefix: u32 = prefix.trim().parse().ok()?;
+    if prefix > 32 {
+        return None;
+    }
+    let mask = match prefix {
+        0 => 0,
+        _ => u32::MAX << (32 - prefix),
+    };
+    Some((u32::from(address) & mask, prefix))
+}
+
+pub fn ipv4_from_yaml(column: &Yaml) -> Result<Box<Ipv4Provider>, FakeLakeError> {
+    let (network, prefix) = match &column["cidr"] {
+        Yaml::BadValue => (0, 0),
+        Yaml::String(value) => match parse_cidr(value) {
+            Some(value) => value,
+            None => {
+                return Err(FakeLakeError::BadYAMLFormat(format!(
+                    "Column {} param cidr should be like 192.168.0.0/16.",
+                    get_column_name(column)
+                )))
+            }
+        },
+        _ => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} param cidr should be a string.",
+                get_column_name(column)
+            )))
+        }
+    };
+    Ok(Box::new(Ipv4Provider { network, prefix }))
+}
+
+#[derive(Clone)]
+pub struct Ipv6Provider;
+
+impl Provider for Ipv6Provider {
+    fn value(&self, _: u32) -> Value {
+        let segments: Vec<u16> = (0..8).map(|_| rng::u32(0..0x10000) as u16).collect();
+        let address = Ipv6Addr::new(
+            segments[0],
+            segments[1],
+            segments[2],
+            segments[3],
+            segments[4],
+            segments[5],
+            segments[6],
+            segments[7],
+        );
+        Value::String(address.to_string())
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        // nine groups, or a group that is not hexadecimal
+        let mut groups: Vec<String> = (0..8)
+            .map(|_| format!("{:x}", rng::u32(0..0x10000)))
+            .collect();
+        match rng::bool() {
+            true => groups.push(format!("{:x}", rng::u32(0..0x10000))),
+            false => groups[rng::usize(..8)] = "ghij".to_string(),
+        }
+        Value::String(groups.join(":"))
+    }
+}
+
+pub fn ipv6_from_yaml(_: &Yaml) -> Box<Ipv6Provider> {
+    Box::new(Ipv6Provider)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ipv4_from_yaml, parse_cidr, Ipv6Provider};
+    use crate::providers::provider::{Provider, Value};
+
+    use std::net::{Ipv4Addr, Ipv6Addr};
+    use yaml_rust::YamlLoader;
+
+    fn string_value(value: Value) -> String {
+        match value {
+            Value::String(value) => value,
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_cidr_should_return_network_and_prefix() {
+        assert_eq!(parse_cidr("10.0.0.0/8"), Some((0x0A00_0000, 8)));
+        assert_eq!(parse_cidr("192.168.12.34/16"), Some((0xC0A8_0000, 16)));
+        assert_eq!(parse_cidr("0.0.0.0/0"), Some((0, 0)));
+        for cidr in ["10.0.0.0", "10.0.0/8", "10.0.0.0/33", "a.b.c.d/8"] {
+            assert_eq!(parse_cidr(cidr), None, "{} should be wrong", cidr);
+        }
+    }
+
+    #[test]
+    fn given_wrong_cidr_should_return_err() {
+        let yaml = YamlLoader::load_from_str("name: ip\ncidr: 10.0.0.0/40").unwrap();
+        assert!(ipv4_from_yaml(&yaml[0]).is_err());
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_cidr_should_return_addresses_in_network() {
+        let yaml = YamlLoader::load_from_str("name: ip\ncidr: 192.168.1.0/24").unwrap();
+        let provider = ipv4_from_yaml(&yaml[0]).unwrap();
+        for i in 0..100 {
+            let address: Ipv4Addr = string_value(provider.value(i)).parse().unwrap();
+            assert_eq!(address.octets()[..3], [192, 168, 1]);
+        }
+    }
+
+    #[test]
+    fn given_no_cidr_should_return_any_address() {
+        let yaml = YamlLoader::load_from_str("name: ip").unwrap();
+        let provider = ipv4_from_yaml(&yaml[0]).unwrap();
+        for i in 0..100 {
+            assert!(string_value(provider.value(i)).parse::<Ipv4Addr>().is_ok());
+            assert!(string_value(provider.corrupted_value(i))
+                .parse::<Ipv4Addr>()
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn given_ipv6_should_return_valid_addresses_and_corrupted_invalid() {
+        for i in 0..100 {
+            assert!(string_value(Ipv6Provider.value(i))
+                .parse::<Ipv6Addr>()
+                .is_ok());
+            assert!(string_value(Ipv6Provider.corrupted_value(i))
+                .parse::<Ipv6Addr>()
+                .is_err());
+        }
+    }
+}
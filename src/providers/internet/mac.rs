@@ -0,0 +1,57 @@
+use crate::providers::provider::{Provider, Value};
+use crate::rng;
+
+use yaml_rust::Yaml;
+
+#[derive(Clone)]
+pub struct MacProvider;
+
+fn random_octets(n: usize) -> Vec<String> {
+    (0..n)
+        .map(|_| format!("{:02x}", rng::u32(0..256)))
+        .collect()
+}
+
+impl Provider for MacProvider {
+    fn value(&self, _: u32) -> Value {
+        Value::String(random_octets(6).join(":"))
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        // five octets, or an octet that is not hexadecimal
+        let mut octets = random_octets(6);
+        match rng::bool() {
+            true => {
+                octets.pop();
+            }
+            false => octets[rng::usize(..6)] = "zz".to_string(),
+        }
+        Value::String(octets.join(":"))
+    }
+}
+
+pub fn new_from_yaml(_: &Yaml) -> Box<MacProvider> {
+    Box::new(MacProvider)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MacProvider;
+    use crate::providers::provider::{Provider, Value};
+
+    use regex::Regex;
+
+    #[test]
+    fn given_provider_should_return_mac_addresses() {
+        let re = Regex::new(r"^([0-9a-f]{2}:){5}[0-9a-f]{2}$").unwrap();
+        for i in 0..100 {
+            match MacProvider.value(i) {
+                Value::String(value) => assert!(re.is_match(&value), "{}", value),
+                _ => panic!("Wrong type"),
+            }
+            match MacProvider.corrupted_value(i) {
+                Value::String(value) => assert!(!re.is_match(&value), "{}", value),
+                _ => panic!("Wrong type"),
+            }
+        }
+    }
+}
@@ -0,0 +1,8 @@
+pub mod builder;
+
+pub mod domain;
+pub mod http_status;
+pub mod ip;
+pub mod mac;
+pub mod url;
+pub mod user_agent;
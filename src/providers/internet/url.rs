@@ -0,0 +1,163 @@
+use crate::providers::parameters::string::StringParameter;
+use crate::providers::parameters::urange::URangeParameter;
+use crate::providers::parameters::wstring::{weighted_random, WStringParameter};
+use crate::providers::provider::{Provider, Value};
+use crate::providers::utils::string::pick;
+use crate::rng;
+
+use super::domain::{corrupted_domain, random_domain};
+
+use yaml_rust::Yaml;
+
+const DEFAULT_SCHEME: &str = "https";
+/// Number of path segments, the max being excluded
+const DEFAULT_PATH_DEPTH: (u32, u32) = (1, 4);
+
+const SEGMENTS: &[&str] = &[
+    "about", "account", "api", "articles", "blog", "cart", "category", "contact", "docs", "events",
+    "help", "images", "items", "login", "news", "orders", "products", "profile", "search",
+    "settings", "shop", "static", "users", "v1", "v2",
+];
+
+const QUERY_KEYS: &[&str] = &[
+    "id",
+    "page",
+    "q",
+    "lang",
+    "sort",
+    "ref",
+    "utm_source",
+    "utm_campaign",
+    "limit",
+    "filter",
+];
+
+#[derive(Clone)]
+pub struct UrlProvider {
+    pub scheme: String,
+    /// Domains of the urls, random ones if empty
+    pub domains: Vec<WStringParameter>,
+    pub path_depth: (u32, u32),
+    pub query_params: (u32, u32),
+}
+
+impl UrlProvider {
+    fn domain(&self) -> String {
+        match self.domains.is_empty() {
+            true => random_domain(),
+            false => weighted_random(&self.domains).to_string(),
+        }
+    }
+}
+
+impl Provider for UrlProvider {
+    fn value(&self, _: u32) -> Value {
+        let mut url = format!("{}://{}/", self.scheme, self.domain());
+
+        let depth = rng::u32(self.path_depth.0..self.path_depth.1);
+        let segments: Vec<String> = (0..depth)
+            .map(|_| match rng::u32(0..4) {
+                0 => rng::u32(1..10000).to_string(),
+                _ => pick(SEGMENTS).to_string(),
+            })
+            .collect();
+        url.push_str(&segments.join("/"));
+
+        let params = rng::u32(self.query_params.0..self.query_params.1);
+        if params > 0 {
+            let query: Vec<String> = (0..params)
+                .map(|_| format!("{}={}", pick(QUERY_KEYS), rng::u32(1..1000)))
+                .collect();
+            url.push('?');
+            url.push_str(&query.join("&"));
+        }
+        Value::String(url)
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        // no scheme separator, or a host that is not a domain
+        let value = match rng::bool() {
+            true => format!("{}:/{}/{}", self.scheme, self.domain(), pick(SEGMENTS)),
+            false => format!(
+                "{}://{}/{}",
+                self.scheme,
+                corrupted_domain(),
+                pick(SEGMENTS)
+            ),
+        };
+        Value::String(value)
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml) -> Box<UrlProvider> {
+    let domains = match &column["domain"] {
+        Yaml::BadValue => Vec::new(),
+        _ => WStringParameter::new(column, "domain", "example.com"),
+    };
+    let path_depth =
+        URangeParameter::with_default_range(column, "path_depth", DEFAULT_PATH_DEPTH).range();
+
+    Box::new(UrlProvider {
+        scheme: StringParameter::new(column, "scheme", DEFAULT_SCHEME).value,
+        domains,
+        path_depth,
+        query_params: URangeParameter::with_default_range(column, "query_params", (0, 1)).range(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_from_yaml, DEFAULT_PATH_DEPTH, DEFAULT_SCHEME};
+    use crate::providers::provider::{Provider, Value};
+
+    use regex::Regex;
+    use yaml_rust::YamlLoader;
+
+    fn generate_values(yaml_str: &str, corrupted: bool) -> Vec<String> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        let provider = new_from_yaml(&yaml[0]);
+        (0..100)
+            .map(|i| {
+                let value = match corrupted {
+                    true => provider.corrupted_value(i),
+                    false => provider.value(i),
+                };
+                match value {
+                    Value::String(value) => value,
+                    _ => panic!("Wrong type"),
+                }
+            })
+            .collect()
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_nothing_should_use_defaults() {
+        let yaml = YamlLoader::load_from_str("name: url").unwrap();
+        let provider = new_from_yaml(&yaml[0]);
+        assert_eq!(provider.scheme, DEFAULT_SCHEME);
+        assert!(provider.domains.is_empty());
+        assert_eq!(provider.path_depth, DEFAULT_PATH_DEPTH);
+        assert_eq!(provider.query_params, (0, 1));
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_params_should_return_urls_following_them() {
+        let re =
+            Regex::new(r"^http://shop\.example\.com/[a-z0-9]+/[a-z0-9]+\?[a-z_]+=\d+&[a-z_]+=\d+$")
+                .unwrap();
+        let yaml =
+            "name: url\nscheme: http\ndomain: shop.example.com\npath_depth: 2\nquery_params: 2";
+        for value in generate_values(yaml, false) {
+            assert!(re.is_match(&value), "{} is not expected", value);
+        }
+    }
+
+    #[test]
+    fn given_corrupted_should_return_invalid_urls() {
+        let re = Regex::new(r"^https://[a-z]+(-[a-z]+)?\.[a-z]+(\.[a-z]+)?/").unwrap();
+        for value in generate_values("name: url", true) {
+            assert!(!re.is_match(&value), "{} is a valid url", value);
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use crate::providers::provider::{Provider, Value};
+use crate::providers::utils::string::{pick, random_alphanumeric};
+use crate::rng;
+
+use yaml_rust::Yaml;
+
+const WINDOWS: &str = "Windows NT 10.0; Win64; x64";
+const MACOS: &[&str] = &["10_15_7", "13_6_1", "14_4_1"];
+const ANDROID: &[&str] = &["12", "13", "14"];
+const IOS: &[&str] = &["16_6", "17_4", "17_5"];
+
+fn chrome_version() -> String {
+    format!(
+        "{}.0.{}.{}",
+        rng::u32(110..127),
+        rng::u32(5000..6500),
+        rng::u32(0..200)
+    )
+}
+
+fn firefox_version() -> u32 {
+    rng::u32(110..127)
+}
+
+#[derive(Clone)]
+pub struct UserAgentProvider;
+
+impl Provider for UserAgentProvider {
+    fn value(&self, _: u32) -> Value {
+        let webkit = "AppleWebKit/537.36 (KHTML, like Gecko)";
+        let user_agent = match rng::u32(0..10) {
+            0..=2 => format!(
+                "Mozilla/5.0 ({}) {} Chrome/{} Safari/537.36",
+                WINDOWS,
+                webkit,
+                chrome_version()
+            ),
+            3 => format!(
+                "Mozilla/5.0 (Macintosh; Intel Mac OS X {}) {} Chrome/{} Safari/537.36",
+                pick(MACOS),
+                webkit,
+                chrome_version()
+            ),
+            4 => {
+                let version = firefox_version();
+                format!(
+                    "Mozilla/5.0 ({}; rv:{}.0) Gecko/20100101 Firefox/{}.0",
+                    WINDOWS, version, version
+                )
+            }
+            5 => format!(
+                "Mozilla/5.0 (Macintosh; Intel Mac OS X {}) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/{}.{} Safari/605.1.15",
+                pick(MACOS),
+                rng::u32(16..18),
+                rng::u32(0..6)
+            ),
+            6 => {
+                let version = chrome_version();
+                format!(
+                    "Mozilla/5.0 ({}) {} Chrome/{} Safari/537.36 Edg/{}",
+                    WINDOWS, webkit, version, version
+                )
+            }
+            7 => format!(
+                "Mozilla/5.0 (Linux; Android {}; Pixel {}) {} Chrome/{} Mobile Safari/537.36",
+                pick(ANDROID),
+                rng::u32(6..9),
+                webkit,
+                chrome_version()
+            ),
+            8 => {
+                let version = pick(IOS);
+                format!(
+                    "Mozilla/5.0 (iPhone; CPU iPhone OS {} like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/{} Mobile/15E148 Safari/604.1",
+                    version,
+                    version.replace('_', ".")
+                )
+            }
+            _ => match rng::bool() {
+                true => "Mozilla/5.0 (compatible; Googlebot/2.1; +http://www.google.com/bot.html)"
+                    .to_string(),
+                false => "Mozilla/5.0 (compatible; bingbot/2.0; +http://www.bing.com/bingbot.htm)"
+                    .to_string(),
+            },
+        };
+        Value::String(user_agent)
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        // no product token, or an unclosed comment
+        let value = match rng::bool() {
+            true => random_alphanumeric(20),
+            false => format!("Mozilla/5.0 ({}", WINDOWS),
+        };
+        Value::String(value)
+    }
+}
+
+pub fn new_from_yaml(_: &Yaml) -> Box<UserAgentProvider> {
+    Box::new(UserAgentProvider)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UserAgentProvider;
+    use crate::providers::provider::{Provider, Value};
+
+    use regex::Regex;
+
+    #[test]
+    fn given_provider_should_return_user_agents() {
+        let re = Regex::new(r"^Mozilla/5\.0 \([^()]+\)( .+)?$").unwrap();
+        for i in 0..200 {
+            match UserAgentProvider.value(i) {
+                Value::String(value) => assert!(re.is_match(&value), "{}", value),
+                _ => panic!("Wrong type"),
+            }
+            match UserAgentProvider.corrupted_value(i) {
+                Value::String(value) => assert!(!re.is_match(&value), "{}", value),
+                _ => panic!("Wrong type"),
+            }
+        }
+    }
+}
@@ -4,7 +4,21 @@ pub mod generic;
 pub mod parameters;
 pub mod utils;
 
+pub mod address;
+pub mod commerce;
+pub mod company;
+pub mod conditional;
 pub mod constant;
+pub mod expression;
+pub mod finance;
+pub mod geo;
+pub mod hash;
+pub mod id;
 pub mod increment;
+pub mod internet;
 pub mod person;
 pub mod random;
+pub mod reference;
+pub mod template;
+pub mod text;
+pub mod timeseries;
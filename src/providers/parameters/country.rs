@@ -0,0 +1,94 @@
+use crate::errors::FakeLakeError;
+use crate::providers::parameters::get_column_name;
+use crate::providers::parameters::locale::LocaleParameter;
+use crate::providers::parameters::wstring::WStringParameter;
+
+use yaml_rust::Yaml;
+
+/// Countries of the country parameter, a code or a weighted list of codes.
+/// Without it, the country of the locale, or all the supported countries when it is not one of them.
+/// Any two letter code is accepted when supported is None.
+pub fn countries_from_yaml(
+    column: &Yaml,
+    supported: Option<&[&str]>,
+) -> Result<Vec<WStringParameter>, FakeLakeError> {
+    let is_supported = |code: &str| match supported {
+        Some(supported) => supported.contains(&code),
+        None => code.len() == 2 && code.chars().all(|c| c.is_ascii_uppercase()),
+    };
+
+    if let Yaml::BadValue = column["country"] {
+        let country = LocaleParameter::new(column).value.country_code();
+        return Ok(match (is_supported(country), supported) {
+            (false, Some(supported)) => supported
+                .iter()
+                .map(|code| WStringParameter::new_from_str(code))
+                .collect(),
+            _ => vec![WStringParameter::new_from_str(country)],
+        });
+    }
+
+    let mut countries = WStringParameter::new(column, "country", "");
+    for country in countries.iter_mut() {
+        country.value = country.value.to_uppercase();
+        if !is_supported(&country.value) {
+            let expected = match supported {
+                Some(supported) => format!("one of {}", supported.join(", ")),
+                None => "a two letter country code".to_string(),
+            };
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} param country {} should be {}.",
+                get_column_name(column),
+                country.value,
+                expected
+            )));
+        }
+    }
+    Ok(countries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::countries_from_yaml;
+
+    use yaml_rust::YamlLoader;
+
+    fn countries(params: &str, supported: Option<&[&str]>) -> Option<Vec<String>> {
+        let yaml = YamlLoader::load_from_str(&format!("name: a\n{}", params)).unwrap();
+        countries_from_yaml(&yaml[0], supported)
+            .ok()
+            .map(|countries| countries.into_iter().map(|c| c.value).collect())
+    }
+
+    #[test]
+    fn given_no_country_should_return_country_of_locale() {
+        assert_eq!(
+            countries("locale: de_DE", None),
+            Some(vec!["DE".to_string()])
+        );
+        assert_eq!(
+            countries("locale: de_DE", Some(&["DE", "FR"])),
+            Some(vec!["DE".to_string()])
+        );
+        assert_eq!(
+            countries("locale: en_US", Some(&["DE", "FR"])),
+            Some(vec!["DE".to_string(), "FR".to_string()])
+        );
+    }
+
+    #[test]
+    fn given_countries_should_return_them() {
+        assert_eq!(countries("country: fr", None), Some(vec!["FR".to_string()]));
+        assert_eq!(
+            countries("country: [FR, {value: DE, weight: 2}]", Some(&["DE", "FR"])),
+            Some(vec!["FR".to_string(), "DE".to_string()])
+        );
+    }
+
+    #[test]
+    fn given_wrong_countries_should_return_err() {
+        assert_eq!(countries("country: US", Some(&["DE", "FR"])), None);
+        assert_eq!(countries("country: FRA", None), None);
+        assert_eq!(countries("country: [FR, 12]", None), None);
+    }
+}
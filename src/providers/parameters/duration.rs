@@ -0,0 +1,318 @@
+use crate::errors::FakeLakeError;
+
+use chrono::{Months, NaiveDateTime, TimeDelta};
+use yaml_rust::Yaml;
+
+use super::get_column_name;
+
+const SECONDS_PER_DAY: i64 = 86400;
+
+/// Duration written like `15 minutes`, `1 day` or `PT15M`. Months and years don't have a
+/// fixed number of seconds, so they are kept apart.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Duration {
+    Seconds(i64),
+    Months(i64),
+}
+
+/// How durations are written in CSV and JSON
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    /// Number of seconds
+    Seconds,
+    /// ISO 8601 duration like P1DT2H30M
+    Iso,
+}
+
+/// Numbers followed by their unit, like 1 and D then 12 and H for `1D12H`
+fn iso_parts(value: &str) -> Option<Vec<(i64, char)>> {
+    let mut parts = Vec::new();
+    let mut count = String::new();
+    for c in value.chars() {
+        match c {
+            '0'..='9' => count.push(c),
+            _ if !count.is_empty() => parts.push((std::mem::take(&mut count).parse().ok()?, c)),
+            _ => return None,
+        }
+    }
+    match count.is_empty() {
+        true => Some(parts),
+        false => None,
+    }
+}
+
+/// Duration in ISO 8601 like `PT15M`, `P1DT12H` or `-P1W`. Years and months can't be mixed
+/// with shorter units.
+fn parse_iso(value: &str) -> Option<Duration> {
+    let (sign, value) = match value.strip_prefix('-') {
+        Some(value) => (-1, value),
+        None => (1, value),
+    };
+    let value = value.strip_prefix('P')?;
+    let (date, time) = match value.split_once('T') {
+        Some((date, time)) if !time.is_empty() => (date, time),
+        Some(_) => return None,
+        None => (value, ""),
+    };
+
+    let mut months: i64 = 0;
+    let mut seconds: i64 = 0;
+    let date_parts = iso_parts(date)?.into_iter().map(|part| (part, false));
+    let time_parts = iso_parts(time)?.into_iter().map(|part| (part, true));
+    let mut empty = true;
+    for ((count, unit), is_time) in date_parts.chain(time_parts) {
+        empty = false;
+        match (unit, is_time) {
+            ('Y', false) => months = months.checked_add(count.checked_mul(12)?)?,
+            ('M', false) => months = months.checked_add(count)?,
+            ('W', false) => {
+                seconds = seconds.checked_add(count.checked_mul(7 * SECONDS_PER_DAY)?)?
+            }
+            ('D', false) => seconds = seconds.checked_add(count.checked_mul(SECONDS_PER_DAY)?)?,
+            ('H', true) => seconds = seconds.checked_add(count.checked_mul(3600)?)?,
+            ('M', true) => seconds = seconds.checked_add(count.checked_mul(60)?)?,
+            ('S', true) => seconds = seconds.checked_add(count)?,
+            _ => return None,
+        }
+    }
+
+    match (empty, months, seconds) {
+        (true, _, _) => None,
+        (_, 0, seconds) => Some(Duration::Seconds(sign * seconds)),
+        (_, months, 0) => Some(Duration::Months(sign * months)),
+        _ => None,
+    }
+}
+
+/// Seconds written as an ISO 8601 duration, like P1DT2H30M
+pub fn iso(seconds: i64) -> String {
+    let sign = match seconds < 0 {
+        true => "-",
+        false => "",
+    };
+    let seconds = seconds.unsigned_abs();
+    let days = seconds / SECONDS_PER_DAY as u64;
+    let time = [
+        (seconds % SECONDS_PER_DAY as u64 / 3600, 'H'),
+        (seconds % 3600 / 60, 'M'),
+        (seconds % 60, 'S'),
+    ];
+
+    let mut value = format!("{}P", sign);
+    if days > 0 {
+        value.push_str(&format!("{}D", days));
+    }
+    if time.iter().any(|(count, _)| *count > 0) {
+        value.push('T');
+        for (count, unit) in time.iter().filter(|(count, _)| *count > 0) {
+            value.push_str(&format!("{}{}", count, unit));
+        }
+    }
+    match value.ends_with('P') {
+        true => format!("{}T0S", value),
+        false => value,
+    }
+}
+
+impl Duration {
+    /// Duration like `1 day`, `-2 hours` or `15 minutes`, the unit being singular or plural,
+    /// or an ISO 8601 duration like `PT15M`
+    pub fn parse(value: &str) -> Option<Duration> {
+        let value = value.trim();
+        if value.starts_with('P') || value.starts_with("-P") {
+            return parse_iso(value);
+        }
+        let (count, unit) = value.split_once(' ')?;
+        let count: i64 = count.parse().ok()?;
+        let unit = unit.trim();
+        let unit = unit.strip_suffix('s').unwrap_or(unit);
+        let duration = match unit {
+            "second" => Duration::Seconds(count),
+            "minute" => Duration::Seconds(count.checked_mul(60)?),
+            "hour" => Duration::Seconds(count.checked_mul(3600)?),
+            "day" => Duration::Seconds(count.checked_mul(SECONDS_PER_DAY)?),
+            "week" => Duration::Seconds(count.checked_mul(7 * SECONDS_PER_DAY)?),
+            "month" => Duration::Months(count),
+            "year" => Duration::Months(count.checked_mul(12)?),
+            _ => return None,
+        };
+        Some(duration)
+    }
+
+    /// Duration of a param of the column, None if the param is missing
+    pub fn from_yaml(column: &Yaml, param_name: &str) -> Result<Option<Duration>, FakeLakeError> {
+        match &column[param_name] {
+            Yaml::BadValue => Ok(None),
+            value => match value.as_str().and_then(Duration::parse) {
+                Some(duration) => Ok(Some(duration)),
+                None => Err(FakeLakeError::BadYAMLFormat(format!(
+                    "Column {} param {} should be a duration like 15 minutes, 1 day or PT15M, with a unit among second, minute, hour, day, week, month and year.",
+                    get_column_name(column),
+                    param_name
+                ))),
+            },
+        }
+    }
+
+    /// Shortest number of seconds of the duration, a month lasting at least 28 days
+    pub fn min_seconds(&self) -> i64 {
+        match self {
+            Duration::Seconds(seconds) => *seconds,
+            Duration::Months(months) => months.saturating_mul(28 * SECONDS_PER_DAY),
+        }
+    }
+
+    /// Whether the duration is a whole number of days, or of months
+    pub fn is_whole_days(&self) -> bool {
+        match self {
+            Duration::Seconds(seconds) => seconds % SECONDS_PER_DAY == 0,
+            Duration::Months(_) => true,
+        }
+    }
+
+    /// Datetime after some times the duration, None if out of the range of datetimes
+    pub fn add_to(&self, datetime: NaiveDateTime, times: i64) -> Option<NaiveDateTime> {
+        match self {
+            Duration::Seconds(seconds) => {
+                datetime.checked_add_signed(TimeDelta::try_seconds(seconds.checked_mul(times)?)?)
+            }
+            Duration::Months(months) => {
+                let months = months.checked_mul(times)?;
+                let count = Months::new(u32::try_from(months.unsigned_abs()).ok()?);
+                match months >= 0 {
+                    true => datetime.checked_add_months(count),
+                    false => datetime.checked_sub_months(count),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{iso, Duration};
+
+    use chrono::NaiveDate;
+    use yaml_rust::YamlLoader;
+
+    // Validate YAML file
+    #[test]
+    fn given_durations_should_parse_them() {
+        for (value, expected) in [
+            ("1 second", Duration::Seconds(1)),
+            ("15 minutes", Duration::Seconds(900)),
+            ("2 hours", Duration::Seconds(7200)),
+            ("1 day", Duration::Seconds(86400)),
+            ("-1 day", Duration::Seconds(-86400)),
+            ("2 weeks", Duration::Seconds(1_209_600)),
+            ("1 month", Duration::Months(1)),
+            ("3 years", Duration::Months(36)),
+        ] {
+            assert_eq!(Duration::parse(value), Some(expected), "{}", value);
+        }
+    }
+
+    #[test]
+    fn given_iso_durations_should_parse_them() {
+        for (value, expected) in [
+            ("PT15M", Duration::Seconds(900)),
+            ("PT1H30M", Duration::Seconds(5400)),
+            ("P1DT12H", Duration::Seconds(129_600)),
+            ("P2W", Duration::Seconds(1_209_600)),
+            ("-PT30S", Duration::Seconds(-30)),
+            ("P1M", Duration::Months(1)),
+            ("P1Y6M", Duration::Months(18)),
+        ] {
+            assert_eq!(Duration::parse(value), Some(expected), "{}", value);
+        }
+    }
+
+    #[test]
+    fn given_wrong_durations_should_return_none() {
+        for value in [
+            "1",
+            "day",
+            "1.5 days",
+            "1 fortnight",
+            "a day",
+            "",
+            "P",
+            "PT",
+            "P1H",
+            "PT1D",
+            "P1M1D",
+            "PT1.5S",
+            "P1DT",
+        ] {
+            assert_eq!(Duration::parse(value), None, "{}", value);
+        }
+    }
+
+    #[test]
+    fn given_yaml_should_return_duration_or_err() {
+        let yaml = YamlLoader::load_from_str("name: a\nstep: 1 hour\njitter: 3").unwrap();
+        assert_eq!(
+            Duration::from_yaml(&yaml[0], "step").unwrap(),
+            Some(Duration::Seconds(3600))
+        );
+        assert_eq!(Duration::from_yaml(&yaml[0], "other").unwrap(), None);
+        assert!(Duration::from_yaml(&yaml[0], "jitter").is_err());
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_times_should_add_duration() {
+        let start = NaiveDate::from_ymd_opt(2024, 1, 31)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        assert_eq!(
+            Duration::Seconds(900).add_to(start, 4),
+            NaiveDate::from_ymd_opt(2024, 1, 31)
+                .unwrap()
+                .and_hms_opt(13, 0, 0)
+        );
+        // the day is kept when the month has it, else the last day of the month is taken
+        assert_eq!(
+            Duration::Months(1).add_to(start, 1),
+            NaiveDate::from_ymd_opt(2024, 2, 29)
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+        );
+        assert_eq!(
+            Duration::Months(-12).add_to(start, 2),
+            NaiveDate::from_ymd_opt(2022, 1, 31)
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+        );
+        assert_eq!(Duration::Seconds(i64::MAX / 2).add_to(start, 4), None);
+    }
+
+    #[test]
+    fn given_seconds_should_write_iso_durations() {
+        for (seconds, expected) in [
+            (0, "PT0S"),
+            (45, "PT45S"),
+            (5400, "PT1H30M"),
+            (86400, "P1D"),
+            (93_784, "P1DT2H3M4S"),
+            (-900, "-PT15M"),
+        ] {
+            assert_eq!(iso(seconds), expected);
+            assert_eq!(
+                Duration::parse(expected),
+                Some(Duration::Seconds(seconds)),
+                "{}",
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn given_durations_should_return_min_seconds_and_whole_days() {
+        assert_eq!(Duration::Months(1).min_seconds(), 28 * 86400);
+        assert!(Duration::Seconds(2 * 86400).is_whole_days());
+        assert!(!Duration::Seconds(3600).is_whole_days());
+        assert!(Duration::Months(1).is_whole_days());
+    }
+}
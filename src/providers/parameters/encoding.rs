@@ -0,0 +1,105 @@
+use crate::errors::FakeLakeError;
+use crate::providers::provider::Value;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use yaml_rust::Yaml;
+
+use super::get_column_name;
+
+/// How bytes are written in a column
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Encoding {
+    /// Binary in Parquet, base64 in the other formats
+    Raw,
+    /// Lowercase hexadecimal string
+    Hex,
+    /// Base64 string, with padding
+    Base64,
+}
+
+/// Bytes written as lowercase hexadecimal
+pub fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Bytes written in standard base64, with padding
+pub fn base64(bytes: &[u8]) -> String {
+    STANDARD.encode(bytes)
+}
+
+impl Encoding {
+    /// Param encoding of the column, raw, hex or base64
+    pub fn from_yaml(column: &Yaml, default: Encoding) -> Result<Encoding, FakeLakeError> {
+        match column["encoding"].as_str() {
+            None if column["encoding"].is_badvalue() => Ok(default),
+            Some("raw") => Ok(Encoding::Raw),
+            Some("hex") => Ok(Encoding::Hex),
+            Some("base64") => Ok(Encoding::Base64),
+            _ => Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} param encoding should be raw, hex or base64.",
+                get_column_name(column)
+            ))),
+        }
+    }
+
+    /// Value of the bytes, large being only used by raw bytes
+    pub fn value(&self, bytes: Vec<u8>, large: bool) -> Value {
+        match self {
+            Encoding::Raw => Value::Bytes(bytes, large),
+            Encoding::Hex => Value::String(hex(&bytes)),
+            Encoding::Base64 => Value::String(base64(&bytes)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{base64, hex, Encoding};
+    use crate::providers::provider::Value;
+
+    use yaml_rust::YamlLoader;
+
+    fn from_yaml(params: &str) -> Result<Encoding, crate::errors::FakeLakeError> {
+        let yaml = YamlLoader::load_from_str(&format!("name: a\n{}", params)).unwrap();
+        Encoding::from_yaml(&yaml[0], Encoding::Raw)
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_encodings_should_parse_them() {
+        assert_eq!(from_yaml("").unwrap(), Encoding::Raw);
+        assert_eq!(from_yaml("encoding: raw").unwrap(), Encoding::Raw);
+        assert_eq!(from_yaml("encoding: hex").unwrap(), Encoding::Hex);
+        assert_eq!(from_yaml("encoding: base64").unwrap(), Encoding::Base64);
+    }
+
+    #[test]
+    fn given_wrong_encoding_should_return_err() {
+        for params in ["encoding: utf8", "encoding: 1", "encoding: [hex]"] {
+            assert!(from_yaml(params).is_err(), "{}", params);
+        }
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_bytes_should_encode_them() {
+        let bytes = vec![0, 15, 16, 255, 104, 105];
+        assert_eq!(hex(&bytes), "000f10ff6869");
+        assert_eq!(base64(&bytes), "AA8Q/2hp");
+        assert_eq!(base64(b"hi"), "aGk=");
+
+        assert_eq!(
+            Encoding::Raw.value(bytes.clone(), true),
+            Value::Bytes(bytes.clone(), true)
+        );
+        assert_eq!(
+            Encoding::Hex.value(bytes.clone(), true),
+            Value::String("000f10ff6869".to_string())
+        );
+        assert_eq!(
+            Encoding::Base64.value(bytes, false),
+            Value::String("AA8Q/2hp".to_string())
+        );
+    }
+}
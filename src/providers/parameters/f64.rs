@@ -3,6 +3,7 @@ use yaml_rust::Yaml;
 use log::warn;
 
 use super::get_column_name;
+use crate::errors::FakeLakeError;
 
 pub struct F64Parameter {
     pub value: f64,
@@ -24,6 +25,35 @@ impl F64Parameter {
 
         F64Parameter { value: param_f64 }
     }
+
+    /// Same as new, but a param that is not a number is an error instead of a warning
+    pub fn from_yaml(
+        column: &Yaml,
+        param_name: &str,
+        default_value: f64,
+    ) -> Result<F64Parameter, FakeLakeError> {
+        let value = match &column[param_name] {
+            Yaml::BadValue => Some(default_value),
+            value => number(value),
+        };
+        match value {
+            Some(value) => Ok(F64Parameter { value }),
+            None => Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} param {} should be a number.",
+                get_column_name(column),
+                param_name
+            ))),
+        }
+    }
+}
+
+/// Number of a YAML value, integers being accepted
+pub fn number(value: &Yaml) -> Option<f64> {
+    match value {
+        Yaml::Real(value) => value.parse::<f64>().ok(),
+        Yaml::Integer(value) => Some(*value as f64),
+        _ => None,
+    }
 }
 
 fn print_wrong_param(column_name: &str, param_name: &str, new_value: f64) {
@@ -37,7 +67,7 @@ fn print_wrong_param(column_name: &str, param_name: &str, new_value: f64) {
 mod tests {
     use yaml_rust::{Yaml, YamlLoader};
 
-    use super::F64Parameter;
+    use super::{number, F64Parameter};
 
     fn generate_yaml(name: Option<&str>, param_name: &str, param_value: Option<&str>) -> Vec<Yaml> {
         let yaml_name = match name {
@@ -81,4 +111,28 @@ mod tests {
         let f64parameter = F64Parameter::new(&yaml_param[0], "param", 10.4);
         assert_eq!(f64parameter.value, 10.4);
     }
+
+    #[test]
+    fn given_number_param_should_give_value() {
+        for (param_value, expected) in [(Some("5"), 5.0), (Some("-5.3"), -5.3), (None, 10.4)] {
+            let yaml_param = generate_yaml(Some("col"), "param", param_value);
+            let f64parameter = F64Parameter::from_yaml(&yaml_param[0], "param", 10.4).unwrap();
+            assert_eq!(f64parameter.value, expected);
+        }
+    }
+
+    #[test]
+    fn given_not_number_param_should_return_err() {
+        for param_value in ["str", "[1, 2]", "true"] {
+            let yaml_param = generate_yaml(Some("col"), "param", Some(param_value));
+            assert!(F64Parameter::from_yaml(&yaml_param[0], "param", 10.4).is_err());
+        }
+    }
+
+    #[test]
+    fn given_yaml_values_should_give_numbers() {
+        let yaml = YamlLoader::load_from_str("[1, 2.5, a, ~]").unwrap();
+        let numbers: Vec<Option<f64>> = yaml[0].as_vec().unwrap().iter().map(number).collect();
+        assert_eq!(numbers, vec![Some(1.0), Some(2.5), None, None]);
+    }
 }
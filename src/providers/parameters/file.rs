@@ -3,6 +3,7 @@ use std::fs::read_to_string;
 use yaml_rust::Yaml;
 
 use super::get_column_name;
+use crate::providers::utils::string::lines;
 
 pub struct FileParameter {
     pub path: String,
@@ -28,7 +29,7 @@ impl FileParameter {
 
     pub fn get_file_content(&self) -> Vec<String> {
         match read_to_string(&self.path) {
-            Ok(content) => content.lines().map(|v| v.to_string()).collect(),
+            Ok(content) => lines(&content),
             Err(error) => panic!("Error {} occured when read file {}", error, &self.path),
         }
     }
@@ -0,0 +1,138 @@
+use yaml_rust::Yaml;
+
+use log::warn;
+
+use super::get_column_name;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Locale {
+    DeDE,
+    EnGB,
+    EnUS,
+    EsES,
+    FrFR,
+    ItIT,
+    JaJP,
+    PtBR,
+    ZhCN,
+}
+
+pub const DEFAULT_LOCALE: Locale = Locale::FrFR;
+
+pub const AVAILABLE_LOCALES: &[&str] = &[
+    "de_DE", "en_GB", "en_US", "es_ES", "fr_FR", "it_IT", "ja_JP", "pt_BR", "zh_CN",
+];
+
+impl Locale {
+    /// Locale of a code like en_US. The case and a dash instead of the underscore are accepted.
+    pub fn from_code(code: &str) -> Option<Locale> {
+        match code.replace('-', "_").to_lowercase().as_str() {
+            "de_de" => Some(Locale::DeDE),
+            "en_gb" => Some(Locale::EnGB),
+            "en_us" => Some(Locale::EnUS),
+            "es_es" => Some(Locale::EsES),
+            "fr_fr" => Some(Locale::FrFR),
+            "it_it" => Some(Locale::ItIT),
+            "ja_jp" => Some(Locale::JaJP),
+            "pt_br" => Some(Locale::PtBR),
+            "zh_cn" => Some(Locale::ZhCN),
+            _ => None,
+        }
+    }
+
+    /// ISO 3166-1 alpha-2 code of the country of the locale
+    pub fn country_code(&self) -> &'static str {
+        match self {
+            Locale::DeDE => "DE",
+            Locale::EnGB => "GB",
+            Locale::EnUS => "US",
+            Locale::EsES => "ES",
+            Locale::FrFR => "FR",
+            Locale::ItIT => "IT",
+            Locale::JaJP => "JP",
+            Locale::PtBR => "BR",
+            Locale::ZhCN => "CN",
+        }
+    }
+}
+
+pub struct LocaleParameter {
+    pub value: Locale,
+}
+
+impl LocaleParameter {
+    pub fn new(column: &Yaml) -> LocaleParameter {
+        let column_name = get_column_name(column);
+        let value = match &column["locale"] {
+            Yaml::BadValue => DEFAULT_LOCALE,
+            Yaml::String(code) => match Locale::from_code(code) {
+                Some(locale) => locale,
+                None => {
+                    print_wrong_param(column_name);
+                    DEFAULT_LOCALE
+                }
+            },
+            _ => {
+                print_wrong_param(column_name);
+                DEFAULT_LOCALE
+            }
+        };
+
+        LocaleParameter { value }
+    }
+}
+
+fn print_wrong_param(column_name: &str) {
+    warn!(
+        "Column {} param locale should be one of {}. Value {:?} is taken instead.",
+        column_name,
+        AVAILABLE_LOCALES.join(", "),
+        DEFAULT_LOCALE
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use yaml_rust::YamlLoader;
+
+    use super::{Locale, LocaleParameter, AVAILABLE_LOCALES, DEFAULT_LOCALE};
+
+    fn generate_parameter(yaml_str: &str) -> LocaleParameter {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        LocaleParameter::new(&yaml[0])
+    }
+
+    #[test]
+    fn given_no_locale_should_return_default() {
+        assert_eq!(generate_parameter("name: id").value, DEFAULT_LOCALE);
+    }
+
+    #[test]
+    fn given_locale_should_return_it() {
+        assert_eq!(
+            generate_parameter("name: id\nlocale: ja_JP").value,
+            Locale::JaJP
+        );
+        assert_eq!(
+            generate_parameter("name: id\nlocale: en-gb").value,
+            Locale::EnGB
+        );
+    }
+
+    #[test]
+    fn given_wrong_locale_should_return_default() {
+        for value in ["xx_XX", "12", "[en_US]"] {
+            assert_eq!(
+                generate_parameter(&format!("name: id\nlocale: {}", value)).value,
+                DEFAULT_LOCALE
+            );
+        }
+    }
+
+    #[test]
+    fn given_available_locales_should_be_known() {
+        for code in AVAILABLE_LOCALES {
+            assert!(Locale::from_code(code).is_some(), "{} is unknown", code);
+        }
+    }
+}
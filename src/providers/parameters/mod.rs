@@ -1,10 +1,15 @@
+pub mod country;
 pub mod date;
 pub mod datetime;
+pub mod duration;
+pub mod encoding;
 pub mod f64;
 pub mod file;
 pub mod i32;
+pub mod locale;
 pub mod percentage;
 pub mod string;
+pub mod timestamp;
 pub mod urange;
 pub mod wstring;
 
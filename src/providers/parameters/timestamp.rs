@@ -0,0 +1,299 @@
+use crate::errors::FakeLakeError;
+
+use chrono::{DateTime, FixedOffset, NaiveDateTime, TimeZone, Utc};
+use yaml_rust::Yaml;
+
+use super::get_column_name;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Precision {
+    #[default]
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+}
+
+impl Precision {
+    fn parse(value: &str) -> Option<Precision> {
+        match value {
+            "s" => Some(Precision::Seconds),
+            "ms" => Some(Precision::Milliseconds),
+            "us" => Some(Precision::Microseconds),
+            "ns" => Some(Precision::Nanoseconds),
+            _ => None,
+        }
+    }
+
+    /// Number of units in a second
+    pub fn per_second(&self) -> i64 {
+        match self {
+            Precision::Seconds => 1,
+            Precision::Milliseconds => 1_000,
+            Precision::Microseconds => 1_000_000,
+            Precision::Nanoseconds => 1_000_000_000,
+        }
+    }
+
+    /// Fractional seconds of the precision, with their leading dot
+    fn fraction_format(&self) -> &'static str {
+        match self {
+            Precision::Seconds => "",
+            Precision::Milliseconds => "%.3f",
+            Precision::Microseconds => "%.6f",
+            Precision::Nanoseconds => "%.9f",
+        }
+    }
+}
+
+/// Unit and timezone of timestamps. Values are kept in UTC, the timezone is only used to
+/// read and write them.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TimestampType {
+    pub precision: Precision,
+    /// Fixed offset of the timezone, None for timestamps without timezone
+    pub timezone: Option<FixedOffset>,
+}
+
+fn parse_timezone(value: &str) -> Option<FixedOffset> {
+    match value {
+        "UTC" | "Z" => FixedOffset::east_opt(0),
+        _ => value.parse().ok(),
+    }
+}
+
+impl TimestampType {
+    /// Params precision and timezone of the column, seconds without timezone by default
+    pub fn from_yaml(column: &Yaml) -> Result<TimestampType, FakeLakeError> {
+        let column_name = get_column_name(column);
+
+        let precision = match &column["precision"] {
+            Yaml::BadValue => Some(Precision::default()),
+            value => value.as_str().and_then(Precision::parse),
+        };
+        let precision = match precision {
+            Some(value) => value,
+            None => {
+                return Err(FakeLakeError::BadYAMLFormat(format!(
+                    "Column {} param precision should be one of s, ms, us and ns.",
+                    column_name
+                )))
+            }
+        };
+
+        let timezone = match &column["timezone"] {
+            Yaml::BadValue => None,
+            value => match value.as_str().and_then(parse_timezone) {
+                Some(value) => Some(value),
+                None => {
+                    return Err(FakeLakeError::BadYAMLFormat(format!(
+                        "Column {} param timezone should be UTC or an offset like +02:00.",
+                        column_name
+                    )))
+                }
+            },
+        };
+
+        Ok(TimestampType {
+            precision,
+            timezone,
+        })
+    }
+
+    /// Format followed by the fractional seconds of the precision and the offset of the timezone
+    pub fn default_format(&self, format: &str) -> String {
+        let offset = match self.timezone {
+            Some(_) => "%:z",
+            None => "",
+        };
+        format!("{}{}{}", format, self.precision.fraction_format(), offset)
+    }
+
+    /// Timestamp in the timezone, UTC when there is none
+    pub fn local(&self, value: &DateTime<Utc>) -> DateTime<FixedOffset> {
+        value.with_timezone(&self.timezone.unwrap_or(FixedOffset::east_opt(0).unwrap()))
+    }
+
+    pub fn format(&self, value: &DateTime<Utc>, format: &str) -> String {
+        self.local(value).format(format).to_string()
+    }
+
+    /// Timestamp of a datetime read in the timezone
+    pub fn timestamp_of_local(&self, datetime: NaiveDateTime) -> DateTime<Utc> {
+        self.timezone
+            .and_then(|timezone| timezone.from_local_datetime(&datetime).single())
+            .map(|value| value.with_timezone(&Utc))
+            .unwrap_or(datetime.and_utc())
+    }
+
+    /// Timestamp of a number of units of the precision since the epoch
+    pub fn timestamp(&self, units: i64) -> Option<DateTime<Utc>> {
+        match self.precision {
+            Precision::Seconds => DateTime::from_timestamp(units, 0),
+            Precision::Milliseconds => DateTime::from_timestamp_millis(units),
+            Precision::Microseconds => DateTime::from_timestamp_micros(units),
+            Precision::Nanoseconds => Some(DateTime::from_timestamp_nanos(units)),
+        }
+    }
+
+    /// Number of units of the precision since the epoch, None if it does not fit in an i64
+    pub fn units(&self, value: &DateTime<Utc>) -> Option<i64> {
+        match self.precision {
+            Precision::Seconds => Some(value.timestamp()),
+            Precision::Milliseconds => Some(value.timestamp_millis()),
+            Precision::Microseconds => Some(value.timestamp_micros()),
+            Precision::Nanoseconds => value.timestamp_nanos_opt(),
+        }
+    }
+
+    /// Range of the units of the timestamps that can be written
+    pub fn units_range(&self) -> (i64, i64) {
+        match self.precision {
+            Precision::Nanoseconds => (i64::MIN, i64::MAX),
+            _ => (
+                self.units(&DateTime::<Utc>::MIN_UTC).unwrap(),
+                self.units(&DateTime::<Utc>::MAX_UTC).unwrap(),
+            ),
+        }
+    }
+
+    /// Name of the timezone written in the Parquet schema
+    pub fn timezone_name(&self) -> Option<String> {
+        self.timezone
+            .map(|timezone| match timezone.local_minus_utc() {
+                0 => "UTC".to_string(),
+                _ => timezone.to_string(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Precision, TimestampType};
+
+    use chrono::{DateTime, FixedOffset, NaiveDate};
+    use yaml_rust::YamlLoader;
+
+    fn from_yaml(params: &str) -> Result<TimestampType, crate::errors::FakeLakeError> {
+        let yaml = YamlLoader::load_from_str(&format!("name: a\n{}", params)).unwrap();
+        TimestampType::from_yaml(&yaml[0])
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_no_params_should_return_seconds_without_timezone() {
+        assert_eq!(from_yaml("").unwrap(), TimestampType::default());
+        assert_eq!(TimestampType::default().precision, Precision::Seconds);
+        assert_eq!(TimestampType::default().timezone, None);
+    }
+
+    #[test]
+    fn given_params_should_parse_them() {
+        for (params, precision, offset) in [
+            ("precision: s", Precision::Seconds, None),
+            (
+                "precision: ms\ntimezone: UTC",
+                Precision::Milliseconds,
+                Some(0),
+            ),
+            (
+                "precision: us\ntimezone: Z",
+                Precision::Microseconds,
+                Some(0),
+            ),
+            (
+                "precision: ns\ntimezone: +02:00",
+                Precision::Nanoseconds,
+                Some(7200),
+            ),
+            ("timezone: \"-05:30\"", Precision::Seconds, Some(-19800)),
+        ] {
+            let timestamp_type = from_yaml(params).unwrap();
+            assert_eq!(timestamp_type.precision, precision, "{}", params);
+            assert_eq!(
+                timestamp_type.timezone,
+                offset.map(|offset| FixedOffset::east_opt(offset).unwrap()),
+                "{}",
+                params
+            );
+        }
+    }
+
+    #[test]
+    fn given_wrong_params_should_return_err() {
+        for params in [
+            "precision: 1",
+            "precision: min",
+            "timezone: 2",
+            "timezone: Mars",
+            "timezone: +25:00",
+        ] {
+            assert!(from_yaml(params).is_err(), "{}", params);
+        }
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_timezone_should_write_local_time_with_offset() {
+        let timestamp_type = from_yaml("precision: ms\ntimezone: +02:00").unwrap();
+        let format = timestamp_type.default_format("%Y-%m-%d %H:%M:%S");
+        assert_eq!(format, "%Y-%m-%d %H:%M:%S%.3f%:z");
+
+        let value = DateTime::from_timestamp_millis(1_704_067_200_123).unwrap();
+        assert_eq!(
+            timestamp_type.format(&value, &format),
+            "2024-01-01 02:00:00.123+02:00"
+        );
+        assert_eq!(timestamp_type.timezone_name(), Some("+02:00".to_string()));
+        assert_eq!(
+            TimestampType::default().format(&value, &format),
+            "2024-01-01 00:00:00.123+00:00"
+        );
+    }
+
+    #[test]
+    fn given_local_datetime_should_return_utc_timestamp() {
+        let timestamp_type = from_yaml("timezone: +02:00").unwrap();
+        let datetime = NaiveDate::from_ymd_opt(2024, 1, 1)
+            .unwrap()
+            .and_hms_opt(2, 0, 0)
+            .unwrap();
+        assert_eq!(
+            timestamp_type.timestamp_of_local(datetime).timestamp(),
+            1_704_067_200
+        );
+        assert_eq!(
+            TimestampType::default()
+                .timestamp_of_local(datetime)
+                .timestamp(),
+            1_704_074_400
+        );
+        assert_eq!(
+            from_yaml("timezone: UTC").unwrap().timezone_name(),
+            Some("UTC".to_string())
+        );
+    }
+
+    #[test]
+    fn given_precisions_should_convert_units() {
+        for (precision, units) in [
+            ("s", 1_704_067_200),
+            ("ms", 1_704_067_200_123),
+            ("us", 1_704_067_200_123_456),
+            ("ns", 1_704_067_200_123_456_789),
+        ] {
+            let timestamp_type = from_yaml(&format!("precision: {}", precision)).unwrap();
+            let value = timestamp_type.timestamp(units).unwrap();
+            assert_eq!(timestamp_type.units(&value), Some(units), "{}", precision);
+            let (min, max) = timestamp_type.units_range();
+            assert!(timestamp_type.timestamp(min).is_some());
+            assert!(timestamp_type.timestamp(max).is_some());
+        }
+        // nanoseconds since the epoch don't fit in an i64 after 2262
+        let timestamp_type = from_yaml("precision: ns").unwrap();
+        assert_eq!(
+            timestamp_type.units(&DateTime::<chrono::Utc>::MAX_UTC),
+            None
+        );
+    }
+}
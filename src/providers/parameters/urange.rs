@@ -29,6 +29,26 @@ impl URangeParameter {
             max: u32parameters[1],
         }
     }
+
+    /// Range of the parameter, or the default range, max excluded, when it is missing
+    pub fn with_default_range(
+        column: &Yaml,
+        param_name: &str,
+        default: (u32, u32),
+    ) -> URangeParameter {
+        match &column[param_name] {
+            Yaml::BadValue => URangeParameter {
+                min: default.0,
+                max: default.1,
+            },
+            _ => URangeParameter::new(column, param_name, default.0),
+        }
+    }
+
+    /// Min and max of the range, the max being excluded
+    pub fn range(&self) -> (u32, u32) {
+        (self.min, self.max)
+    }
 }
 
 fn new_from_i64(column_name: &str, param_name: &str, value: i64, default_value: u32) -> Vec<u32> {
@@ -204,4 +224,19 @@ mod tests {
         assert_eq!(urangeparameter.min, 10);
         assert_eq!(urangeparameter.max, 11);
     }
+
+    #[test]
+    fn given_default_range_should_give_it_when_param_is_missing() {
+        let yaml_param = generate_yaml(Some("col"), "param", None);
+        let urangeparameter = URangeParameter::with_default_range(&yaml_param[0], "param", (3, 8));
+        assert_eq!(urangeparameter.range(), (3, 8));
+
+        let yaml_param = generate_yaml(Some("col"), "param", Some("5..6"));
+        let urangeparameter = URangeParameter::with_default_range(&yaml_param[0], "param", (3, 8));
+        assert_eq!(urangeparameter.range(), (5, 6));
+
+        let yaml_param = generate_yaml(Some("col"), "param", Some("a"));
+        let urangeparameter = URangeParameter::with_default_range(&yaml_param[0], "param", (3, 8));
+        assert_eq!(urangeparameter.range(), (3, 4));
+    }
 }
@@ -71,6 +71,22 @@ impl WStringParameter {
     }
 }
 
+/// Value drawn according to the weights, the first one if all the weights are 0
+pub fn weighted_random(parameters: &[WStringParameter]) -> &str {
+    let sum: u32 = parameters.iter().map(|w| w.weight).sum();
+    if sum == 0 {
+        return &parameters[0].value;
+    }
+    let mut random = crate::rng::u32(0..sum);
+    for parameter in parameters {
+        if random < parameter.weight {
+            return &parameter.value;
+        }
+        random -= parameter.weight;
+    }
+    panic!("Random value can't be greater than the weights")
+}
+
 fn extract_weight(column_name: &str, weight: Option<i64>) -> u32 {
     let w: i64 = weight.unwrap_or(1);
     if w >= u32::MIN as i64 && w <= u32::MAX as i64 {
@@ -149,6 +165,7 @@ mod tests {
     use crate::providers::parameters::wstring::WStringParameter;
 
     use super::extract_hash;
+    use super::weighted_random;
     use super::WEIGHT_KEY;
 
     fn generate_yaml(name: Option<&str>, param_name: &str, param_value: Option<&str>) -> Vec<Yaml> {
@@ -295,6 +312,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn given_weights_should_weighted_random_follow_them() {
+        let parameters = [
+            WStringParameter {
+                value: "never".to_string(),
+                weight: 0,
+            },
+            WStringParameter {
+                value: "always".to_string(),
+                weight: 3,
+            },
+        ];
+        for _ in 0..20 {
+            assert_eq!(weighted_random(&parameters), "always");
+        }
+    }
+
     #[test]
     fn given_weight_should_return_u32() {
         let data: [(i64, u32); 6] = [
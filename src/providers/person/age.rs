@@ -0,0 +1,215 @@
+use crate::providers::parameters::get_column_name;
+use crate::providers::parameters::string::StringParameter;
+use crate::providers::parameters::urange::URangeParameter;
+use crate::providers::provider::{Provider, Value};
+use crate::rng;
+
+use chrono::{Datelike, Months, NaiveDate};
+use log::warn;
+use yaml_rust::Yaml;
+
+const DEFAULT_MIN_AGE: u32 = 18;
+const DEFAULT_MAX_AGE: u32 = 80;
+const DEFAULT_FORMAT: &str = "%Y-%m-%d";
+/// Date at which the ages are computed by default. It is fixed so that a seed
+/// gives the same birthdates whatever the day of the generation.
+const DEFAULT_AT: &str = "2025-01-01";
+
+/// Ages of the range parameter, the max being excluded
+fn get_age_range(column: &Yaml) -> (u32, u32) {
+    URangeParameter::with_default_range(column, "range", (DEFAULT_MIN_AGE, DEFAULT_MAX_AGE)).range()
+}
+
+#[derive(Clone)]
+pub struct AgeProvider {
+    pub min: u32,
+    pub max: u32,
+}
+
+impl Provider for AgeProvider {
+    fn value(&self, _: u32) -> Value {
+        Value::Int32(rng::u32(self.min..self.max) as i32)
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        // negative ages or ages nobody reaches
+        match rng::bool() {
+            true => Value::Int32(-rng::i32(1..100)),
+            false => Value::Int32(rng::i32(150..1000)),
+        }
+    }
+}
+
+pub fn age_from_yaml(column: &Yaml) -> Box<AgeProvider> {
+    let (min, max) = get_age_range(column);
+    Box::new(AgeProvider { min, max })
+}
+
+#[derive(Clone)]
+pub struct BirthdateProvider {
+    pub format: String,
+    /// First and last birthdates giving an age of the range, as days from CE
+    pub after: i32,
+    pub before: i32,
+    /// Date at which the ages are computed
+    pub at: NaiveDate,
+}
+
+impl Provider for BirthdateProvider {
+    fn value(&self, _: u32) -> Value {
+        let days = rng::i32(self.after..self.before + 1);
+        Value::Date(
+            NaiveDate::from_num_days_from_ce_opt(days).unwrap(),
+            self.format.clone(),
+        )
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        // birthdates after the date of the ages
+        let days = self.at.num_days_from_ce() + rng::i32(1..3650);
+        Value::Date(
+            NaiveDate::from_num_days_from_ce_opt(days).unwrap(),
+            self.format.clone(),
+        )
+    }
+}
+
+pub fn birthdate_from_yaml(column: &Yaml) -> Box<BirthdateProvider> {
+    let column_name = get_column_name(column);
+    let format = StringParameter::new(column, "format", DEFAULT_FORMAT).value;
+    let default_at = NaiveDate::parse_from_str(DEFAULT_AT, "%Y-%m-%d").unwrap();
+
+    let at = match &column["at"] {
+        Yaml::String(value) => match NaiveDate::parse_from_str(value, &format) {
+            Ok(date) => date,
+            Err(_) => {
+                warn!(
+                    "Column {} param at should be a date with the format {}. Default {} is taken instead.",
+                    column_name, format, DEFAULT_AT
+                );
+                default_at
+            }
+        },
+        _ => default_at,
+    };
+
+    // born at least min years ago, and less than max years ago
+    let (min, max) = get_age_range(column);
+    let before = at.checked_sub_months(Months::new(min * 12)).unwrap_or(at);
+    let after = at
+        .checked_sub_months(Months::new(max * 12))
+        .and_then(|date| date.succ_opt())
+        .unwrap_or(before);
+
+    Box::new(BirthdateProvider {
+        format,
+        after: after.num_days_from_ce(),
+        before: before.num_days_from_ce().max(after.num_days_from_ce()),
+        at,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{age_from_yaml, birthdate_from_yaml, DEFAULT_AT, DEFAULT_MAX_AGE, DEFAULT_MIN_AGE};
+    use crate::providers::provider::{Provider, Value};
+
+    use chrono::NaiveDate;
+    use yaml_rust::YamlLoader;
+
+    fn column(yaml_str: &str) -> yaml_rust::Yaml {
+        YamlLoader::load_from_str(yaml_str).unwrap()[0].clone()
+    }
+
+    fn date(value: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d").unwrap()
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_no_range_should_use_default() {
+        let provider = age_from_yaml(&column("name: age"));
+        assert_eq!(provider.min, DEFAULT_MIN_AGE);
+        assert_eq!(provider.max, DEFAULT_MAX_AGE);
+    }
+
+    #[test]
+    fn given_range_should_use_it() {
+        let provider = age_from_yaml(&column("name: age\nrange: 25..30"));
+        assert_eq!((provider.min, provider.max), (25, 30));
+    }
+
+    #[test]
+    fn given_at_should_compute_birthdates_from_it() {
+        let provider =
+            birthdate_from_yaml(&column("name: birthdate\nrange: 18..20\nat: 2024-03-15"));
+        assert_eq!(provider.at, date("2024-03-15"));
+        assert_eq!(
+            NaiveDate::from_num_days_from_ce_opt(provider.after).unwrap(),
+            date("2004-03-16")
+        );
+        assert_eq!(
+            NaiveDate::from_num_days_from_ce_opt(provider.before).unwrap(),
+            date("2006-03-15")
+        );
+    }
+
+    #[test]
+    fn given_no_at_should_use_default_date() {
+        let provider = birthdate_from_yaml(&column("name: birthdate"));
+        assert_eq!(provider.at, date(DEFAULT_AT));
+
+        let provider = birthdate_from_yaml(&column("name: birthdate\nat: someday"));
+        assert_eq!(provider.at, date(DEFAULT_AT));
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_seed_without_at_should_return_same_birthdates() {
+        let birthdates = |yaml_str: &str| -> Vec<Value> {
+            crate::rng::initialize_rng(Some(42));
+            let provider = birthdate_from_yaml(&column(yaml_str));
+            (0..100).map(|i| provider.value(i)).collect()
+        };
+
+        let default_at = birthdates("name: birthdate");
+        assert_eq!(default_at, birthdates("name: birthdate"));
+        assert_eq!(
+            default_at,
+            birthdates(&format!("name: birthdate\nat: {}", DEFAULT_AT))
+        );
+    }
+
+    #[test]
+    fn given_range_should_return_ages_in_it() {
+        let provider = age_from_yaml(&column("name: age\nrange: 25..30"));
+        for i in 0..100 {
+            match provider.value(i) {
+                Value::Int32(value) => assert!((25..30).contains(&value)),
+                _ => panic!("Wrong type"),
+            }
+            match provider.corrupted_value(i) {
+                Value::Int32(value) => assert!(!(0..150).contains(&value)),
+                _ => panic!("Wrong type"),
+            }
+        }
+    }
+
+    #[test]
+    fn given_range_should_return_birthdates_of_these_ages() {
+        let provider = birthdate_from_yaml(&column(
+            "name: birthdate\nrange: 30..31\nat: 2024-03-15\nformat: '%Y-%m-%d'",
+        ));
+        for i in 0..100 {
+            match provider.value(i) {
+                Value::Date(value, format) => {
+                    assert!(value > date("1993-03-15") && value <= date("1994-03-15"));
+                    assert_eq!(format, "%Y-%m-%d");
+                }
+                _ => panic!("Wrong type"),
+            }
+            match provider.corrupted_value(i) {
+                Value::Date(value, _) => assert!(value > date("2024-03-15")),
+                _ => panic!("Wrong type"),
+            }
+        }
+    }
+}
@@ -1,41 +1,43 @@
 use crate::errors::FakeLakeError;
-use crate::providers::constant::external;
 use crate::providers::provider::{unknown_provider, Provider};
 
-use super::email;
+use super::{age, email, gender, name, phone, username};
 
-use once_cell::sync::Lazy;
 use yaml_rust::Yaml;
 
-const AVAILABLE: &[&str] = &["person.email", "person.fname", "person.lname"];
-
-static FIRST_NAMES: Lazy<Vec<String>> = Lazy::new(|| {
-    let raw_first_names = include_str!("../../../static/first_name_fr.txt");
-    raw_first_names.lines().map(|v| v.to_string()).collect()
-});
-
-static LAST_NAMES: Lazy<Vec<String>> = Lazy::new(|| {
-    let raw_larst_names = include_str!("../../../static/last_name_fr.txt");
-    raw_larst_names.lines().map(|v| v.to_string()).collect()
-});
+const AVAILABLE: &[&str] = &[
+    "person.age",
+    "person.birthdate",
+    "person.email",
+    "person.fname",
+    "person.full_name",
+    "person.gender",
+    "person.lname",
+    "person.phone",
+    "person.username",
+];
 
 pub fn get_corresponding_provider(
     mut provider_split: std::str::Split<'_, char>,
     column: &Yaml,
 ) -> Result<Box<dyn Provider>, FakeLakeError> {
     match provider_split.next() {
+        Some("age") => Ok(age::age_from_yaml(column)),
+        Some("birthdate") => Ok(age::birthdate_from_yaml(column)),
         Some("email") => Ok(email::new_from_yaml(column)),
-        Some("fname") => Ok(external::new(FIRST_NAMES.to_vec())),
-        Some("lname") => Ok(external::new(LAST_NAMES.to_vec())),
+        Some("fname") => name::first_name_from_yaml(column),
+        Some("full_name") => name::full_name_from_yaml(column),
+        Some("gender") => Ok(gender::new_from_yaml(column)),
+        Some("lname") => Ok(name::last_name_from_yaml(column)),
+        Some("phone") => Ok(phone::new_from_yaml(column)?),
+        Some("username") => Ok(username::new_from_yaml(column)),
         other => Err(unknown_provider("person", other, AVAILABLE)),
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::providers::person::builder::LAST_NAMES;
-
-    use super::{get_corresponding_provider, FIRST_NAMES};
+    use super::get_corresponding_provider;
 
     use yaml_rust::YamlLoader;
 
@@ -78,6 +80,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn given_new_person_providers_should_return_provider() {
+        for provider_name in [
+            "age",
+            "birthdate",
+            "full_name",
+            "gender",
+            "phone",
+            "username",
+        ] {
+            let yaml_str = format!("name: person{}provider: {}", '\n', provider_name);
+            let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+            let provider_split = provider_name.split('.');
+            match get_corresponding_provider(provider_split, column) {
+                Ok(_) => (),
+                _ => panic!("{} should return a provider", provider_name),
+            }
+        }
+    }
+
     #[test]
     fn given_wrong_provider_should_return_error() {
         let provider_name = "not_a_provider";
@@ -90,10 +113,4 @@ mod tests {
             _ => panic!(),
         }
     }
-
-    #[test]
-    fn given_name_files_should_be_loaded() {
-        assert_eq!(13387, FIRST_NAMES.len());
-        assert_eq!(95590, LAST_NAMES.len());
-    }
 }
@@ -1,22 +1,130 @@
-use crate::providers::parameters::string::StringParameter;
+use crate::providers::parameters::get_column_name;
+use crate::providers::parameters::locale::{Locale, LocaleParameter};
+use crate::providers::parameters::percentage::PercentageParameter;
+use crate::providers::parameters::wstring::{weighted_random, WStringParameter};
 use crate::providers::provider::{Provider, Value};
 use crate::providers::utils::string::{random_alphanumeric, random_characters};
+use crate::rng;
 
+use super::names;
+
+use log::warn;
 use yaml_rust::Yaml;
 
 const DEFAULT_DOMAIN: &str = "example.com";
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LocalPart {
+    /// 10 random alphanumeric characters
+    Random,
+    /// First and last names, like john.doe, jdoe42 or doe_john
+    Name,
+}
+
+/// Free mail domains used by the people of a locale
+fn free_mail_domains(locale: Locale) -> &'static [&'static str] {
+    match locale {
+        Locale::DeDE => &["gmail.com", "web.de", "gmx.de", "t-online.de", "outlook.de"],
+        Locale::EnGB => &[
+            "gmail.com",
+            "hotmail.co.uk",
+            "yahoo.co.uk",
+            "outlook.com",
+            "btinternet.com",
+        ],
+        Locale::EnUS => &[
+            "gmail.com",
+            "yahoo.com",
+            "outlook.com",
+            "hotmail.com",
+            "aol.com",
+            "icloud.com",
+        ],
+        Locale::EsES => &[
+            "gmail.com",
+            "hotmail.es",
+            "yahoo.es",
+            "outlook.es",
+            "telefonica.net",
+        ],
+        Locale::FrFR => &[
+            "gmail.com",
+            "orange.fr",
+            "free.fr",
+            "laposte.net",
+            "hotmail.fr",
+            "sfr.fr",
+            "yahoo.fr",
+        ],
+        Locale::ItIT => &[
+            "gmail.com",
+            "libero.it",
+            "virgilio.it",
+            "hotmail.it",
+            "tiscali.it",
+        ],
+        Locale::JaJP => &[
+            "gmail.com",
+            "yahoo.co.jp",
+            "docomo.ne.jp",
+            "ezweb.ne.jp",
+            "icloud.com",
+        ],
+        Locale::PtBR => &[
+            "gmail.com",
+            "hotmail.com",
+            "uol.com.br",
+            "bol.com.br",
+            "yahoo.com.br",
+        ],
+        Locale::ZhCN => &["qq.com", "163.com", "126.com", "sina.com", "gmail.com"],
+    }
+}
+
+fn name_local_part(locale: Locale) -> String {
+    let (first_name, last_name) = names::random_ascii_names(locale);
+    let initial = &first_name[..1];
+    match rng::u32(0..8) {
+        0 => format!("{}.{}", first_name, last_name),
+        1 => format!("{}_{}", first_name, last_name),
+        2 => format!("{}{}", first_name, last_name),
+        3 => format!("{}{}", initial, last_name),
+        4 => format!("{}{}{}", initial, last_name, rng::u32(1..100)),
+        5 => format!("{}_{}", last_name, first_name),
+        6 => format!("{}.{}", last_name, first_name),
+        _ => format!("{}.{}{}", first_name, last_name, rng::u32(1..100)),
+    }
+}
+
 #[derive(Clone)]
 pub struct EmailProvider {
-    pub domain: String,
+    pub local_part: LocalPart,
+    pub locale: Locale,
+    /// Corporate domains
+    pub domains: Vec<WStringParameter>,
+    /// Share of the emails using a free mail domain of the locale
+    pub free_mail: f64,
+}
+
+impl EmailProvider {
+    fn domain(&self) -> &str {
+        match self.free_mail > 0.0 && rng::f64() < self.free_mail {
+            true => {
+                let domains = free_mail_domains(self.locale);
+                domains[rng::usize(..domains.len())]
+            }
+            false => weighted_random(&self.domains),
+        }
+    }
 }
 
 impl Provider for EmailProvider {
     fn value(&self, _: u32) -> Value {
-        // return a random email address
-        // generate a random string of length 10 (subject) + @ + random domain
-        let subject: String = random_alphanumeric(10);
-        Value::String(format!("{}@{}", subject, self.domain))
+        let subject = match self.local_part {
+            LocalPart::Random => random_alphanumeric(10),
+            LocalPart::Name => name_local_part(self.locale),
+        };
+        Value::String(format!("{}@{}", subject, self.domain()))
     }
     fn corrupted_value(&self, _: u32) -> Value {
         // return string that are not emails
@@ -25,16 +133,39 @@ impl Provider for EmailProvider {
 }
 
 pub fn new_from_yaml(column: &Yaml) -> Box<EmailProvider> {
-    let domain_parameter = StringParameter::new(column, "domain", DEFAULT_DOMAIN);
+    let column_name = get_column_name(column);
+
+    let local_part = match column["local_part"].as_str().map(|v| v.to_lowercase()) {
+        None if column["local_part"].is_badvalue() => LocalPart::Random,
+        Some(value) if value == "random" => LocalPart::Random,
+        Some(value) if value == "name" => LocalPart::Name,
+        _ => {
+            warn!(
+                "Column {} param local_part should be random or name. Value random is taken instead.",
+                column_name
+            );
+            LocalPart::Random
+        }
+    };
+
+    let domains = match column["domain"].is_badvalue() {
+        true => vec![WStringParameter::new_from_str(DEFAULT_DOMAIN)],
+        false => WStringParameter::new(column, "domain", DEFAULT_DOMAIN),
+    };
 
     Box::new(EmailProvider {
-        domain: domain_parameter.value,
+        local_part,
+        locale: LocaleParameter::new(column).value,
+        domains,
+        free_mail: PercentageParameter::new(column, "free_mail", 0.0).value,
     })
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{EmailProvider, DEFAULT_DOMAIN};
+    use super::{free_mail_domains, EmailProvider, LocalPart, DEFAULT_DOMAIN};
+    use crate::providers::parameters::locale::{Locale, DEFAULT_LOCALE};
+    use crate::providers::parameters::wstring::WStringParameter;
     use crate::providers::provider::{Provider, Value};
 
     use regex::Regex;
@@ -49,6 +180,26 @@ mod tests {
         super::new_from_yaml(&yaml[0])
     }
 
+    fn domain_provider(domain: &str) -> EmailProvider {
+        EmailProvider {
+            local_part: LocalPart::Random,
+            locale: DEFAULT_LOCALE,
+            domains: vec![WStringParameter::new_from_str(domain)],
+            free_mail: 0.0,
+        }
+    }
+
+    fn generate_values(yaml_str: &str) -> Vec<String> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        let provider = super::new_from_yaml(&yaml[0]);
+        (0..200)
+            .map(|i| match provider.value(i) {
+                Value::String(value) => value,
+                _ => panic!("Wrong type"),
+            })
+            .collect()
+    }
+
     // Parquet type
     #[test]
     fn given_nothing_should_return_parquet_type() {
@@ -63,7 +214,12 @@ mod tests {
     #[test]
     fn given_no_domain_in_yaml_should_give_domain_default() {
         let provider = generate_provider(None);
-        assert_eq!(provider.domain, DEFAULT_DOMAIN);
+        assert_eq!(
+            provider.domains,
+            vec![WStringParameter::new_from_str(DEFAULT_DOMAIN)]
+        );
+        assert_eq!(provider.local_part, LocalPart::Random);
+        assert_eq!(provider.free_mail, 0.0);
     }
 
     #[test]
@@ -71,7 +227,10 @@ mod tests {
         let values_to_check = ["test.com", "domain.org", "test.this"];
         for value in values_to_check {
             let provider = generate_provider(Some(value.to_string()));
-            assert_eq!(provider.domain, value);
+            assert_eq!(
+                provider.domains,
+                vec![WStringParameter::new_from_str(value)]
+            );
         }
     }
 
@@ -80,9 +239,7 @@ mod tests {
     fn given_domain_x_should_return_string_length_10_plus_1_plus_length_x() {
         let domain_to_check = ["test.com", "domain.org", "test.this"];
         for domain in domain_to_check {
-            let provider = EmailProvider {
-                domain: domain.to_string(),
-            };
+            let provider = domain_provider(domain);
             let expected_length = 10 + "@".len() + domain.len();
 
             let values_to_check = [0, 4, 50];
@@ -99,9 +256,7 @@ mod tests {
     fn given_domain_x_should_return_correct_email() {
         let domain_to_check = ["test.com", "domain.org", "other.this"];
         for domain in domain_to_check {
-            let provider = EmailProvider {
-                domain: domain.to_string(),
-            };
+            let provider = domain_provider(domain);
 
             let pattern = format!(r"@{}$", regex::escape(domain));
             let re = Regex::new(&pattern).unwrap();
@@ -120,9 +275,7 @@ mod tests {
     fn given_domain_x_should_return_corrupted_incorrect_email() {
         let domain_to_check = ["test.com", "domain.org", "other.this"];
         for domain in domain_to_check {
-            let provider = EmailProvider {
-                domain: domain.to_string(),
-            };
+            let provider = domain_provider(domain);
 
             let pattern = format!(r"@{}$", regex::escape(domain));
             let re = Regex::new(&pattern).unwrap();
@@ -136,4 +289,52 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn given_weighted_domains_should_return_them() {
+        let values = generate_values(
+            "name: id\ndomain:\n  - value: soma-smart.com\n    weight: 3\n  - value: example.org\n    weight: 1",
+        );
+        let smart = values
+            .iter()
+            .filter(|v| v.ends_with("@soma-smart.com"))
+            .count();
+        let example = values
+            .iter()
+            .filter(|v| v.ends_with("@example.org"))
+            .count();
+        assert_eq!(smart + example, 200);
+        assert!(smart > example);
+    }
+
+    #[test]
+    fn given_name_local_part_should_return_names() {
+        let re = Regex::new(r"^[a-z][a-z0-9]*([._][a-z0-9]+)?@example\.com$").unwrap();
+        for value in generate_values("name: id\nlocal_part: name\nlocale: en_US") {
+            assert!(re.is_match(&value), "{} is not a name email", value);
+        }
+    }
+
+    #[test]
+    fn given_free_mail_should_return_free_mail_domains_accordingly() {
+        let free = free_mail_domains(Locale::DeDE);
+        let values = generate_values("name: id\nlocale: de_DE\ndomain: firma.de\nfree_mail: 0.5");
+        let free_count = values
+            .iter()
+            .filter(|v| free.contains(&v.split('@').nth(1).unwrap()))
+            .count();
+        let corporate_count = values.iter().filter(|v| v.ends_with("@firma.de")).count();
+        assert_eq!(free_count + corporate_count, 200);
+        assert!((60..140).contains(&free_count));
+
+        for value in generate_values("name: id\nfree_mail: 1") {
+            assert!(free_mail_domains(Locale::FrFR).contains(&value.split('@').nth(1).unwrap()));
+        }
+    }
+
+    #[test]
+    fn given_wrong_local_part_should_use_random() {
+        let yaml = YamlLoader::load_from_str("name: id\nlocal_part: robot").unwrap();
+        assert_eq!(super::new_from_yaml(&yaml[0]).local_part, LocalPart::Random);
+    }
 }
@@ -0,0 +1,271 @@
+use crate::errors::FakeLakeError;
+use crate::providers::parameters::get_column_name;
+use crate::providers::provider::{ColumnType, Provider, Value};
+use crate::providers::utils::string::random_alphanumeric;
+use crate::row;
+
+use log::warn;
+use std::collections::HashMap;
+use yaml_rust::Yaml;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Gender {
+    Female,
+    Male,
+    Other,
+}
+
+const GENDERS: [Gender; 3] = [Gender::Female, Gender::Male, Gender::Other];
+const DEFAULT_WEIGHTS: [u32; 3] = [1, 1, 0];
+
+impl Gender {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Gender::Female => "female",
+            Gender::Male => "male",
+            Gender::Other => "other",
+        }
+    }
+
+    /// Gender of a value like female, F, male or M, whatever the case
+    pub fn from_label(label: &str) -> Option<Gender> {
+        match label.to_lowercase().as_str() {
+            "female" | "f" | "woman" => Some(Gender::Female),
+            "male" | "m" | "man" => Some(Gender::Male),
+            "other" | "x" => Some(Gender::Other),
+            _ => None,
+        }
+    }
+
+    /// Female or male with the same probability
+    pub fn random() -> Gender {
+        match crate::rng::bool() {
+            true => Gender::Female,
+            false => Gender::Male,
+        }
+    }
+}
+
+/// Where a provider takes the gender of the person from
+#[derive(Clone, Debug, PartialEq)]
+pub enum GenderSource {
+    Any,
+    Fixed(Gender),
+    Column(String),
+}
+
+impl GenderSource {
+    /// Read the gender parameter, a gender, and the gender_column parameter, a column of the row
+    pub fn new(column: &Yaml) -> Result<GenderSource, FakeLakeError> {
+        let column_name = get_column_name(column);
+        match (&column["gender"], &column["gender_column"]) {
+            (Yaml::BadValue, Yaml::BadValue) => Ok(GenderSource::Any),
+            (Yaml::String(label), Yaml::BadValue) => match Gender::from_label(label) {
+                Some(gender) => Ok(GenderSource::Fixed(gender)),
+                None => Err(FakeLakeError::BadYAMLFormat(format!(
+                    "Column {} param gender should be female, male or other.",
+                    column_name
+                ))),
+            },
+            (Yaml::BadValue, Yaml::String(name)) => Ok(GenderSource::Column(name.clone())),
+            _ => Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} should specify either a gender or a gender_column.",
+                column_name
+            ))),
+        }
+    }
+
+    /// Gender of the person of the current row, None if it is unknown
+    pub fn gender(&self) -> Option<Gender> {
+        match self {
+            GenderSource::Any => None,
+            GenderSource::Fixed(gender) => Some(*gender),
+            GenderSource::Column(name) => match row::get(name) {
+                Some(Value::String(label)) => Gender::from_label(&label),
+                _ => None,
+            },
+        }
+    }
+
+    pub fn dependencies(&self) -> Vec<String> {
+        match self {
+            GenderSource::Column(name) => vec![name.clone()],
+            _ => Vec::new(),
+        }
+    }
+
+    pub fn bind(&self, columns: &HashMap<String, ColumnType>) -> Result<(), FakeLakeError> {
+        match self {
+            GenderSource::Column(name) => match columns.get(name).map(|column| &column.sample) {
+                Some(Value::String(_)) => Ok(()),
+                _ => Err(FakeLakeError::BadYAMLFormat(format!(
+                    "gender_column {} should be a string column.",
+                    name
+                ))),
+            },
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct GenderProvider {
+    /// Weights of female, male and other
+    pub weights: [u32; 3],
+}
+
+impl Provider for GenderProvider {
+    fn value(&self, _: u32) -> Value {
+        let mut random = crate::rng::u32(0..self.weights.iter().sum());
+        for (gender, weight) in GENDERS.iter().zip(self.weights) {
+            if random < weight {
+                return Value::String(gender.label().to_string());
+            }
+            random -= weight;
+        }
+        panic!("Random value can't be greater than the weights")
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        Value::String(random_alphanumeric(6))
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml) -> Box<GenderProvider> {
+    let column_name = get_column_name(column);
+    let weights_yaml = &column["weights"];
+    if weights_yaml.is_badvalue() {
+        return Box::new(GenderProvider {
+            weights: DEFAULT_WEIGHTS,
+        });
+    }
+
+    let mut weights = [0; 3];
+    for (gender, weight) in GENDERS.iter().zip(weights.iter_mut()) {
+        *weight = match &weights_yaml[gender.label()] {
+            Yaml::Integer(value) if *value >= 0 && *value <= u16::MAX as i64 => *value as u32,
+            Yaml::BadValue => 0,
+            _ => {
+                warn!(
+                    "Column {} weight of {} should be a positive integer. It is ignored.",
+                    column_name,
+                    gender.label()
+                );
+                0
+            }
+        };
+    }
+
+    if weights.iter().sum::<u32>() == 0 {
+        warn!(
+            "Column {} weights should give female, male or other weights. Default weights are used.",
+            column_name
+        );
+        weights = DEFAULT_WEIGHTS;
+    }
+
+    Box::new(GenderProvider { weights })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_from_yaml, Gender, GenderSource, DEFAULT_WEIGHTS};
+    use crate::providers::provider::{ColumnType, Provider, Value};
+    use crate::row;
+
+    use std::collections::HashMap;
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider(yaml_str: &str) -> Box<super::GenderProvider> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        new_from_yaml(&yaml[0])
+    }
+
+    fn generate_source(yaml_str: &str) -> Result<GenderSource, crate::errors::FakeLakeError> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        GenderSource::new(&yaml[0])
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_no_weights_should_use_default() {
+        assert_eq!(generate_provider("name: gender").weights, DEFAULT_WEIGHTS);
+    }
+
+    #[test]
+    fn given_weights_should_use_them() {
+        let provider = generate_provider("name: gender\nweights:\n  female: 3\n  other: 1");
+        assert_eq!(provider.weights, [3, 0, 1]);
+    }
+
+    #[test]
+    fn given_wrong_weights_should_use_default() {
+        for weights in [
+            "weights: 3",
+            "weights:\n  female: -1",
+            "weights:\n  female: a",
+        ] {
+            let provider = generate_provider(&format!("name: gender\n{}", weights));
+            assert_eq!(provider.weights, DEFAULT_WEIGHTS);
+        }
+    }
+
+    #[test]
+    fn given_gender_params_should_return_source() {
+        assert_eq!(generate_source("name: a").unwrap(), GenderSource::Any);
+        assert_eq!(
+            generate_source("name: a\ngender: F").unwrap(),
+            GenderSource::Fixed(Gender::Female)
+        );
+        assert_eq!(
+            generate_source("name: a\ngender_column: g").unwrap(),
+            GenderSource::Column("g".to_string())
+        );
+        assert!(generate_source("name: a\ngender: unknown").is_err());
+        assert!(generate_source("name: a\ngender: male\ngender_column: g").is_err());
+    }
+
+    #[test]
+    fn given_gender_column_should_read_it_in_row() {
+        let source = GenderSource::Column("person_gender".to_string());
+        assert_eq!(source.dependencies(), vec!["person_gender"]);
+
+        row::set("person_gender", Some(Value::String("M".to_string())));
+        assert_eq!(source.gender(), Some(Gender::Male));
+        row::set("person_gender", Some(Value::String("unknown".to_string())));
+        assert_eq!(source.gender(), None);
+        row::set("person_gender", None);
+        assert_eq!(source.gender(), None);
+    }
+
+    #[test]
+    fn given_gender_column_not_string_should_bind_return_err() {
+        let source = GenderSource::Column("person_gender".to_string());
+        let column = |sample: Value| {
+            HashMap::from([(
+                "person_gender".to_string(),
+                ColumnType {
+                    sample,
+                    nullable: false,
+                },
+            )])
+        };
+        assert!(source.bind(&column(Value::String(String::new()))).is_ok());
+        assert!(source.bind(&column(Value::Int32(1))).is_err());
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_weights_should_return_genders_accordingly() {
+        let provider = generate_provider("name: gender\nweights:\n  female: 1\n  male: 3");
+        let mut counts: HashMap<String, u32> = HashMap::new();
+        for i in 0..4000 {
+            match provider.value(i) {
+                Value::String(value) => *counts.entry(value).or_insert(0) += 1,
+                _ => panic!("Wrong type"),
+            }
+        }
+        assert_eq!(counts.get("other"), None);
+        assert!((800..1200).contains(&counts["female"]));
+        assert!((2800..3200).contains(&counts["male"]));
+    }
+}
@@ -1,3 +1,9 @@
 pub mod builder;
 
+pub mod age;
 pub mod email;
+pub mod gender;
+pub mod name;
+pub mod names;
+pub mod phone;
+pub mod username;
@@ -0,0 +1,279 @@
+use crate::errors::FakeLakeError;
+use crate::providers::constant::external;
+use crate::providers::parameters::get_column_name;
+use crate::providers::parameters::locale::{Locale, LocaleParameter};
+use crate::providers::provider::{ColumnType, Provider, Value};
+use crate::providers::utils::string::{pick, random_alphanumeric};
+
+use super::gender::{Gender, GenderSource};
+use super::names;
+
+use std::collections::HashMap;
+use yaml_rust::Yaml;
+
+#[derive(Clone)]
+pub struct FirstNameProvider {
+    pub locale: Locale,
+    pub gender: GenderSource,
+}
+
+impl Provider for FirstNameProvider {
+    fn value(&self, _: u32) -> Value {
+        let first_names = names::first_names(self.locale, self.gender.gender());
+        Value::String(pick(first_names).to_string())
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        Value::String(random_alphanumeric(10))
+    }
+    fn dependencies(&self) -> Vec<String> {
+        self.gender.dependencies()
+    }
+    fn bind(&mut self, columns: &HashMap<String, ColumnType>) -> Result<(), FakeLakeError> {
+        self.gender.bind(columns)
+    }
+}
+
+pub fn first_name_from_yaml(column: &Yaml) -> Result<Box<dyn Provider>, FakeLakeError> {
+    Ok(Box::new(FirstNameProvider {
+        locale: LocaleParameter::new(column).value,
+        gender: GenderSource::new(column)?,
+    }))
+}
+
+pub fn last_name_from_yaml(column: &Yaml) -> Box<dyn Provider> {
+    let locale = LocaleParameter::new(column).value;
+    external::new(names::last_names(locale).to_vec())
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Part {
+    Text(String),
+    Title,
+    FirstName,
+    MiddleName,
+    LastName,
+}
+
+/// Parts of a pattern like `{title} {first_name} {last_name}`
+fn parse_pattern(pattern: &str) -> Result<Vec<Part>, String> {
+    let mut parts = Vec::new();
+    let mut rest = pattern;
+    while !rest.is_empty() {
+        match rest.find(['{', '}']) {
+            Some(position) if rest[position..].starts_with('{') => {
+                if position > 0 {
+                    parts.push(Part::Text(rest[..position].to_string()));
+                }
+                let end = match rest[position..].find('}') {
+                    Some(end) => position + end,
+                    None => return Err("a { is not closed".to_string()),
+                };
+                parts.push(match &rest[position + 1..end] {
+                    "title" => Part::Title,
+                    "first_name" => Part::FirstName,
+                    "middle_name" => Part::MiddleName,
+                    "last_name" => Part::LastName,
+                    other => return Err(format!("{{{}}} is unknown", other)),
+                });
+                rest = &rest[end + 1..];
+            }
+            Some(_) => return Err("a } is not opened".to_string()),
+            None => {
+                parts.push(Part::Text(rest.to_string()));
+                rest = "";
+            }
+        }
+    }
+    Ok(parts)
+}
+
+#[derive(Clone)]
+pub struct FullNameProvider {
+    pub locale: Locale,
+    pub gender: GenderSource,
+    pub pattern: Vec<Part>,
+}
+
+impl Provider for FullNameProvider {
+    fn value(&self, _: u32) -> Value {
+        // the first name, the middle name and the title share the same gender
+        let gender = match self.gender {
+            GenderSource::Any => Some(Gender::random()),
+            _ => self.gender.gender(),
+        };
+        let first_names = names::first_names(self.locale, gender);
+
+        let full_name = self
+            .pattern
+            .iter()
+            .map(|part| match part {
+                Part::Text(text) => text.as_str(),
+                Part::Title => pick(names::titles(self.locale, gender)),
+                Part::FirstName | Part::MiddleName => pick(first_names),
+                Part::LastName => pick(names::last_names(self.locale)),
+            })
+            .collect();
+        Value::String(full_name)
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        Value::String(random_alphanumeric(10))
+    }
+    fn dependencies(&self) -> Vec<String> {
+        self.gender.dependencies()
+    }
+    fn bind(&mut self, columns: &HashMap<String, ColumnType>) -> Result<(), FakeLakeError> {
+        self.gender.bind(columns)
+    }
+}
+
+pub fn full_name_from_yaml(column: &Yaml) -> Result<Box<dyn Provider>, FakeLakeError> {
+    let locale = LocaleParameter::new(column).value;
+    let default_pattern = match locale {
+        Locale::JaJP | Locale::ZhCN => "{last_name}{first_name}",
+        _ => "{first_name} {last_name}",
+    };
+
+    let pattern = match &column["pattern"] {
+        Yaml::String(value) => value.as_str(),
+        Yaml::BadValue => default_pattern,
+        _ => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} param pattern should be a string.",
+                get_column_name(column)
+            )))
+        }
+    };
+    let pattern = match parse_pattern(pattern) {
+        Ok(value) => value,
+        Err(e) => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} has a wrong pattern: {}",
+                get_column_name(column),
+                e
+            )))
+        }
+    };
+
+    Ok(Box::new(FullNameProvider {
+        locale,
+        gender: GenderSource::new(column)?,
+        pattern,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{first_name_from_yaml, full_name_from_yaml, parse_pattern, Part};
+    use crate::providers::parameters::locale::Locale;
+    use crate::providers::person::gender::Gender;
+    use crate::providers::person::names;
+    use crate::providers::provider::{Provider, Value};
+    use crate::row;
+
+    use yaml_rust::YamlLoader;
+
+    fn generate_first_name(yaml_str: &str) -> Box<dyn Provider> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        first_name_from_yaml(&yaml[0]).unwrap()
+    }
+
+    fn generate_full_name(yaml_str: &str) -> Box<dyn Provider> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        full_name_from_yaml(&yaml[0]).unwrap()
+    }
+
+    fn string_value(provider: &dyn Provider) -> String {
+        match provider.value(0) {
+            Value::String(value) => value,
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_pattern_should_return_parts() {
+        assert_eq!(
+            parse_pattern("{title} {last_name}, {first_name}").unwrap(),
+            vec![
+                Part::Title,
+                Part::Text(" ".to_string()),
+                Part::LastName,
+                Part::Text(", ".to_string()),
+                Part::FirstName
+            ]
+        );
+    }
+
+    #[test]
+    fn given_wrong_pattern_should_return_err() {
+        for pattern in ["{first_name", "first_name}", "{name}", "{}"] {
+            assert!(
+                parse_pattern(pattern).is_err(),
+                "{} should be wrong",
+                pattern
+            );
+        }
+
+        for params in ["pattern: [a]", "pattern: '{age}'", "gender: robot"] {
+            let yaml = YamlLoader::load_from_str(&format!("name: a\n{}", params)).unwrap();
+            assert!(full_name_from_yaml(&yaml[0]).is_err());
+        }
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_gender_should_return_first_name_of_gender() {
+        let provider = generate_first_name("name: a\nlocale: en_US\ngender: female");
+        let female = names::first_names(Locale::EnUS, Some(Gender::Female));
+        for _ in 0..50 {
+            assert!(female.contains(&string_value(provider.as_ref())));
+        }
+    }
+
+    #[test]
+    fn given_gender_column_should_return_first_name_of_row_gender() {
+        let provider = generate_first_name("name: a\nlocale: de_DE\ngender_column: g");
+        assert_eq!(provider.dependencies(), vec!["g"]);
+
+        let male = names::first_names(Locale::DeDE, Some(Gender::Male));
+        row::set("g", Some(Value::String("male".to_string())));
+        for _ in 0..50 {
+            assert!(male.contains(&string_value(provider.as_ref())));
+        }
+    }
+
+    #[test]
+    fn given_default_pattern_should_return_first_and_last_names() {
+        let provider = generate_full_name("name: a\nlocale: en_GB");
+        let value = string_value(provider.as_ref());
+        let parts: Vec<&str> = value.split(' ').collect();
+        assert_eq!(parts.len(), 2);
+        assert!(names::first_names(Locale::EnGB, None).contains(&parts[0].to_string()));
+        assert!(names::last_names(Locale::EnGB).contains(&parts[1].to_string()));
+    }
+
+    #[test]
+    fn given_pattern_should_return_consistent_title_and_names() {
+        let provider = generate_full_name(
+            "name: a\nlocale: en_US\ngender: male\npattern: '{title} {first_name} {middle_name} {last_name}'",
+        );
+        let male = names::first_names(Locale::EnUS, Some(Gender::Male));
+        for _ in 0..50 {
+            let value = string_value(provider.as_ref());
+            let parts: Vec<&str> = value.split(' ').collect();
+            assert!(["Mr.", "Dr."].contains(&parts[0]));
+            assert!(male.contains(&parts[1].to_string()));
+            assert!(male.contains(&parts[2].to_string()));
+        }
+    }
+
+    #[test]
+    fn given_japanese_locale_should_write_last_name_first() {
+        let provider = generate_full_name("name: a\nlocale: ja_JP");
+        let value = string_value(provider.as_ref());
+        assert!(!value.contains(' '));
+        assert!(names::last_names(Locale::JaJP)
+            .iter()
+            .any(|last_name| value.starts_with(last_name.as_str())));
+    }
+}
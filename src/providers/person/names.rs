@@ -0,0 +1,205 @@
+use crate::providers::parameters::locale::{Locale, AVAILABLE_LOCALES};
+use crate::providers::utils::string::{ascii_identifier, lines};
+use crate::rng;
+
+use super::gender::Gender;
+
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+
+pub struct LocaleNames {
+    pub female: Vec<String>,
+    pub male: Vec<String>,
+    /// First names whatever the gender
+    pub first: Vec<String>,
+    pub last: Vec<String>,
+}
+
+/// Female first names, male first names and last names of a locale
+fn files(locale: Locale) -> [&'static str; 3] {
+    match locale {
+        Locale::DeDE => [
+            include_str!("../../../static/first_name_female_de_DE.txt"),
+            include_str!("../../../static/first_name_male_de_DE.txt"),
+            include_str!("../../../static/last_name_de_DE.txt"),
+        ],
+        Locale::EnGB => [
+            include_str!("../../../static/first_name_female_en_GB.txt"),
+            include_str!("../../../static/first_name_male_en_GB.txt"),
+            include_str!("../../../static/last_name_en_GB.txt"),
+        ],
+        Locale::EnUS => [
+            include_str!("../../../static/first_name_female_en_US.txt"),
+            include_str!("../../../static/first_name_male_en_US.txt"),
+            include_str!("../../../static/last_name_en_US.txt"),
+        ],
+        Locale::EsES => [
+            include_str!("../../../static/first_name_female_es_ES.txt"),
+            include_str!("../../../static/first_name_male_es_ES.txt"),
+            include_str!("../../../static/last_name_es_ES.txt"),
+        ],
+        Locale::FrFR => [
+            include_str!("../../../static/first_name_female_fr_FR.txt"),
+            include_str!("../../../static/first_name_male_fr_FR.txt"),
+            include_str!("../../../static/last_name_fr.txt"),
+        ],
+        Locale::ItIT => [
+            include_str!("../../../static/first_name_female_it_IT.txt"),
+            include_str!("../../../static/first_name_male_it_IT.txt"),
+            include_str!("../../../static/last_name_it_IT.txt"),
+        ],
+        Locale::JaJP => [
+            include_str!("../../../static/first_name_female_ja_JP.txt"),
+            include_str!("../../../static/first_name_male_ja_JP.txt"),
+            include_str!("../../../static/last_name_ja_JP.txt"),
+        ],
+        Locale::PtBR => [
+            include_str!("../../../static/first_name_female_pt_BR.txt"),
+            include_str!("../../../static/first_name_male_pt_BR.txt"),
+            include_str!("../../../static/last_name_pt_BR.txt"),
+        ],
+        Locale::ZhCN => [
+            include_str!("../../../static/first_name_female_zh_CN.txt"),
+            include_str!("../../../static/first_name_male_zh_CN.txt"),
+            include_str!("../../../static/last_name_zh_CN.txt"),
+        ],
+    }
+}
+
+fn load(locale: Locale) -> LocaleNames {
+    let [female, male, last] = files(locale).map(lines);
+    let first = match locale {
+        // the french list is larger than the gendered ones
+        Locale::FrFR => lines(include_str!("../../../static/first_name_fr.txt")),
+        _ => [female.as_slice(), male.as_slice()].concat(),
+    };
+
+    LocaleNames {
+        female,
+        male,
+        first,
+        last,
+    }
+}
+
+static NAMES: Lazy<HashMap<Locale, LocaleNames>> = Lazy::new(|| {
+    AVAILABLE_LOCALES
+        .iter()
+        .filter_map(|code| Locale::from_code(code))
+        .map(|locale| (locale, load(locale)))
+        .collect()
+});
+
+/// First names of a locale for a gender, of any gender if None or Other
+pub fn first_names(locale: Locale, gender: Option<Gender>) -> &'static [String] {
+    let names = &NAMES[&locale];
+    match gender {
+        Some(Gender::Female) => &names.female,
+        Some(Gender::Male) => &names.male,
+        _ => &names.first,
+    }
+}
+
+pub fn last_names(locale: Locale) -> &'static [String] {
+    &NAMES[&locale].last
+}
+
+fn random_ascii_name(names: &[String]) -> String {
+    loop {
+        let name = ascii_identifier(&names[rng::usize(..names.len())]);
+        if !name.is_empty() {
+            return name;
+        }
+    }
+}
+
+/// Random first and last names of a locale in lowercase ascii, like in usernames or emails.
+/// Names written in another script than the latin one give american names.
+pub fn random_ascii_names(locale: Locale) -> (String, String) {
+    let locale = match locale {
+        Locale::JaJP | Locale::ZhCN => Locale::EnUS,
+        locale => locale,
+    };
+    (
+        random_ascii_name(first_names(locale, None)),
+        random_ascii_name(last_names(locale)),
+    )
+}
+
+/// Titles written before or after a name
+pub fn titles(locale: Locale, gender: Option<Gender>) -> &'static [&'static str] {
+    match (locale, gender) {
+        (Locale::DeDE, Some(Gender::Female)) => &["Frau", "Dr."],
+        (Locale::DeDE, Some(Gender::Male)) => &["Herr", "Dr."],
+        (Locale::DeDE, _) => &["Dr.", "Prof."],
+        (Locale::EnGB | Locale::EnUS, Some(Gender::Female)) => &["Mrs.", "Ms.", "Miss", "Dr."],
+        (Locale::EnGB | Locale::EnUS, Some(Gender::Male)) => &["Mr.", "Dr."],
+        (Locale::EnGB | Locale::EnUS, _) => &["Mx.", "Dr."],
+        (Locale::EsES, Some(Gender::Female)) => &["Sra.", "Srta.", "Dra."],
+        (Locale::EsES, Some(Gender::Male)) => &["Sr.", "Dr."],
+        (Locale::EsES, _) => &["Dr."],
+        (Locale::FrFR, Some(Gender::Female)) => &["Mme", "Mlle", "Dr"],
+        (Locale::FrFR, Some(Gender::Male)) => &["M.", "Dr"],
+        (Locale::FrFR, _) => &["Dr"],
+        (Locale::ItIT, Some(Gender::Female)) => &["Sig.ra", "Dott.ssa"],
+        (Locale::ItIT, Some(Gender::Male)) => &["Sig.", "Dott."],
+        (Locale::ItIT, _) => &["Dott."],
+        (Locale::JaJP, _) => &["様", "さん"],
+        (Locale::PtBR, Some(Gender::Female)) => &["Sra.", "Dra."],
+        (Locale::PtBR, Some(Gender::Male)) => &["Sr.", "Dr."],
+        (Locale::PtBR, _) => &["Dr."],
+        (Locale::ZhCN, Some(Gender::Female)) => &["女士", "小姐"],
+        (Locale::ZhCN, Some(Gender::Male)) => &["先生"],
+        (Locale::ZhCN, _) => &["老师"],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{first_names, last_names, random_ascii_names, titles};
+    use crate::providers::parameters::locale::{Locale, AVAILABLE_LOCALES};
+    use crate::providers::person::gender::Gender;
+
+    #[test]
+    fn given_name_files_should_be_loaded() {
+        assert_eq!(13387, first_names(Locale::FrFR, None).len());
+        assert_eq!(95590, last_names(Locale::FrFR).len());
+    }
+
+    #[test]
+    fn given_each_locale_should_have_names() {
+        for code in AVAILABLE_LOCALES {
+            let locale = Locale::from_code(code).unwrap();
+            for gender in [None, Some(Gender::Female), Some(Gender::Male)] {
+                let names = first_names(locale, gender);
+                assert!(names.len() >= 70, "{} misses first names", code);
+                assert!(names.iter().all(|name| !name.is_empty()));
+                assert!(!titles(locale, gender).is_empty());
+            }
+            assert!(last_names(locale).len() >= 90, "{} misses last names", code);
+            assert!(last_names(locale).iter().all(|name| !name.is_empty()));
+        }
+    }
+
+    #[test]
+    fn given_gender_should_return_names_of_this_gender() {
+        let female = first_names(Locale::EnUS, Some(Gender::Female));
+        let male = first_names(Locale::EnUS, Some(Gender::Male));
+        assert!(female.contains(&"Emma".to_string()));
+        assert!(!male.contains(&"Emma".to_string()));
+        assert!(male.contains(&"James".to_string()));
+        assert_eq!(
+            first_names(Locale::EnUS, Some(Gender::Other)).len(),
+            female.len() + male.len()
+        );
+    }
+
+    #[test]
+    fn given_non_latin_locale_should_return_ascii_names() {
+        for locale in [Locale::JaJP, Locale::ZhCN, Locale::DeDE] {
+            let (first_name, last_name) = random_ascii_names(locale);
+            assert!(!first_name.is_empty() && first_name.is_ascii());
+            assert!(!last_name.is_empty() && last_name.is_ascii());
+        }
+    }
+}
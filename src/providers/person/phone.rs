@@ -0,0 +1,351 @@
+use crate::errors::FakeLakeError;
+use crate::providers::parameters::get_column_name;
+use crate::providers::parameters::locale::LocaleParameter;
+use crate::providers::provider::{Provider, Value};
+use crate::rng;
+
+use yaml_rust::Yaml;
+
+const AVAILABLE_COUNTRIES: &[&str] = &["BR", "CN", "DE", "ES", "FR", "GB", "IT", "JP", "US"];
+const AVAILABLE_FORMATS: &[&str] = &["e164", "international", "national"];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    E164,
+    International,
+    National,
+}
+
+/// Numbering plan of a country: its calling code and the numbers it allows, written
+/// in the national and the international formats. In the numbers, # is any digit,
+/// N a digit from 2 to 9 and M a digit from 1 to 9.
+#[derive(Debug, PartialEq)]
+pub struct Plan {
+    pub calling_code: &'static str,
+    pub numbers: &'static [(&'static str, &'static str)],
+}
+
+pub fn get_plan(country: &str) -> Option<Plan> {
+    let (calling_code, numbers): (&str, &[(&str, &str)]) = match country {
+        "BR" => (
+            "55",
+            &[
+                ("(MM) 9####-####", "MM 9####-####"),
+                ("(MM) 3###-####", "MM 3###-####"),
+            ],
+        ),
+        "CN" => (
+            "86",
+            &[
+                ("13# #### ####", "13# #### ####"),
+                ("15# #### ####", "15# #### ####"),
+                ("18# #### ####", "18# #### ####"),
+                ("010 #### ####", "10 #### ####"),
+                ("021 #### ####", "21 #### ####"),
+            ],
+        ),
+        "DE" => (
+            "49",
+            &[
+                ("015# ########", "15# ########"),
+                ("017# #######", "17# #######"),
+                ("030 ########", "30 ########"),
+                ("089 ########", "89 ########"),
+            ],
+        ),
+        "ES" => (
+            "34",
+            &[
+                ("6## ## ## ##", "6## ## ## ##"),
+                ("9M# ## ## ##", "9M# ## ## ##"),
+            ],
+        ),
+        "FR" => ("33", &[("0M ## ## ## ##", "M ## ## ## ##")]),
+        "GB" => (
+            "44",
+            &[
+                ("07M## ######", "7M## ######"),
+                ("020 #### ####", "20 #### ####"),
+                ("01M## ######", "1M## ######"),
+            ],
+        ),
+        "IT" => (
+            "39",
+            &[
+                ("3## ### ####", "3## ### ####"),
+                ("06 #### ####", "06 #### ####"),
+                ("02 #### ####", "02 #### ####"),
+            ],
+        ),
+        "JP" => (
+            "81",
+            &[
+                ("090-####-####", "90-####-####"),
+                ("080-####-####", "80-####-####"),
+                ("03-####-####", "3-####-####"),
+                ("06-####-####", "6-####-####"),
+            ],
+        ),
+        "US" => ("1", &[("(N##) N##-####", "N##-N##-####")]),
+        _ => return None,
+    };
+    Some(Plan {
+        calling_code,
+        numbers,
+    })
+}
+
+/// Replace the placeholders of a number by the digits, in order
+fn fill(number: &str, digits: &[char]) -> String {
+    let mut digits = digits.iter();
+    number
+        .chars()
+        .map(|c| match c {
+            '#' | 'N' | 'M' => *digits.next().unwrap(),
+            c => c,
+        })
+        .collect()
+}
+
+#[derive(Clone)]
+pub struct PhoneProvider {
+    pub country: String,
+    pub format: Format,
+}
+
+impl PhoneProvider {
+    fn plan(&self) -> Plan {
+        get_plan(&self.country).unwrap()
+    }
+}
+
+impl Provider for PhoneProvider {
+    fn value(&self, _: u32) -> Value {
+        let plan = self.plan();
+        let (national, international) = plan.numbers[rng::usize(..plan.numbers.len())];
+        let digits: Vec<char> = national
+            .chars()
+            .filter_map(|c| match c {
+                '#' => Some(rng::u32(0..10)),
+                'N' => Some(rng::u32(2..10)),
+                'M' => Some(rng::u32(1..10)),
+                _ => None,
+            })
+            .map(|digit| char::from_digit(digit, 10).unwrap())
+            .collect();
+
+        let international = fill(international, &digits);
+        let value = match self.format {
+            Format::E164 => format!(
+                "+{}{}",
+                plan.calling_code,
+                international
+                    .chars()
+                    .filter(char::is_ascii_digit)
+                    .collect::<String>()
+            ),
+            Format::International => format!("+{} {}", plan.calling_code, international),
+            Format::National => fill(national, &digits),
+        };
+        Value::String(value)
+    }
+    fn corrupted_value(&self, index: u32) -> Value {
+        // numbers with too few digits, too many digits or a letter
+        let mut value = match self.value(index) {
+            Value::String(value) => value,
+            _ => panic!("Phone numbers should be strings"),
+        };
+        match rng::u32(0..3) {
+            0 => {
+                for _ in 0..rng::u32(2..4) {
+                    if let Some(position) = value.rfind(|c: char| c.is_ascii_digit()) {
+                        value.remove(position);
+                    }
+                }
+            }
+            1 => {
+                for _ in 0..rng::u32(2..4) {
+                    value.push(char::from_digit(rng::u32(0..10), 10).unwrap());
+                }
+            }
+            _ => {
+                let positions: Vec<usize> = value
+                    .char_indices()
+                    .filter(|(_, c)| c.is_ascii_digit())
+                    .map(|(position, _)| position)
+                    .collect();
+                let position = positions[rng::usize(..positions.len())];
+                let letter = char::from(b'A' + rng::u32(0..26) as u8);
+                value.replace_range(position..position + 1, &letter.to_string());
+            }
+        }
+        Value::String(value)
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml) -> Result<Box<PhoneProvider>, FakeLakeError> {
+    let column_name = get_column_name(column);
+
+    let country = match &column["country"] {
+        Yaml::BadValue => LocaleParameter::new(column)
+            .value
+            .country_code()
+            .to_string(),
+        Yaml::String(value) if get_plan(&value.to_uppercase()).is_some() => value.to_uppercase(),
+        _ => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} param country should be one of {}.",
+                column_name,
+                AVAILABLE_COUNTRIES.join(", ")
+            )))
+        }
+    };
+
+    let format = match &column["format"] {
+        Yaml::BadValue => Some(Format::International),
+        Yaml::String(value) => match value.to_lowercase().replace(['.', '_'], "").as_str() {
+            "e164" => Some(Format::E164),
+            "international" => Some(Format::International),
+            "national" => Some(Format::National),
+            _ => None,
+        },
+        _ => None,
+    };
+    let format = match format {
+        Some(value) => value,
+        None => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} param format should be one of {}.",
+                column_name,
+                AVAILABLE_FORMATS.join(", ")
+            )))
+        }
+    };
+
+    Ok(Box::new(PhoneProvider { country, format }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{get_plan, new_from_yaml, Format, PhoneProvider, AVAILABLE_COUNTRIES};
+    use crate::providers::provider::{Provider, Value};
+
+    use regex::Regex;
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider(yaml_str: &str) -> Box<PhoneProvider> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        new_from_yaml(&yaml[0]).unwrap()
+    }
+
+    fn values(provider: &dyn Provider, corrupted: bool) -> Vec<String> {
+        (0..100)
+            .map(|i| {
+                let value = match corrupted {
+                    true => provider.corrupted_value(i),
+                    false => provider.value(i),
+                };
+                match value {
+                    Value::String(value) => value,
+                    _ => panic!("Wrong type"),
+                }
+            })
+            .collect()
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_no_params_should_use_locale_country_and_international_format() {
+        let provider = generate_provider("name: phone");
+        assert_eq!(provider.country, "FR");
+        assert_eq!(provider.format, Format::International);
+
+        let provider = generate_provider("name: phone\nlocale: ja_JP\nformat: E.164");
+        assert_eq!(provider.country, "JP");
+        assert_eq!(provider.format, Format::E164);
+    }
+
+    #[test]
+    fn given_wrong_params_should_return_err() {
+        for params in [
+            "country: XX",
+            "country: 33",
+            "format: local",
+            "format: [e164]",
+        ] {
+            let yaml = YamlLoader::load_from_str(&format!("name: phone\n{}", params)).unwrap();
+            assert!(
+                new_from_yaml(&yaml[0]).is_err(),
+                "{} should be wrong",
+                params
+            );
+        }
+    }
+
+    #[test]
+    fn given_plans_should_numbers_have_same_digits_in_both_formats() {
+        for country in AVAILABLE_COUNTRIES {
+            let plan = get_plan(country).unwrap();
+            for (national, international) in plan.numbers {
+                let placeholders = |number: &str| -> String {
+                    number.chars().filter(|c| "#NM".contains(*c)).collect()
+                };
+                assert_eq!(placeholders(national), placeholders(international));
+            }
+        }
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_country_and_format_should_return_numbers_of_plan() {
+        for (params, pattern) in [
+            ("country: FR\nformat: national", r"^0[1-9]( \d{2}){4}$"),
+            ("country: FR\nformat: e164", r"^\+33[1-9]\d{8}$"),
+            (
+                "country: US\nformat: national",
+                r"^\([2-9]\d{2}\) [2-9]\d{2}-\d{4}$",
+            ),
+            (
+                "country: us\nformat: international",
+                r"^\+1 [2-9]\d{2}-[2-9]\d{2}-\d{4}$",
+            ),
+            ("country: GB\nformat: e164", r"^\+44[127]\d{9}$"),
+            (
+                "country: JP\nformat: international",
+                r"^\+81 [3689]0?-\d{4}-\d{4}$",
+            ),
+            (
+                "country: BR\nformat: national",
+                r"^\([1-9]{2}\) (9\d{4}|3\d{3})-\d{4}$",
+            ),
+            (
+                "country: CN\nformat: e164",
+                r"^\+86(1[358]\d{9}|(10|21)\d{8})$",
+            ),
+            (
+                "country: DE\nformat: e164",
+                r"^\+49(1[57]\d{8,9}|(30|89)\d{8})$",
+            ),
+            (
+                "country: IT\nformat: international",
+                r"^\+39 (3\d{2} \d{3}|0[26] \d{4}) \d{4}$",
+            ),
+            ("country: ES\nformat: e164", r"^\+34[69]\d{8}$"),
+        ] {
+            let re = Regex::new(pattern).unwrap();
+            let provider = generate_provider(&format!("name: phone\n{}", params));
+            for value in values(provider.as_ref(), false) {
+                assert!(re.is_match(&value), "{} does not match {}", value, pattern);
+            }
+        }
+    }
+
+    #[test]
+    fn given_corrupted_should_return_invalid_numbers() {
+        let provider = generate_provider("name: phone\ncountry: FR\nformat: e164");
+        let re = Regex::new(r"^\+33[1-9]\d{8}$").unwrap();
+        for value in values(provider.as_ref(), true) {
+            assert!(!re.is_match(&value), "{} should be invalid", value);
+        }
+    }
+}
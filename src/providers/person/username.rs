@@ -0,0 +1,81 @@
+use crate::providers::parameters::locale::{Locale, LocaleParameter};
+use crate::providers::provider::{Provider, Value};
+use crate::providers::utils::string::random_characters;
+use crate::rng;
+
+use super::names;
+
+use yaml_rust::Yaml;
+
+#[derive(Clone)]
+pub struct UsernameProvider {
+    pub locale: Locale,
+}
+
+impl Provider for UsernameProvider {
+    fn value(&self, _: u32) -> Value {
+        let (first_name, last_name) = names::random_ascii_names(self.locale);
+        let initial = &first_name[..1];
+
+        let username = match rng::u32(0..7) {
+            0 => format!("{}.{}", first_name, last_name),
+            1 => format!("{}_{}", first_name, last_name),
+            2 => format!("{}{}", first_name, last_name),
+            3 => format!("{}{}", initial, last_name),
+            4 => format!("{}{}", first_name, rng::u32(1..100)),
+            5 => format!("{}.{}{}", first_name, last_name, rng::u32(1950..2010)),
+            _ => format!("{}{}{}", initial, last_name, rng::u32(1..1000)),
+        };
+        Value::String(username)
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        // usernames with spaces and characters that are not allowed
+        Value::String(format!("{} {}", random_characters(4), random_characters(4)))
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml) -> Box<UsernameProvider> {
+    Box::new(UsernameProvider {
+        locale: LocaleParameter::new(column).value,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::new_from_yaml;
+    use crate::providers::provider::{Provider, Value};
+
+    use regex::Regex;
+    use yaml_rust::YamlLoader;
+
+    fn generate_values(yaml_str: &str) -> Vec<String> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        let provider = new_from_yaml(&yaml[0]);
+        (0..200)
+            .map(|i| match provider.value(i) {
+                Value::String(value) => value,
+                _ => panic!("Wrong type"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn given_locale_should_return_ascii_usernames() {
+        let re = Regex::new(r"^[a-z][a-z0-9._]*$").unwrap();
+        for locale in ["fr_FR", "de_DE", "es_ES", "ja_JP", "zh_CN"] {
+            for value in generate_values(&format!("name: a\nlocale: {}", locale)) {
+                assert!(re.is_match(&value), "{} is not a username", value);
+            }
+        }
+    }
+
+    #[test]
+    fn given_provider_should_corrupted_return_not_usernames() {
+        let yaml = YamlLoader::load_from_str("name: a").unwrap();
+        let provider = new_from_yaml(&yaml[0]);
+        match provider.corrupted_value(0) {
+            Value::String(value) => assert!(value.contains(' ')),
+            _ => panic!("Wrong type"),
+        }
+    }
+}
@@ -1,9 +1,12 @@
 use crate::errors::FakeLakeError;
 use crate::providers;
+use crate::providers::parameters::duration;
 use crate::providers::parameters::percentage::PercentageParameter;
+use crate::providers::parameters::timestamp::TimestampType;
 
-use chrono::{DateTime, NaiveDate, Utc};
+use chrono::{DateTime, NaiveDate, NaiveTime, TimeDelta, Utc};
 use core::fmt;
+use std::collections::HashMap;
 use yaml_rust::Yaml;
 
 #[derive(Clone, PartialEq, fmt::Debug)]
@@ -13,7 +16,28 @@ pub enum Value {
     Float64(f64),
     String(String),
     Date(NaiveDate, String),
-    Timestamp(DateTime<Utc>, String),
+    /// UTC timestamp, its format and its Parquet unit and timezone
+    Timestamp(DateTime<Utc>, String, TimestampType),
+    /// Time of day and its format
+    Time(NaiveTime, String),
+    /// Duration, written as seconds or in ISO 8601 in the formats other than Parquet
+    Duration(TimeDelta, duration::Format),
+    /// UUID bytes, written as a UUID in Parquet and hyphenated in the other formats
+    Uuid([u8; 16]),
+    /// Latitude and longitude, written as WKB in Parquet, GeoJSON in JSON and WKT in CSV
+    Point(f64, f64),
+    /// Raw bytes and whether they are written as LargeBinary instead of Binary in Parquet,
+    /// base64 in the other formats
+    Bytes(Vec<u8>, bool),
+    /// No value for this row, written as a missing value
+    Null,
+}
+
+/// What a provider knows about another column of the same row
+#[derive(Clone, Debug)]
+pub struct ColumnType {
+    pub sample: Value,
+    pub nullable: bool,
 }
 
 pub trait CloneProvider {
@@ -32,6 +56,30 @@ where
 pub trait Provider: CloneProvider + Send + Sync {
     fn value(&self, index: u32) -> Value;
     fn corrupted_value(&self, index: u32) -> Value;
+    /// Value used to infer the type of the column. It is not part of the generated data.
+    fn sample_value(&self) -> Value {
+        crate::rng::isolated(|| self.value(0))
+    }
+    /// Columns of the same row read by the provider. They are generated before it.
+    fn dependencies(&self) -> Vec<String> {
+        Vec::new()
+    }
+    /// Called once the columns it depends on are built, to check that the provider can read them.
+    fn bind(&mut self, _columns: &HashMap<String, ColumnType>) -> Result<(), FakeLakeError> {
+        Ok(())
+    }
+    /// Whether the provider can return Value::Null
+    fn can_be_null(&self) -> bool {
+        false
+    }
+    /// Number of rows the values are made for, used as the rows of the table when it has none
+    fn expected_rows(&self) -> Option<u32> {
+        None
+    }
+    /// Number of rows the provider can give values for, None if there is no limit
+    fn max_rows(&self) -> Option<u32> {
+        None
+    }
 }
 
 pub struct CorruptedProvider {
@@ -59,6 +107,24 @@ impl Provider for CorruptedProvider {
     fn corrupted_value(&self, index: u32) -> Value {
         self.provider.corrupted_value(index)
     }
+    fn sample_value(&self) -> Value {
+        self.provider.sample_value()
+    }
+    fn dependencies(&self) -> Vec<String> {
+        self.provider.dependencies()
+    }
+    fn bind(&mut self, columns: &HashMap<String, ColumnType>) -> Result<(), FakeLakeError> {
+        self.provider.bind(columns)
+    }
+    fn can_be_null(&self) -> bool {
+        self.provider.can_be_null()
+    }
+    fn expected_rows(&self) -> Option<u32> {
+        self.provider.expected_rows()
+    }
+    fn max_rows(&self) -> Option<u32> {
+        self.provider.max_rows()
+    }
 }
 
 impl CorruptedProvider {
@@ -94,22 +160,83 @@ impl ProviderBuilder {
         let mut provider_split = lowercased.split('.');
 
         match provider_split.next() {
+            Some("address") => {
+                providers::address::builder::get_corresponding_provider(provider_split, column)
+            }
+            Some("commerce") => {
+                providers::commerce::builder::get_corresponding_provider(provider_split, column)
+            }
+            Some("company") => {
+                providers::company::builder::get_corresponding_provider(provider_split, column)
+            }
+            Some("conditional") => {
+                providers::conditional::builder::get_corresponding_provider(provider_split, column)
+            }
             Some("constant") => {
                 providers::constant::builder::get_corresponding_provider(provider_split, column)
             }
+            Some("expression") => {
+                providers::expression::builder::get_corresponding_provider(provider_split, column)
+            }
+            Some("finance") => {
+                providers::finance::builder::get_corresponding_provider(provider_split, column)
+            }
+            Some("geo") => {
+                providers::geo::builder::get_corresponding_provider(provider_split, column)
+            }
+            Some("hash") => {
+                providers::hash::builder::get_corresponding_provider(provider_split, column)
+            }
+            Some("id") => {
+                providers::id::builder::get_corresponding_provider(provider_split, column)
+            }
             Some("increment") => {
                 providers::increment::builder::get_corresponding_provider(provider_split, column)
             }
+            Some("internet") => {
+                providers::internet::builder::get_corresponding_provider(provider_split, column)
+            }
             Some("person") => {
                 providers::person::builder::get_corresponding_provider(provider_split, column)
             }
             Some("random") => {
                 providers::random::builder::get_corresponding_provider(provider_split, column)
             }
+            Some("reference") => {
+                providers::reference::builder::get_corresponding_provider(provider_split, column)
+            }
+            Some("template") => {
+                providers::template::builder::get_corresponding_provider(provider_split, column)
+            }
+            Some("text") => {
+                providers::text::builder::get_corresponding_provider(provider_split, column)
+            }
+            Some("timeseries") => {
+                providers::timeseries::builder::get_corresponding_provider(provider_split, column)
+            }
             other => Err(unknown_provider(
                 "",
                 other,
-                &["constant.*", "increment.*", "person.*", "random.*"],
+                &[
+                    "address.*",
+                    "commerce.*",
+                    "company.*",
+                    "conditional",
+                    "constant.*",
+                    "expression",
+                    "finance.*",
+                    "geo.*",
+                    "hash.*",
+                    "id.*",
+                    "increment.*",
+                    "internet.*",
+                    "person.*",
+                    "random.*",
+                    "reference.*",
+                    "template",
+                    "text.*",
+                    "timeseries.*",
+                ],
             )),
         }
     }
@@ -157,6 +284,90 @@ mod tests {
         }
     }
 
+    #[test]
+    fn given_address_should_return_provider() {
+        let provider_name = "address.city";
+        let yaml_str = format!("name: test_col{}provider: {}", '\n', provider_name);
+        let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+        match ProviderBuilder::get_corresponding_provider(provider_name, column) {
+            Ok(_) => (),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn given_commerce_should_return_provider() {
+        let provider_name = "commerce.price";
+        let yaml_str = format!("name: test_col{}provider: {}", '\n', provider_name);
+        let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+        match ProviderBuilder::get_corresponding_provider(provider_name, column) {
+            Ok(_) => (),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn given_company_should_return_provider() {
+        let provider_name = "company.name";
+        let yaml_str = format!("name: test_col{}provider: {}", '\n', provider_name);
+        let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+        match ProviderBuilder::get_corresponding_provider(provider_name, column) {
+            Ok(_) => (),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn given_finance_should_return_provider() {
+        let provider_name = "finance.iban";
+        let yaml_str = format!("name: test_col{}provider: {}", '\n', provider_name);
+        let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+        match ProviderBuilder::get_corresponding_provider(provider_name, column) {
+            Ok(_) => (),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn given_geo_should_return_provider() {
+        let provider_name = "geo.point";
+        let yaml_str = format!("name: test_col{}provider: {}", '\n', provider_name);
+        let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+        match ProviderBuilder::get_corresponding_provider(provider_name, column) {
+            Ok(_) => (),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn given_hash_should_return_provider() {
+        let provider_name = "hash.sha256";
+        let yaml_str = format!("name: test_col{}provider: {}", '\n', provider_name);
+        let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+        match ProviderBuilder::get_corresponding_provider(provider_name, column) {
+            Ok(_) => (),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn given_id_should_return_provider() {
+        let provider_name = "id.national";
+        let yaml_str = format!("name: test_col{}provider: {}", '\n', provider_name);
+        let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+        match ProviderBuilder::get_corresponding_provider(provider_name, column) {
+            Ok(_) => (),
+            _ => panic!(),
+        }
+    }
+
     #[test]
     fn given_increment_should_return_provider() {
         let provider_name = "increment.integer";
@@ -169,6 +380,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn given_internet_should_return_provider() {
+        let provider_name = "internet.ipv4";
+        let yaml_str = format!("name: test_col{}provider: {}", '\n', provider_name);
+        let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+        match ProviderBuilder::get_corresponding_provider(provider_name, column) {
+            Ok(_) => (),
+            _ => panic!(),
+        }
+    }
+
     #[test]
     fn given_person_should_return_provider() {
         let provider_name = "person.email";
@@ -193,6 +416,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn given_template_should_return_provider() {
+        let provider_name = "template";
+        let yaml_str = format!(
+            "name: test_col{}provider: {}{}template: '{{person.fname}}'",
+            '\n', provider_name, '\n'
+        );
+        let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+        match ProviderBuilder::get_corresponding_provider(provider_name, column) {
+            Ok(_) => (),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn given_text_should_return_provider() {
+        let provider_name = "text.sentence";
+        let yaml_str = format!("name: test_col{}provider: {}", '\n', provider_name);
+        let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+        match ProviderBuilder::get_corresponding_provider(provider_name, column) {
+            Ok(_) => (),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn given_timeseries_should_return_provider() {
+        let provider_name = "timeseries.value";
+        let yaml_str = format!("name: test_col{}provider: {}", '\n', provider_name);
+        let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+        match ProviderBuilder::get_corresponding_provider(provider_name, column) {
+            Ok(_) => (),
+            _ => panic!(),
+        }
+    }
+
     #[test]
     fn given_valid_provider_should_return_provider() {
         let provider_names = [
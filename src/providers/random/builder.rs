@@ -1,15 +1,18 @@
 use crate::errors::FakeLakeError;
 use crate::providers::provider::{unknown_provider, Provider};
 
-use super::{bool, date, number, string};
+use super::{bool, bytes, date, duration, number, string, uuid};
 
 use yaml_rust::Yaml;
 
 const AVAILABLE: &[&str] = &[
     "random.bool",
+    "random.bytes",
     "random.date.*",
+    "random.duration",
     "random.number.*",
     "random.string.*",
+    "random.uuid",
 ];
 
 pub fn get_corresponding_provider(
@@ -18,9 +21,12 @@ pub fn get_corresponding_provider(
 ) -> Result<Box<dyn Provider>, FakeLakeError> {
     match provider_split.next() {
         Some("bool") => Ok(bool::new_from_yaml(column)),
+        Some("bytes") => Ok(bytes::new_from_yaml(column)?),
         Some("date") => date::builder::get_corresponding_provider(provider_split, column),
+        Some("duration") => Ok(duration::new_from_yaml(column)?),
         Some("number") => number::builder::get_corresponding_provider(provider_split, column),
         Some("string") => string::builder::get_corresponding_provider(provider_split, column),
+        Some("uuid") => Ok(uuid::new_from_yaml(column)?),
         other => Err(unknown_provider("random", other, AVAILABLE)),
     }
 }
@@ -44,6 +50,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn given_bytes_should_return_provider() {
+        let provider_name = "bytes";
+        let yaml_str = format!("name: payload{}provider: {}", '\n', provider_name);
+        let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+        let provider_split = provider_name.split('.');
+        match get_corresponding_provider(provider_split, column) {
+            Ok(_) => (),
+            _ => panic!(),
+        }
+    }
+
     #[test]
     fn given_date_date_should_return_provider() {
         let provider_name = "date.date";
@@ -57,6 +76,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn given_duration_should_return_provider() {
+        let provider_name = "duration";
+        let yaml_str = format!("name: sla{}provider: {}", '\n', provider_name);
+        let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+        let provider_split = provider_name.split('.');
+        match get_corresponding_provider(provider_split, column) {
+            Ok(_) => (),
+            _ => panic!(),
+        }
+    }
+
     #[test]
     fn given_string_alphanumeric_should_return_provider() {
         let provider_name = "string.alphanumeric";
@@ -70,6 +102,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn given_uuid_should_return_provider() {
+        let provider_name = "uuid";
+        let yaml_str = format!("name: id{}provider: {}", '\n', provider_name);
+        let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+        let provider_split = provider_name.split('.');
+        match get_corresponding_provider(provider_split, column) {
+            Ok(_) => (),
+            _ => panic!(),
+        }
+    }
+
     #[test]
     fn given_wrong_provider_should_return_error() {
         let provider_name = "not_a_provider";
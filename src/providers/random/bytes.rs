@@ -0,0 +1,180 @@
+use crate::errors::FakeLakeError;
+use crate::providers::parameters::encoding::Encoding;
+use crate::providers::parameters::get_column_name;
+use crate::providers::parameters::urange::URangeParameter;
+use crate::providers::provider::{Provider, Value};
+
+use yaml_rust::Yaml;
+
+const DEFAULT_LENGTH: u32 = 16;
+
+#[derive(Clone)]
+pub struct BytesProvider {
+    /// Min number of bytes, included
+    pub min_length: u32,
+    /// Max number of bytes, excluded
+    pub max_length: u32,
+    pub encoding: Encoding,
+    /// Whether raw bytes are written as LargeBinary instead of Binary in Parquet
+    pub large: bool,
+}
+
+impl BytesProvider {
+    fn bytes(&self, length: u32) -> Value {
+        self.encoding
+            .value(crate::rng::bytes(length as usize), self.large)
+    }
+}
+
+impl Provider for BytesProvider {
+    fn value(&self, _: u32) -> Value {
+        self.bytes(crate::rng::u32(self.min_length..self.max_length))
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        // bytes longer than the range
+        let max_length = self.max_length.max(1);
+        self.bytes(max_length + crate::rng::u32(0..max_length))
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml) -> Result<Box<BytesProvider>, FakeLakeError> {
+    let u_range_parameter = URangeParameter::new(column, "length", DEFAULT_LENGTH);
+
+    let large = match &column["large"] {
+        Yaml::BadValue => false,
+        Yaml::Boolean(value) => *value,
+        _ => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} param large should be a boolean.",
+                get_column_name(column)
+            )))
+        }
+    };
+
+    Ok(Box::new(BytesProvider {
+        min_length: u_range_parameter.min,
+        max_length: u_range_parameter.max,
+        encoding: Encoding::from_yaml(column, Encoding::Raw)?,
+        large,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_from_yaml, BytesProvider};
+    use crate::providers::parameters::encoding::Encoding;
+    use crate::providers::provider::{Provider, Value};
+
+    use regex::Regex;
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider(yaml_str: &str) -> Box<BytesProvider> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        new_from_yaml(&yaml[0]).unwrap()
+    }
+
+    // Parquet type
+    #[test]
+    fn given_nothing_should_return_bytes_type() {
+        let provider = generate_provider("name: payload");
+        assert!(matches!(provider.value(0), Value::Bytes(_, false)));
+
+        let provider = generate_provider("name: payload\nlarge: true");
+        assert!(matches!(provider.value(0), Value::Bytes(_, true)));
+    }
+
+    #[test]
+    fn given_text_encoding_should_return_string_type() {
+        for encoding in ["hex", "base64"] {
+            let provider = generate_provider(&format!("name: payload\nencoding: {}", encoding));
+            assert!(
+                matches!(provider.value(0), Value::String(_)),
+                "{}",
+                encoding
+            );
+        }
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_no_params_should_use_defaults() {
+        let provider = generate_provider("name: payload");
+        assert_eq!((provider.min_length, provider.max_length), (16, 17));
+        assert_eq!(provider.encoding, Encoding::Raw);
+        assert!(!provider.large);
+    }
+
+    #[test]
+    fn given_params_should_use_them() {
+        let provider =
+            generate_provider("name: payload\nlength: 4..64\nencoding: base64\nlarge: true");
+        assert_eq!((provider.min_length, provider.max_length), (4, 64));
+        assert_eq!(provider.encoding, Encoding::Base64);
+        assert!(provider.large);
+    }
+
+    #[test]
+    fn given_wrong_params_should_return_err() {
+        for params in [
+            "encoding: utf8",
+            "encoding: 1",
+            "large: 1",
+            "large: yes please",
+        ] {
+            let yaml = YamlLoader::load_from_str(&format!("name: payload\n{}", params)).unwrap();
+            assert!(new_from_yaml(&yaml[0]).is_err(), "{}", params);
+        }
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_length_range_should_return_bytes_within() {
+        let provider = generate_provider("name: payload\nlength: 4..8");
+        for i in 0..1000 {
+            match provider.value(i) {
+                Value::Bytes(value, _) => assert!((4..8).contains(&value.len())),
+                _ => panic!("Wrong type"),
+            }
+        }
+    }
+
+    #[test]
+    fn given_encoding_should_encode_bytes() {
+        let provider = generate_provider("name: payload\nlength: 8\nencoding: hex");
+        let re = Regex::new(r"^[0-9a-f]{16}$").unwrap();
+        for i in 0..100 {
+            match provider.value(i) {
+                Value::String(value) => assert!(re.is_match(&value), "{}", value),
+                _ => panic!("Wrong type"),
+            }
+        }
+
+        let provider = generate_provider("name: payload\nlength: 8\nencoding: base64");
+        let re = Regex::new(r"^[A-Za-z0-9+/]{11}=$").unwrap();
+        for i in 0..100 {
+            match provider.value(i) {
+                Value::String(value) => assert!(re.is_match(&value), "{}", value),
+                _ => panic!("Wrong type"),
+            }
+        }
+    }
+
+    // Corrupted value
+    #[test]
+    fn given_corrupted_should_return_bytes_longer_than_range() {
+        let provider = generate_provider("name: payload\nlength: 4..8");
+        for i in 0..1000 {
+            match provider.corrupted_value(i) {
+                Value::Bytes(value, _) => assert!(value.len() >= 8, "{}", value.len()),
+                _ => panic!("Wrong type"),
+            }
+        }
+
+        // an empty range still gives bytes
+        let provider = generate_provider("name: payload\nlength: 0");
+        match provider.corrupted_value(0) {
+            Value::Bytes(value, _) => assert!(!value.is_empty()),
+            _ => panic!("Wrong type"),
+        }
+    }
+}
@@ -3,10 +3,15 @@ use crate::providers::provider::{unknown_provider, Provider};
 
 use super::date;
 use super::datetime;
+use super::time;
 
 use yaml_rust::Yaml;
 
-const AVAILABLE: &[&str] = &["random.date.date", "random.date.datetime"];
+const AVAILABLE: &[&str] = &[
+    "random.date.date",
+    "random.date.datetime",
+    "random.date.time",
+];
 
 pub fn get_corresponding_provider(
     mut provider_split: std::str::Split<'_, char>,
@@ -14,7 +19,8 @@ pub fn get_corresponding_provider(
 ) -> Result<Box<dyn Provider>, FakeLakeError> {
     match provider_split.next() {
         Some("date") => Ok(date::new_from_yaml(column)),
-        Some("datetime") => Ok(datetime::new_from_yaml(column)),
+        Some("datetime") => Ok(datetime::new_from_yaml(column)?),
+        Some("time") => Ok(time::new_from_yaml(column)?),
         other => Err(unknown_provider("random.date", other, AVAILABLE)),
     }
 }
@@ -51,6 +57,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn given_time_should_return_provider() {
+        let provider_name = "time";
+        let yaml_str = format!("name: opening{}provider: {}", '\n', provider_name);
+        let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+        let provider_split = provider_name.split('.');
+        match get_corresponding_provider(provider_split, column) {
+            Ok(_) => (),
+            _ => panic!(),
+        }
+    }
+
     #[test]
     fn given_wrong_provider_should_return_error() {
         let provider_name = "not_a_provider";
@@ -1,56 +1,83 @@
+use crate::errors::FakeLakeError;
 use crate::providers::parameters::datetime::DatetimeParameter;
+use crate::providers::parameters::get_column_name;
+use crate::providers::parameters::timestamp::TimestampType;
 use crate::providers::provider::{Provider, Value};
 
-use chrono::{DateTime, Utc};
 use yaml_rust::Yaml;
 
 const DEFAULT_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
 const DEFAULT_AFTER: &str = "1980-01-01 12:00:00";
 const DEFAULT_BEFORE: &str = "2000-01-01 12:00:00";
 
-const MIN_TIMESTAMP: DateTime<Utc> = DateTime::<Utc>::MIN_UTC;
-const MAX_TIMESTAMP: DateTime<Utc> = DateTime::<Utc>::MAX_UTC;
-
 #[derive(Clone)]
 pub struct DatetimeProvider {
     pub format: String,
     pub after: i64,
     pub before: i64,
+    pub timestamp_type: TimestampType,
 }
 
-impl Provider for DatetimeProvider {
-    fn value(&self, _: u32) -> Value {
+impl DatetimeProvider {
+    fn timestamp(&self, units: i64) -> Value {
         Value::Timestamp(
-            DateTime::from_timestamp(crate::rng::i64(self.after..self.before), 0).unwrap(),
+            self.timestamp_type.timestamp(units).unwrap(),
             self.format.clone(),
+            self.timestamp_type,
         )
     }
+}
+
+impl Provider for DatetimeProvider {
+    fn value(&self, _: u32) -> Value {
+        let per_second = self.timestamp_type.precision.per_second();
+        self.timestamp(crate::rng::i64(
+            self.after * per_second..self.before * per_second,
+        ))
+    }
     fn corrupted_value(&self, _: u32) -> Value {
-        Value::Timestamp(
-            DateTime::from_timestamp(
-                crate::rng::i64(MIN_TIMESTAMP.timestamp()..MAX_TIMESTAMP.timestamp()),
-                0,
-            )
-            .unwrap(),
-            self.format.clone(),
-        )
+        let (min, max) = self.timestamp_type.units_range();
+        self.timestamp(crate::rng::i64(min..max))
     }
 }
 
-pub fn new_from_yaml(column: &Yaml) -> Box<DatetimeProvider> {
+pub fn new_from_yaml(column: &Yaml) -> Result<Box<DatetimeProvider>, FakeLakeError> {
+    let timestamp_type = TimestampType::from_yaml(column)?;
     let date_time_parameter =
         DatetimeParameter::new(column, DEFAULT_FORMAT, DEFAULT_AFTER, DEFAULT_BEFORE);
 
-    Box::new(DatetimeProvider {
-        format: date_time_parameter.format,
-        after: date_time_parameter.after,
-        before: date_time_parameter.before,
-    })
+    let format = match &column["format"] {
+        Yaml::BadValue => timestamp_type.default_format(DEFAULT_FORMAT),
+        _ => date_time_parameter.format,
+    };
+
+    // after and before are read in the timezone
+    let offset = timestamp_type
+        .timezone
+        .map_or(0, |timezone| timezone.local_minus_utc() as i64);
+    let after = date_time_parameter.after - offset;
+    let before = date_time_parameter.before - offset;
+
+    let per_second = timestamp_type.precision.per_second();
+    if after.checked_mul(per_second).is_none() || before.checked_mul(per_second).is_none() {
+        return Err(FakeLakeError::BadYAMLFormat(format!(
+            "Column {} after and before should be between 1677 and 2262 with precision ns.",
+            get_column_name(column)
+        )));
+    }
+
+    Ok(Box::new(DatetimeProvider {
+        format,
+        after,
+        before,
+        timestamp_type,
+    }))
 }
 
 #[cfg(test)]
 mod tests {
     use super::{DatetimeProvider, DEFAULT_AFTER, DEFAULT_BEFORE, DEFAULT_FORMAT};
+    use crate::providers::parameters::timestamp::{Precision, TimestampType};
     use crate::providers::provider::{Provider, Value};
 
     use chrono::NaiveDateTime;
@@ -77,7 +104,7 @@ mod tests {
         let yaml_str = format!("name: id{}{}{}", yaml_format, yaml_after, yaml_before);
 
         let yaml = YamlLoader::load_from_str(yaml_str.as_str()).unwrap();
-        super::new_from_yaml(&yaml[0])
+        super::new_from_yaml(&yaml[0]).unwrap()
     }
 
     fn get_seconds_since_day0(date: &str, format: &str) -> i64 {
@@ -91,7 +118,7 @@ mod tests {
     fn given_nothing_should_return_timestamp_type() {
         let provider = generate_provider(None, None, None);
         match provider.value(0) {
-            Value::Timestamp(_, _) => (),
+            Value::Timestamp(..) => (),
             _ => panic!(),
         };
     }
@@ -123,6 +150,32 @@ mod tests {
         assert_eq!(provider.after, get_seconds_since_day0(after, format));
     }
 
+    #[test]
+    fn given_precision_and_timezone_should_add_them_to_default_format() {
+        let yaml = YamlLoader::load_from_str("name: id\nprecision: us\ntimezone: +02:00").unwrap();
+        let provider = super::new_from_yaml(&yaml[0]).unwrap();
+
+        assert_eq!(provider.format, "%Y-%m-%d %H:%M:%S%.6f%:z");
+        assert_eq!(provider.timestamp_type.precision, Precision::Microseconds);
+        // after and before are read in the timezone
+        assert_eq!(
+            provider.after,
+            get_seconds_since_day0(DEFAULT_AFTER, DEFAULT_FORMAT) - 7200
+        );
+    }
+
+    #[test]
+    fn given_wrong_precision_or_timezone_should_return_err() {
+        for params in [
+            "precision: minutes",
+            "timezone: Europe",
+            "precision: ns\nafter: 1500-01-01 00:00:00",
+        ] {
+            let yaml = YamlLoader::load_from_str(&format!("name: id\n{}", params)).unwrap();
+            assert!(super::new_from_yaml(&yaml[0]).is_err(), "{}", params);
+        }
+    }
+
     // Validate value calculation
     #[test]
     fn given_provider_should_return_between_after_inclusive_and_before_exclusive() {
@@ -130,11 +183,12 @@ mod tests {
             format: DEFAULT_FORMAT.to_string(),
             after: get_seconds_since_day0(DEFAULT_AFTER, DEFAULT_FORMAT),
             before: get_seconds_since_day0(DEFAULT_BEFORE, DEFAULT_FORMAT),
+            timestamp_type: TimestampType::default(),
         };
 
         for value in 1..100 {
             match provider.value(value) {
-                Value::Timestamp(value, _) => {
+                Value::Timestamp(value, ..) => {
                     assert!(value.timestamp() >= provider.after);
                     assert!(value.timestamp() < provider.before);
                 }
@@ -149,11 +203,12 @@ mod tests {
             format: DEFAULT_FORMAT.to_string(),
             after: get_seconds_since_day0("2020-05-18 12:00:00", DEFAULT_FORMAT),
             before: get_seconds_since_day0("2020-05-18 12:00:01", DEFAULT_FORMAT),
+            timestamp_type: TimestampType::default(),
         };
 
         for value in 1..100 {
             match provider.value(value) {
-                Value::Timestamp(value, _) => {
+                Value::Timestamp(value, ..) => {
                     assert_eq!(
                         value.timestamp(),
                         get_seconds_since_day0("2020-05-18 12:00:00", DEFAULT_FORMAT)
@@ -170,12 +225,13 @@ mod tests {
             format: DEFAULT_FORMAT.to_string(),
             after: get_seconds_since_day0(DEFAULT_AFTER, DEFAULT_FORMAT),
             before: get_seconds_since_day0(DEFAULT_BEFORE, DEFAULT_FORMAT),
+            timestamp_type: TimestampType::default(),
         };
 
         let mut count_random_datetime = 0;
         for value in 1..100 {
             match provider.corrupted_value(value) {
-                Value::Timestamp(value, _) => {
+                Value::Timestamp(value, ..) => {
                     if value.timestamp() < provider.after || value.timestamp() > provider.before {
                         count_random_datetime += 1
                     }
@@ -185,4 +241,40 @@ mod tests {
         }
         assert!(count_random_datetime >= 99)
     }
+
+    #[test]
+    fn given_precision_should_return_sub_second_timestamps() {
+        let provider = DatetimeProvider {
+            format: DEFAULT_FORMAT.to_string(),
+            after: get_seconds_since_day0("2020-05-18 12:00:00", DEFAULT_FORMAT),
+            before: get_seconds_since_day0("2020-05-18 12:00:01", DEFAULT_FORMAT),
+            timestamp_type: TimestampType {
+                precision: Precision::Nanoseconds,
+                timezone: None,
+            },
+        };
+
+        let mut count_sub_second = 0;
+        for value in 1..100 {
+            match provider.value(value) {
+                Value::Timestamp(value, _, timestamp_type) => {
+                    assert_eq!(timestamp_type, provider.timestamp_type);
+                    assert_eq!(value.timestamp(), provider.after);
+                    if value.timestamp_subsec_nanos() != 0 {
+                        count_sub_second += 1
+                    }
+                }
+                _ => panic!("Wrong type"),
+            }
+        }
+        assert!(count_sub_second >= 95);
+
+        // corrupted values keep to the range of nanoseconds timestamps
+        for value in 1..100 {
+            match provider.corrupted_value(value) {
+                Value::Timestamp(value, ..) => assert!(value.timestamp_nanos_opt().is_some()),
+                _ => panic!("Wrong type"),
+            }
+        }
+    }
 }
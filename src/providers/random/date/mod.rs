@@ -3,3 +3,4 @@ pub mod builder;
 #[allow(clippy::module_inception)]
 pub mod date;
 pub mod datetime;
+pub mod time;
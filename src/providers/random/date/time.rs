@@ -0,0 +1,202 @@
+use crate::errors::FakeLakeError;
+use crate::providers::parameters::get_column_name;
+use crate::providers::provider::{Provider, Value};
+
+use chrono::{NaiveTime, Timelike};
+use yaml_rust::Yaml;
+
+const DEFAULT_FORMAT: &str = "%H:%M:%S";
+const DEFAULT_AFTER: &str = "00:00:00";
+const DEFAULT_BEFORE: &str = "00:00:00";
+
+const SECONDS_PER_DAY: u32 = 86400;
+
+#[derive(Clone)]
+pub struct TimeProvider {
+    pub format: String,
+    /// Seconds since midnight of the first time, included
+    pub after: u32,
+    /// Seconds since midnight of the last time, excluded. The range goes over midnight
+    /// when it is not after the first time.
+    pub before: u32,
+}
+
+impl TimeProvider {
+    /// Number of seconds in the range
+    fn length(&self) -> u32 {
+        match self.before > self.after {
+            true => self.before - self.after,
+            false => SECONDS_PER_DAY - self.after + self.before,
+        }
+    }
+
+    fn time(&self, seconds: u32) -> Value {
+        Value::Time(
+            NaiveTime::from_num_seconds_from_midnight_opt(seconds % SECONDS_PER_DAY, 0).unwrap(),
+            self.format.clone(),
+        )
+    }
+}
+
+impl Provider for TimeProvider {
+    fn value(&self, _: u32) -> Value {
+        self.time(self.after + crate::rng::u32(0..self.length()))
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        // times out of the range, or any time when the range is the whole day
+        match SECONDS_PER_DAY - self.length() {
+            0 => self.time(crate::rng::u32(0..SECONDS_PER_DAY)),
+            outside => self.time(self.after + self.length() + crate::rng::u32(0..outside)),
+        }
+    }
+}
+
+fn extract_time(
+    column: &Yaml,
+    param_name: &str,
+    default_value: &str,
+    format: &str,
+) -> Result<u32, FakeLakeError> {
+    let time = match &column[param_name] {
+        Yaml::BadValue => NaiveTime::parse_from_str(default_value, DEFAULT_FORMAT).ok(),
+        value => value
+            .as_str()
+            .and_then(|value| NaiveTime::parse_from_str(value, format).ok()),
+    };
+    match time {
+        Some(value) => Ok(value.num_seconds_from_midnight()),
+        None => Err(FakeLakeError::BadYAMLFormat(format!(
+            "Column {} param {} should be a time with the format {}.",
+            get_column_name(column),
+            param_name,
+            format
+        ))),
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml) -> Result<Box<TimeProvider>, FakeLakeError> {
+    let format = match &column["format"] {
+        Yaml::BadValue => DEFAULT_FORMAT.to_string(),
+        Yaml::String(value) => value.clone(),
+        _ => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} param format should be a string.",
+                get_column_name(column)
+            )))
+        }
+    };
+
+    Ok(Box::new(TimeProvider {
+        after: extract_time(column, "after", DEFAULT_AFTER, &format)?,
+        before: extract_time(column, "before", DEFAULT_BEFORE, &format)?,
+        format,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_from_yaml, TimeProvider};
+    use crate::providers::provider::{Provider, Value};
+
+    use chrono::{NaiveTime, Timelike};
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider(yaml_str: &str) -> Box<TimeProvider> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        new_from_yaml(&yaml[0]).unwrap()
+    }
+
+    fn seconds(value: Value) -> u32 {
+        match value {
+            Value::Time(value, _) => value.num_seconds_from_midnight(),
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    // Parquet type
+    #[test]
+    fn given_nothing_should_return_time_type() {
+        let provider = generate_provider("name: opening");
+        assert!(matches!(provider.value(0), Value::Time(_, _)));
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_no_params_should_give_the_whole_day() {
+        let provider = generate_provider("name: opening");
+        assert_eq!(provider.format, "%H:%M:%S");
+        assert_eq!((provider.after, provider.before), (0, 0));
+    }
+
+    #[test]
+    fn given_params_should_use_them() {
+        let provider =
+            generate_provider("name: opening\nformat: \"%Hh%M\"\nafter: 08h30\nbefore: 18h00");
+        assert_eq!(provider.format, "%Hh%M");
+        assert_eq!((provider.after, provider.before), (30_600, 64_800));
+
+        // times are strings in YAML
+        let provider = generate_provider("name: opening\nafter: 22:00:00\nbefore: 06:00:00");
+        assert_eq!((provider.after, provider.before), (79_200, 21_600));
+    }
+
+    #[test]
+    fn given_wrong_params_should_return_err() {
+        for params in [
+            "after: 25:00:00",
+            "after: 8",
+            "before: 08:00",
+            "format: 1",
+            "format: \"%H:%M\"\nbefore: 08:00:00",
+        ] {
+            let yaml = YamlLoader::load_from_str(&format!("name: opening\n{}", params)).unwrap();
+            assert!(new_from_yaml(&yaml[0]).is_err(), "{}", params);
+        }
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_range_should_return_times_within() {
+        let provider = generate_provider("name: opening\nafter: 08:00:00\nbefore: 18:00:00");
+        for i in 0..1000 {
+            let value = seconds(provider.value(i));
+            assert!((28_800..64_800).contains(&value), "{}", value);
+        }
+    }
+
+    #[test]
+    fn given_range_over_midnight_should_return_night_times() {
+        let provider = generate_provider("name: night\nafter: 22:00:00\nbefore: 06:00:00");
+        let mut count_after_midnight = 0;
+        for i in 0..1000 {
+            let value = seconds(provider.value(i));
+            assert!(!(21_600..79_200).contains(&value), "{}", value);
+            if value < 21_600 {
+                count_after_midnight += 1;
+            }
+        }
+        assert!(count_after_midnight > 600);
+    }
+
+    #[test]
+    fn given_format_should_keep_it_in_values() {
+        let provider = generate_provider("name: opening\nformat: \"%H:%M\"\nafter: 08:00");
+        match provider.value(0) {
+            Value::Time(value, format) => {
+                assert_eq!(format, "%H:%M");
+                assert!(value >= NaiveTime::from_hms_opt(8, 0, 0).unwrap());
+            }
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    // Corrupted value
+    #[test]
+    fn given_corrupted_should_return_times_out_of_range() {
+        let provider = generate_provider("name: opening\nafter: 08:00:00\nbefore: 18:00:00");
+        for i in 0..1000 {
+            let value = seconds(provider.corrupted_value(i));
+            assert!(!(28_800..64_800).contains(&value), "{}", value);
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use crate::errors::FakeLakeError;
+use crate::providers::parameters::duration::{Duration, Format};
+use crate::providers::parameters::get_column_name;
+use crate::providers::provider::{Provider, Value};
+
+use chrono::TimeDelta;
+use yaml_rust::Yaml;
+
+const DEFAULT_MIN: i64 = 0;
+const DEFAULT_MAX: i64 = 86400;
+
+#[derive(Clone)]
+pub struct DurationProvider {
+    /// Min seconds, included
+    pub min: i64,
+    /// Max seconds, excluded
+    pub max: i64,
+    pub format: Format,
+}
+
+impl Provider for DurationProvider {
+    fn value(&self, _: u32) -> Value {
+        Value::Duration(
+            TimeDelta::seconds(crate::rng::i64(self.min..self.max)),
+            self.format,
+        )
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        // negative durations
+        Value::Duration(
+            TimeDelta::seconds(-crate::rng::i64(1..self.max + 1)),
+            self.format,
+        )
+    }
+}
+
+fn extract_seconds(column: &Yaml, param_name: &str, default: i64) -> Result<i64, FakeLakeError> {
+    let seconds = match &column[param_name] {
+        Yaml::BadValue => Some(default),
+        Yaml::Integer(value) => Some(*value),
+        _ => match Duration::from_yaml(column, param_name)? {
+            Some(Duration::Seconds(value)) => Some(value),
+            _ => None,
+        },
+    };
+    match seconds {
+        Some(value) if value >= 0 && TimeDelta::try_seconds(value + 1).is_some() => Ok(value),
+        _ => Err(FakeLakeError::BadYAMLFormat(format!(
+            "Column {} param {} should be a positive number of seconds or a duration without months and years.",
+            get_column_name(column),
+            param_name
+        ))),
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml) -> Result<Box<DurationProvider>, FakeLakeError> {
+    let column_name = get_column_name(column);
+
+    let format = match column["format"].as_str() {
+        None if column["format"].is_badvalue() => Format::Seconds,
+        Some("seconds") => Format::Seconds,
+        Some("iso") => Format::Iso,
+        _ => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} param format should be seconds or iso.",
+                column_name
+            )))
+        }
+    };
+
+    let min = extract_seconds(column, "min", DEFAULT_MIN)?;
+    let max = extract_seconds(column, "max", DEFAULT_MAX)?;
+    if max <= min {
+        return Err(FakeLakeError::BadYAMLFormat(format!(
+            "Column {} param max should be longer than min.",
+            column_name
+        )));
+    }
+
+    Ok(Box::new(DurationProvider { min, max, format }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_from_yaml, DurationProvider};
+    use crate::providers::parameters::duration::Format;
+    use crate::providers::provider::{Provider, Value};
+
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider(yaml_str: &str) -> Box<DurationProvider> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        new_from_yaml(&yaml[0]).unwrap()
+    }
+
+    fn seconds(value: Value) -> i64 {
+        match value {
+            Value::Duration(value, _) => value.num_seconds(),
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    // Parquet type
+    #[test]
+    fn given_nothing_should_return_duration_type() {
+        let provider = generate_provider("name: sla");
+        assert!(matches!(provider.value(0), Value::Duration(_, _)));
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_no_params_should_use_defaults() {
+        let provider = generate_provider("name: sla");
+        assert_eq!((provider.min, provider.max), (0, 86400));
+        assert_eq!(provider.format, Format::Seconds);
+    }
+
+    #[test]
+    fn given_params_should_use_them() {
+        for (params, min, max) in [
+            ("min: 60\nmax: 3600", 60, 3600),
+            ("min: 15 minutes\nmax: 2 hours", 900, 7200),
+            ("min: PT15M\nmax: P1DT12H", 900, 129_600),
+        ] {
+            let provider = generate_provider(&format!("name: sla\n{}", params));
+            assert_eq!((provider.min, provider.max), (min, max), "{}", params);
+        }
+        assert_eq!(
+            generate_provider("name: sla\nformat: iso").format,
+            Format::Iso
+        );
+    }
+
+    #[test]
+    fn given_wrong_params_should_return_err() {
+        for params in [
+            "min: -1",
+            "min: 1 month",
+            "max: P1Y",
+            "max: a",
+            "min: 60\nmax: 60",
+            "min: 2 hours\nmax: 1 hour",
+            "format: minutes",
+            "format: 1",
+        ] {
+            let yaml = YamlLoader::load_from_str(&format!("name: sla\n{}", params)).unwrap();
+            assert!(new_from_yaml(&yaml[0]).is_err(), "{}", params);
+        }
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_range_should_return_durations_within() {
+        let provider = generate_provider("name: sla\nmin: 15 minutes\nmax: 4 hours");
+        for i in 0..1000 {
+            let value = seconds(provider.value(i));
+            assert!((900..14_400).contains(&value), "{}", value);
+        }
+    }
+
+    // Corrupted value
+    #[test]
+    fn given_corrupted_should_return_negative_durations() {
+        let provider = generate_provider("name: sla\nmin: 60\nmax: 3600");
+        for i in 0..1000 {
+            let value = seconds(provider.corrupted_value(i));
+            assert!((-3600..0).contains(&value), "{}", value);
+        }
+    }
+}
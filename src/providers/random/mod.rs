@@ -1,6 +1,9 @@
 pub mod builder;
 
 pub mod bool;
+pub mod bytes;
 pub mod date;
+pub mod duration;
 pub mod number;
 pub mod string;
+pub mod uuid;
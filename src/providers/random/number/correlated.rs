@@ -0,0 +1,445 @@
+use crate::errors::FakeLakeError;
+use crate::providers::provider::{Provider, Value};
+use crate::providers::utils::normal;
+use crate::row;
+
+use super::f64::{self, F64Provider};
+use super::i32::{self, I32Provider};
+
+use std::collections::HashMap;
+use yaml_rust::Yaml;
+
+/// Distribution of the values of a correlated column, given by its Random.Number provider
+#[derive(Clone)]
+pub enum Marginal {
+    F64(F64Provider),
+    I32(I32Provider),
+}
+
+impl Marginal {
+    fn provider(&self) -> &dyn Provider {
+        match self {
+            Marginal::F64(provider) => provider,
+            Marginal::I32(provider) => provider,
+        }
+    }
+
+    /// Value of the distribution with a probability p of being greater than the others
+    fn quantile(&self, p: f64) -> Value {
+        match self {
+            Marginal::F64(provider) => Value::Float64(provider.min * (1.0 - p) + provider.max * p),
+            Marginal::I32(provider) => {
+                let width = provider.max as f64 - provider.min as f64;
+                let value = provider.min as f64 + (p * width).floor();
+                Value::Int32((value as i32).min(provider.max - 1))
+            }
+        }
+    }
+
+    /// Probability of a value of the distribution being less than value
+    fn cdf(&self, value: &Value) -> Option<f64> {
+        let p = match (self, value) {
+            (Marginal::F64(provider), Value::Float64(value)) => {
+                (value / 2.0 - provider.min / 2.0) / (provider.max / 2.0 - provider.min / 2.0)
+            }
+            (Marginal::I32(provider), Value::Int32(value)) => {
+                (*value as f64 - provider.min as f64 + 0.5)
+                    / (provider.max as f64 - provider.min as f64)
+            }
+            _ => return None,
+        };
+        Some(p.clamp(0.0, 1.0))
+    }
+}
+
+/// Column of a correlation group. The values follow a Gaussian copula: the column
+/// reads the columns generated before it in the group and draws its value conditionally.
+#[derive(Clone)]
+pub struct CorrelatedProvider {
+    pub marginal: Marginal,
+    /// Columns of the group generated before this one
+    pub previous: Vec<(String, Marginal)>,
+    /// Rows of the Cholesky factor of the correlation matrix, up to this column
+    pub cholesky: Vec<Vec<f64>>,
+}
+
+fn weighted_sum(weights: &[f64], draws: &[f64]) -> f64 {
+    weights.iter().zip(draws).map(|(w, d)| w * d).sum()
+}
+
+impl Provider for CorrelatedProvider {
+    fn value(&self, _: u32) -> Value {
+        // independent draws leading to the values of the previous columns
+        let mut draws: Vec<f64> = Vec::with_capacity(self.cholesky.len());
+        for (position, (column, marginal)) in self.previous.iter().enumerate() {
+            let weights = &self.cholesky[position];
+            let draw = match row::get(column).and_then(|value| marginal.cdf(&value)) {
+                Some(p) => {
+                    (normal::quantile(p) - weighted_sum(weights, &draws)) / weights[position]
+                }
+                None => normal::sample(),
+            };
+            draws.push(draw);
+        }
+
+        let position = self.previous.len();
+        let weights = &self.cholesky[position];
+        let z = weighted_sum(weights, &draws) + weights[position] * normal::sample();
+        self.marginal.quantile(normal::cdf(z))
+    }
+    fn corrupted_value(&self, index: u32) -> Value {
+        self.marginal.provider().corrupted_value(index)
+    }
+    fn sample_value(&self) -> Value {
+        self.marginal.provider().sample_value()
+    }
+    fn dependencies(&self) -> Vec<String> {
+        self.previous
+            .iter()
+            .map(|(column, _)| column.clone())
+            .collect()
+    }
+}
+
+/// Lower triangular matrix L such that L * transpose(L) is the matrix,
+/// None if the matrix is not positive definite
+fn cholesky(matrix: &[Vec<f64>]) -> Option<Vec<Vec<f64>>> {
+    let size = matrix.len();
+    let mut lower = vec![vec![0.0; size]; size];
+    for i in 0..size {
+        for j in 0..=i {
+            let sum: f64 = (0..j).map(|k| lower[i][k] * lower[j][k]).sum();
+            if i == j {
+                let diagonal = matrix[i][i] - sum;
+                if diagonal <= 1e-10 {
+                    return None;
+                }
+                lower[i][j] = diagonal.sqrt();
+            } else {
+                lower[i][j] = (matrix[i][j] - sum) / lower[j][j];
+            }
+        }
+    }
+    Some(lower)
+}
+
+fn get_matrix(group: &Yaml, size: usize, group_name: &str) -> Result<Vec<Vec<f64>>, FakeLakeError> {
+    let wrong_matrix = || {
+        FakeLakeError::BadYAMLFormat(format!(
+            "Correlation of {} should have a matrix of {} rows of {} numbers.",
+            group_name, size, size
+        ))
+    };
+
+    let rows = match group["matrix"].as_vec() {
+        Some(value) if value.len() == size => value,
+        _ => return Err(wrong_matrix()),
+    };
+
+    let mut matrix = Vec::new();
+    for row in rows {
+        let values = match row.as_vec() {
+            Some(value) if value.len() == size => value,
+            _ => return Err(wrong_matrix()),
+        };
+        let mut numbers = Vec::new();
+        for value in values {
+            match value {
+                Yaml::Integer(value) => numbers.push(*value as f64),
+                Yaml::Real(_) => numbers.push(value.as_f64().unwrap()),
+                _ => return Err(wrong_matrix()),
+            }
+        }
+        matrix.push(numbers);
+    }
+
+    for (i, row) in matrix.iter().enumerate() {
+        if row[i] != 1.0 {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Correlation of {} should have 1 on the diagonal of its matrix.",
+                group_name
+            )));
+        }
+        for (j, value) in row.iter().enumerate() {
+            if *value != matrix[j][i] || !(-1.0..=1.0).contains(value) {
+                return Err(FakeLakeError::BadYAMLFormat(format!(
+                    "Correlation of {} should have a symmetric matrix with values between -1 and 1.",
+                    group_name
+                )));
+            }
+        }
+    }
+
+    Ok(matrix)
+}
+
+fn get_marginal(columns: &Yaml, column_name: &str) -> Result<Marginal, FakeLakeError> {
+    let column = match columns.as_vec().and_then(|columns| {
+        columns
+            .iter()
+            .find(|column| column["name"].as_str() == Some(column_name))
+    }) {
+        Some(value) => value,
+        None => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Correlation uses the column {} which does not exist.",
+                column_name
+            )))
+        }
+    };
+
+    match column["provider"].as_str().map(|provider| provider.to_lowercase()) {
+        Some(provider) if provider == "random.number.f64" => {
+            Ok(Marginal::F64(*f64::new_from_yaml(column)))
+        }
+        Some(provider) if provider == "random.number.i32" => {
+            Ok(Marginal::I32(*i32::new_from_yaml(column)))
+        }
+        _ => Err(FakeLakeError::BadYAMLFormat(format!(
+            "Column {} should use the Random.Number.f64 or Random.Number.i32 provider to be correlated.",
+            column_name
+        ))),
+    }
+}
+
+/// Providers of the columns listed in the correlations section of a table, by column name
+pub fn new_from_yaml(table: &Yaml) -> Result<HashMap<String, Box<dyn Provider>>, FakeLakeError> {
+    let mut providers: HashMap<String, Box<dyn Provider>> = HashMap::new();
+
+    let groups = match &table["correlations"] {
+        Yaml::BadValue => return Ok(providers),
+        Yaml::Array(value) => value,
+        _ => {
+            return Err(FakeLakeError::BadYAMLFormat(
+                "The correlations section should be a list.".to_string(),
+            ))
+        }
+    };
+
+    for group in groups {
+        let names: Vec<&str> = match group["columns"].as_vec() {
+            Some(value) if value.len() >= 2 => {
+                value.iter().filter_map(|name| name.as_str()).collect()
+            }
+            _ => Vec::new(),
+        };
+        if names.len() < 2 || Some(names.len()) != group["columns"].as_vec().map(|v| v.len()) {
+            return Err(FakeLakeError::BadYAMLFormat(
+                "Each correlation should have a columns list of at least two column names."
+                    .to_string(),
+            ));
+        }
+        let group_name = names.join(", ");
+
+        let matrix = get_matrix(group, names.len(), &group_name)?;
+        let cholesky = match cholesky(&matrix) {
+            Some(value) => value,
+            None => {
+                return Err(FakeLakeError::BadYAMLFormat(format!(
+                    "Correlation of {} has a matrix which is not positive definite.",
+                    group_name
+                )))
+            }
+        };
+
+        let mut previous: Vec<(String, Marginal)> = Vec::new();
+        for (position, name) in names.iter().enumerate() {
+            if providers.contains_key(*name) || names[..position].contains(name) {
+                return Err(FakeLakeError::BadYAMLFormat(format!(
+                    "Column {} is in more than one correlation.",
+                    name
+                )));
+            }
+
+            let marginal = get_marginal(&table["columns"], name)?;
+            providers.insert(
+                name.to_string(),
+                Box::new(CorrelatedProvider {
+                    marginal: marginal.clone(),
+                    previous: previous.clone(),
+                    cholesky: cholesky[..=position].to_vec(),
+                }),
+            );
+            previous.push((name.to_string(), marginal));
+        }
+    }
+
+    Ok(providers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cholesky, new_from_yaml};
+    use crate::providers::provider::{Provider, Value};
+    use crate::row;
+
+    use std::collections::HashMap;
+    use yaml_rust::YamlLoader;
+
+    fn generate_providers(yaml_str: &str) -> HashMap<String, Box<dyn Provider>> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        match new_from_yaml(&yaml[0]) {
+            Ok(providers) => providers,
+            Err(e) => panic!("Providers should be created: {}", e),
+        }
+    }
+
+    fn expecting_err(yaml_str: &str) {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        if new_from_yaml(&yaml[0]).is_ok() {
+            panic!("{} should not be valid", yaml_str)
+        }
+    }
+
+    fn as_f64(value: Value) -> f64 {
+        match value {
+            Value::Float64(value) => value,
+            Value::Int32(value) => value as f64,
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    fn pearson(pairs: &[(f64, f64)]) -> f64 {
+        let n = pairs.len() as f64;
+        let mean_x = pairs.iter().map(|p| p.0).sum::<f64>() / n;
+        let mean_y = pairs.iter().map(|p| p.1).sum::<f64>() / n;
+        let covariance: f64 = pairs.iter().map(|p| (p.0 - mean_x) * (p.1 - mean_y)).sum();
+        let variance_x: f64 = pairs.iter().map(|p| (p.0 - mean_x).powi(2)).sum();
+        let variance_y: f64 = pairs.iter().map(|p| (p.1 - mean_y).powi(2)).sum();
+        covariance / (variance_x * variance_y).sqrt()
+    }
+
+    /// Pairs of values of the first two columns, generated in order like rows
+    fn generate_pairs(yaml_str: &str, first: &str, second: &str) -> Vec<(f64, f64)> {
+        crate::rng::initialize_rng(Some(42));
+        let providers = generate_providers(yaml_str);
+        (0..5_000)
+            .map(|index| {
+                let first_value = providers[first].value(index);
+                row::set(first, Some(first_value.clone()));
+                let second_value = providers[second].value(index);
+                (as_f64(first_value), as_f64(second_value))
+            })
+            .collect()
+    }
+
+    const HEIGHT_WEIGHT: &str = "
+    columns:
+        - name: height
+          provider: Random.Number.f64
+          min: 150
+          max: 200
+        - name: weight
+          provider: Random.Number.i32
+          min: 50
+          max: 100
+        - name: id
+          provider: Increment.integer
+    correlations:
+        - columns: [height, weight]
+          matrix:
+            - [1, 0.8]
+            - [0.8, 1]
+    ";
+
+    // Validate YAML file
+    #[test]
+    fn given_no_correlations_should_return_no_provider() {
+        let providers =
+            generate_providers("columns:\n  - name: a\n    provider: Random.Number.f64");
+        assert!(providers.is_empty());
+    }
+
+    #[test]
+    fn given_wrong_correlations_should_return_err() {
+        let columns = "columns:
+  - name: a
+    provider: Random.Number.f64
+  - name: b
+    provider: Random.Number.i32
+  - name: c
+    provider: Increment.integer
+";
+        let correlations = [
+            "correlations: a",
+            "correlations:\n  - matrix: [[1, 0.5], [0.5, 1]]",
+            "correlations:\n  - columns: [a]\n    matrix: [[1]]",
+            "correlations:\n  - columns: [a, b]",
+            "correlations:\n  - columns: [a, b]\n    matrix: [[1, 0.5]]",
+            "correlations:\n  - columns: [a, b]\n    matrix: [[1, 0.5], [0.4, 1]]",
+            "correlations:\n  - columns: [a, b]\n    matrix: [[2, 0.5], [0.5, 1]]",
+            "correlations:\n  - columns: [a, b]\n    matrix: [[1, 1.5], [1.5, 1]]",
+            "correlations:\n  - columns: [a, b]\n    matrix: [[1, a], [a, 1]]",
+            "correlations:\n  - columns: [a, c]\n    matrix: [[1, 0.5], [0.5, 1]]",
+            "correlations:\n  - columns: [a, d]\n    matrix: [[1, 0.5], [0.5, 1]]",
+            "correlations:\n  - columns: [a, a]\n    matrix: [[1, 0.5], [0.5, 1]]",
+            "correlations:\n  - columns: [a, b]\n    matrix: [[1, 0.5], [0.5, 1]]\n  - columns: [b, a]\n    matrix: [[1, 0.5], [0.5, 1]]",
+        ];
+        for correlation in correlations {
+            expecting_err(&format!("{}{}", columns, correlation));
+        }
+    }
+
+    #[test]
+    fn given_not_positive_definite_matrix_should_return_err() {
+        expecting_err(
+            "columns:
+  - name: a
+    provider: Random.Number.f64
+  - name: b
+    provider: Random.Number.f64
+  - name: c
+    provider: Random.Number.f64
+correlations:
+  - columns: [a, b, c]
+    matrix:
+      - [1, 0.9, -0.9]
+      - [0.9, 1, 0.9]
+      - [-0.9, 0.9, 1]",
+        );
+    }
+
+    #[test]
+    fn given_group_should_depend_on_previous_columns() {
+        let providers = generate_providers(HEIGHT_WEIGHT);
+        assert_eq!(providers.len(), 2);
+        assert!(providers["height"].dependencies().is_empty());
+        assert_eq!(providers["weight"].dependencies(), vec!["height"]);
+    }
+
+    #[test]
+    fn given_matrix_should_return_cholesky_factor() {
+        let lower = cholesky(&[vec![4.0, 2.0], vec![2.0, 5.0]]).unwrap();
+        assert_eq!(lower, vec![vec![2.0, 0.0], vec![1.0, 2.0]]);
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_correlation_should_generate_correlated_values() {
+        let pairs = generate_pairs(HEIGHT_WEIGHT, "height", "weight");
+        let correlation = pearson(&pairs);
+        assert!(correlation > 0.72 && correlation < 0.85, "{}", correlation);
+
+        for (height, weight) in pairs {
+            assert!((150.0..200.0).contains(&height));
+            assert!((50.0..100.0).contains(&weight));
+        }
+    }
+
+    #[test]
+    fn given_negative_correlation_should_generate_opposite_values() {
+        let yaml_str = HEIGHT_WEIGHT.replace("0.8", "-0.5");
+        let correlation = pearson(&generate_pairs(&yaml_str, "height", "weight"));
+        assert!(correlation < -0.4 && correlation > -0.6, "{}", correlation);
+    }
+
+    #[test]
+    fn given_missing_previous_column_should_generate_value() {
+        let providers = generate_providers(HEIGHT_WEIGHT);
+        row::set("height", None);
+        match providers["weight"].value(0) {
+            Value::Int32(value) => assert!((50..100).contains(&value)),
+            _ => panic!("Wrong type"),
+        }
+    }
+}
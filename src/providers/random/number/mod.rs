@@ -1,4 +1,6 @@
 pub mod builder;
 
+pub mod correlated;
+
 pub mod f64;
 pub mod i32;
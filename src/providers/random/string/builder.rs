@@ -1,11 +1,11 @@
 use crate::errors::FakeLakeError;
 use crate::providers::provider::{unknown_provider, Provider};
 
-use super::alphanumeric;
+use super::{alphanumeric, pattern};
 
 use yaml_rust::Yaml;
 
-const AVAILABLE: &[&str] = &["random.string.alphanumeric"];
+const AVAILABLE: &[&str] = &["random.string.alphanumeric", "random.string.pattern"];
 
 pub fn get_corresponding_provider(
     mut provider_split: std::str::Split<'_, char>,
@@ -13,6 +13,7 @@ pub fn get_corresponding_provider(
 ) -> Result<Box<dyn Provider>, FakeLakeError> {
     match provider_split.next() {
         Some("alphanumeric") => Ok(alphanumeric::new_from_yaml(column)),
+        Some("pattern") => Ok(pattern::new_from_yaml(column)?),
         other => Err(unknown_provider("random.string", other, AVAILABLE)),
     }
 }
@@ -36,6 +37,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn given_pattern_should_return_provider() {
+        let provider_name = "pattern";
+        let yaml_str = format!(
+            "name: name{}provider: {}{}mask: '#'",
+            '\n', provider_name, '\n'
+        );
+        let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+        let provider_split = provider_name.split('.');
+        match get_corresponding_provider(provider_split, column) {
+            Ok(_) => (),
+            _ => panic!(),
+        }
+    }
+
     #[test]
     fn given_wrong_provider_should_return_error() {
         let provider_name = "not_a_provider";
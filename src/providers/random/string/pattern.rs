@@ -0,0 +1,271 @@
+use crate::errors::FakeLakeError;
+use crate::providers::parameters::get_column_name;
+use crate::providers::provider::{Provider, Value};
+use crate::providers::utils::string::random_characters;
+use crate::rng;
+
+use regex::Regex;
+use regex_syntax::hir::{Class, Hir, HirKind};
+use yaml_rust::Yaml;
+
+/// Repetitions added to the minimum of an unbounded repetition like + or *
+const MAX_EXTRA_REPETITIONS: u32 = 5;
+const MAX_CORRUPTION_TRIES: u32 = 20;
+
+/// Regex of a mask, where # is a digit, ? an uppercase letter and the other characters are kept
+pub fn mask_to_pattern(mask: &str) -> String {
+    mask.chars()
+        .map(|c| match c {
+            '#' => "[0-9]".to_string(),
+            '?' => "[A-Z]".to_string(),
+            c => regex_syntax::escape(&c.to_string()),
+        })
+        .collect()
+}
+
+/// Random character of the ranges, a printable ASCII one when there are some
+fn random_char(ranges: &[(u32, u32)]) -> char {
+    let printable: Vec<(u32, u32)> = ranges
+        .iter()
+        .filter_map(|(start, end)| {
+            let (start, end) = ((*start).max(0x20), (*end).min(0x7e));
+            (start <= end).then_some((start, end))
+        })
+        .collect();
+    let ranges = match printable.is_empty() {
+        true => ranges,
+        false => &printable,
+    };
+
+    let total: u32 = ranges.iter().map(|(start, end)| end - start + 1).sum();
+    let mut random = rng::u32(0..total);
+    for (start, end) in ranges {
+        if random <= end - start {
+            // surrogates are not characters, the first character of the range is taken instead
+            return char::from_u32(start + random).unwrap_or(char::from_u32(*start).unwrap());
+        }
+        random -= end - start + 1;
+    }
+    panic!("Random value can't be greater than the size of the ranges")
+}
+
+/// Add to the value a random string matched by the regex
+fn generate(hir: &Hir, value: &mut String) {
+    match hir.kind() {
+        HirKind::Empty | HirKind::Look(_) => (),
+        HirKind::Literal(literal) => value.push_str(&String::from_utf8_lossy(&literal.0)),
+        HirKind::Class(Class::Unicode(class)) => {
+            let ranges: Vec<(u32, u32)> = class
+                .ranges()
+                .iter()
+                .map(|range| (range.start() as u32, range.end() as u32))
+                .collect();
+            value.push(random_char(&ranges));
+        }
+        HirKind::Class(Class::Bytes(class)) => {
+            let ranges: Vec<(u32, u32)> = class
+                .ranges()
+                .iter()
+                .map(|range| (range.start() as u32, range.end().min(0x7f) as u32))
+                .filter(|(start, end)| start <= end)
+                .collect();
+            value.push(random_char(&ranges));
+        }
+        HirKind::Repetition(repetition) => {
+            let max = repetition
+                .max
+                .unwrap_or(repetition.min + MAX_EXTRA_REPETITIONS);
+            for _ in 0..rng::u32(repetition.min..max + 1) {
+                generate(&repetition.sub, value);
+            }
+        }
+        HirKind::Capture(capture) => generate(&capture.sub, value),
+        HirKind::Concat(hirs) => hirs.iter().for_each(|hir| generate(hir, value)),
+        HirKind::Alternation(hirs) => generate(&hirs[rng::usize(..hirs.len())], value),
+    }
+}
+
+/// Value with a character replaced, removed or added
+fn mutate(value: &str) -> String {
+    let mut chars: Vec<char> = value.chars().collect();
+    let position = rng::usize(..chars.len() + 1);
+    match (rng::u32(0..3), chars.get(position)) {
+        (0, Some(c)) if c.is_ascii_digit() => {
+            chars[position] = (b'a' + rng::u32(0..26) as u8) as char
+        }
+        (0, Some(_)) => chars[position] = char::from_digit(rng::u32(0..10), 10).unwrap(),
+        (1, Some(_)) => {
+            chars.remove(position);
+        }
+        _ => chars.insert(position, rng::alphanumeric()),
+    }
+    chars.into_iter().collect()
+}
+
+#[derive(Clone)]
+pub struct PatternProvider {
+    pub hir: Hir,
+    /// Regex matching the whole values
+    pub regex: Regex,
+}
+
+impl Provider for PatternProvider {
+    fn value(&self, _: u32) -> Value {
+        let mut value = String::new();
+        generate(&self.hir, &mut value);
+        Value::String(value)
+    }
+    fn corrupted_value(&self, index: u32) -> Value {
+        // valid values changed until they don't match the pattern anymore
+        let mut value = match self.value(index) {
+            Value::String(value) => value,
+            _ => panic!("Pattern values should be strings"),
+        };
+        for _ in 0..MAX_CORRUPTION_TRIES {
+            value = mutate(&value);
+            if !self.regex.is_match(&value) {
+                return Value::String(value);
+            }
+        }
+        Value::String(random_characters(value.chars().count().max(1) as u32))
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml) -> Result<Box<PatternProvider>, FakeLakeError> {
+    let column_name = get_column_name(column);
+
+    let pattern = match (&column["pattern"], &column["mask"]) {
+        (Yaml::String(pattern), Yaml::BadValue) => pattern.clone(),
+        (Yaml::BadValue, Yaml::String(mask)) => mask_to_pattern(mask),
+        _ => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} should specify either a pattern or a mask string.",
+                column_name
+            )))
+        }
+    };
+
+    let wrong_pattern = |e: &dyn std::fmt::Display| {
+        FakeLakeError::BadYAMLFormat(format!("Column {} has a wrong pattern: {}", column_name, e))
+    };
+    let hir = regex_syntax::parse(&pattern).map_err(|e| wrong_pattern(&e))?;
+    let regex = Regex::new(&format!("^(?:{})$", pattern)).map_err(|e| wrong_pattern(&e))?;
+
+    Ok(Box::new(PatternProvider { hir, regex }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{mask_to_pattern, new_from_yaml, PatternProvider};
+    use crate::providers::provider::{Provider, Value};
+
+    use regex::Regex;
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider(yaml_str: &str) -> Box<PatternProvider> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        new_from_yaml(&yaml[0]).unwrap()
+    }
+
+    fn string_value(value: Value) -> String {
+        match value {
+            Value::String(value) => value,
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_mask_should_return_pattern() {
+        assert_eq!(
+            mask_to_pattern("ORD-####-??"),
+            r"ORD\-[0-9][0-9][0-9][0-9]\-[A-Z][A-Z]"
+        );
+        assert_eq!(mask_to_pattern("1.5"), r"1\.5");
+
+        let provider = generate_provider("name: code\nmask: 'AB#'");
+        assert_eq!(provider.regex.as_str(), "^(?:AB[0-9])$");
+    }
+
+    #[test]
+    fn given_wrong_params_should_return_err() {
+        for params in [
+            "",
+            "pattern: 12",
+            "pattern: '[A-Z'",
+            "pattern: 'a{2,1}'",
+            "pattern: a\nmask: '#'",
+        ] {
+            let yaml = YamlLoader::load_from_str(&format!("name: code\n{}", params)).unwrap();
+            assert!(
+                new_from_yaml(&yaml[0]).is_err(),
+                "{} should be wrong",
+                params
+            );
+        }
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_pattern_should_return_matching_values() {
+        for pattern in [
+            r"[A-Z]{3}-\d{4}",
+            r"^(FR|DE)[0-9]{2} ?\w+$",
+            r"[a-f0-9]{8}(-[a-f0-9]{4}){3}",
+            r"[^a-z]{5}",
+            r"x.y*z+",
+            r"é[àç]",
+        ] {
+            let provider = generate_provider(&format!("name: code\npattern: '{}'", pattern));
+            let re = Regex::new(&format!("^(?:{})$", pattern)).unwrap();
+            for i in 0..100 {
+                let value = string_value(provider.value(i));
+                assert!(re.is_match(&value), "{} does not match {}", value, pattern);
+            }
+        }
+    }
+
+    #[test]
+    fn given_classes_should_return_ascii_characters_when_possible() {
+        let provider = generate_provider(
+            r"name: code
+pattern: '\d\w.\s'",
+        );
+        let re = Regex::new(r"^[0-9][0-9A-Za-z_][ -~][ ]$").unwrap();
+        for i in 0..100 {
+            let value = string_value(provider.value(i));
+            assert!(re.is_match(&value), "{} is not ASCII", value);
+        }
+    }
+
+    #[test]
+    fn given_mask_should_return_matching_values() {
+        let provider = generate_provider("name: code\nmask: 'ORD-####-??'");
+        let re = Regex::new(r"^ORD-\d{4}-[A-Z]{2}$").unwrap();
+        for i in 0..100 {
+            let value = string_value(provider.value(i));
+            assert!(re.is_match(&value), "{} does not match", value);
+        }
+    }
+
+    #[test]
+    fn given_corrupted_should_return_values_not_matching() {
+        for params in [
+            r"pattern: '[A-Z]{3}-\d{4}'",
+            "pattern: 'a?'",
+            "pattern: '[0-9]+'",
+            "mask: 'AB-#'",
+        ] {
+            let provider = generate_provider(&format!("name: code\n{}", params));
+            for i in 0..100 {
+                let value = string_value(provider.corrupted_value(i));
+                assert!(
+                    !provider.regex.is_match(&value),
+                    "{} should not match {}",
+                    value,
+                    provider.regex
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,305 @@
+use crate::errors::FakeLakeError;
+use crate::providers::parameters::get_column_name;
+use crate::providers::provider::{Provider, Value};
+use crate::rng;
+
+use chrono::NaiveDateTime;
+use yaml_rust::Yaml;
+
+const DEFAULT_START: &str = "2024-01-01 00:00:00";
+const START_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+const AVAILABLE_FORMATS: &[&str] = &["hyphenated", "simple", "urn"];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Version {
+    /// Random
+    V4,
+    /// Unix time in milliseconds followed by random bits
+    V7,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    Hyphenated,
+    Simple,
+    Urn,
+}
+
+/// UUID written like 67e55044-10b1-426f-9247-bb680e5fe0c8
+pub fn hyphenated(bytes: &[u8; 16]) -> String {
+    let hex: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+#[derive(Clone)]
+pub struct UuidProvider {
+    pub version: Version,
+    pub format: Format,
+    /// Whether the UUID is written as 16 bytes instead of a string
+    pub binary: bool,
+    /// Unix time in milliseconds of the first row of a v7 UUID, each row being one millisecond later
+    pub start: i64,
+}
+
+impl UuidProvider {
+    fn bytes(&self, index: u32) -> [u8; 16] {
+        let mut bytes = rng::u128().to_be_bytes();
+        if self.version == Version::V7 {
+            let timestamp = (self.start + index as i64) as u64;
+            bytes[..6].copy_from_slice(&timestamp.to_be_bytes()[2..]);
+        }
+        let version = match self.version {
+            Version::V4 => 0x40,
+            Version::V7 => 0x70,
+        };
+        bytes[6] = (bytes[6] & 0x0f) | version;
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
+        bytes
+    }
+
+    fn formatted(&self, bytes: &[u8; 16]) -> String {
+        match self.format {
+            Format::Hyphenated => hyphenated(bytes),
+            Format::Simple => hyphenated(bytes).replace('-', ""),
+            Format::Urn => format!("urn:uuid:{}", hyphenated(bytes)),
+        }
+    }
+}
+
+impl Provider for UuidProvider {
+    fn value(&self, index: u32) -> Value {
+        let bytes = self.bytes(index);
+        match self.binary {
+            true => Value::Uuid(bytes),
+            false => Value::String(self.formatted(&bytes)),
+        }
+    }
+    fn corrupted_value(&self, index: u32) -> Value {
+        // UUIDs of another version, or strings too short to be UUIDs
+        let mut bytes = self.bytes(index);
+        bytes[6] &= 0x0f;
+        match self.binary {
+            true => Value::Uuid(bytes),
+            false => {
+                let mut value = self.formatted(&bytes);
+                if rng::bool() {
+                    value.truncate(value.len() - 1 - rng::usize(..7));
+                }
+                Value::String(value)
+            }
+        }
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml) -> Result<Box<UuidProvider>, FakeLakeError> {
+    let column_name = get_column_name(column);
+
+    let version = match &column["version"] {
+        Yaml::BadValue | Yaml::Integer(4) => Version::V4,
+        Yaml::Integer(7) => Version::V7,
+        _ => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} param version should be 4 or 7.",
+                column_name
+            )))
+        }
+    };
+
+    let format = match &column["format"] {
+        Yaml::BadValue => Some(Format::Hyphenated),
+        Yaml::String(value) => match value.to_lowercase().as_str() {
+            "hyphenated" => Some(Format::Hyphenated),
+            "simple" => Some(Format::Simple),
+            "urn" => Some(Format::Urn),
+            _ => None,
+        },
+        _ => None,
+    };
+    let format = match format {
+        Some(value) => value,
+        None => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} param format should be one of {}.",
+                column_name,
+                AVAILABLE_FORMATS.join(", ")
+            )))
+        }
+    };
+
+    let binary = match &column["binary"] {
+        Yaml::BadValue => false,
+        Yaml::Boolean(value) => *value,
+        _ => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} param binary should be a boolean.",
+                column_name
+            )))
+        }
+    };
+
+    let start = match &column["start"] {
+        Yaml::BadValue => Some(DEFAULT_START),
+        Yaml::String(value) => Some(value.as_str()),
+        _ => None,
+    }
+    .and_then(|value| NaiveDateTime::parse_from_str(value, START_FORMAT).ok());
+    let start = match start {
+        Some(value) => value.and_utc().timestamp_millis(),
+        None => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} param start should be a datetime with the format {}.",
+                column_name, START_FORMAT
+            )))
+        }
+    };
+
+    Ok(Box::new(UuidProvider {
+        version,
+        format,
+        binary,
+        start,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hyphenated, new_from_yaml, Format, UuidProvider, Version};
+    use crate::providers::provider::{Provider, Value};
+    use crate::rng;
+
+    use regex::Regex;
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider(yaml_str: &str) -> Box<UuidProvider> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        new_from_yaml(&yaml[0]).unwrap()
+    }
+
+    fn string_value(value: Value) -> String {
+        match value {
+            Value::String(value) => value,
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_no_params_should_return_hyphenated_v4() {
+        let provider = generate_provider("name: id");
+        assert_eq!(provider.version, Version::V4);
+        assert_eq!(provider.format, Format::Hyphenated);
+        assert!(!provider.binary);
+        assert_eq!(provider.start, 1_704_067_200_000);
+    }
+
+    #[test]
+    fn given_params_should_use_them() {
+        let provider = generate_provider(
+            "name: id\nversion: 7\nformat: URN\nbinary: true\nstart: 2000-01-01 00:00:01",
+        );
+        assert_eq!(provider.version, Version::V7);
+        assert_eq!(provider.format, Format::Urn);
+        assert!(provider.binary);
+        assert_eq!(provider.start, 946_684_801_000);
+    }
+
+    #[test]
+    fn given_wrong_params_should_return_err() {
+        for params in [
+            "version: 1",
+            "version: v4",
+            "format: braced",
+            "format: 1",
+            "binary: yes please",
+            "start: 2024-01-01",
+        ] {
+            let yaml = YamlLoader::load_from_str(&format!("name: id\n{}", params)).unwrap();
+            assert!(
+                new_from_yaml(&yaml[0]).is_err(),
+                "{} should be wrong",
+                params
+            );
+        }
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_bytes_should_return_hyphenated() {
+        let bytes = [
+            0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f, 0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f,
+            0xe0, 0xc8,
+        ];
+        assert_eq!(hyphenated(&bytes), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+    }
+
+    #[test]
+    fn given_formats_should_return_uuids_of_format() {
+        for (params, pattern) in [
+            (
+                "format: hyphenated",
+                r"^[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}$",
+            ),
+            (
+                "format: simple",
+                r"^[0-9a-f]{12}4[0-9a-f]{3}[89ab][0-9a-f]{15}$",
+            ),
+            (
+                "format: urn\nversion: 7",
+                r"^urn:uuid:[0-9a-f]{8}-[0-9a-f]{4}-7[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}$",
+            ),
+        ] {
+            let re = Regex::new(pattern).unwrap();
+            let provider = generate_provider(&format!("name: id\n{}", params));
+            for i in 0..100 {
+                let value = string_value(provider.value(i));
+                assert!(re.is_match(&value), "{} does not match {}", value, pattern);
+                let value = string_value(provider.corrupted_value(i));
+                assert!(!re.is_match(&value), "{} should be invalid", value);
+            }
+        }
+    }
+
+    #[test]
+    fn given_v7_should_return_uuids_ordered_by_row() {
+        let provider = generate_provider("name: id\nversion: 7\nstart: 2024-01-01 00:00:00");
+        let values: Vec<String> = (0..100).map(|i| string_value(provider.value(i))).collect();
+        assert!(values[0].starts_with("018cc251-f400-7"));
+        assert!(values.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn given_binary_should_return_uuid_bytes() {
+        let provider = generate_provider("name: id\nbinary: true");
+        match provider.value(0) {
+            Value::Uuid(bytes) => {
+                assert_eq!(bytes[6] >> 4, 4);
+                assert_eq!(bytes[8] >> 6, 2);
+            }
+            _ => panic!("Wrong type"),
+        }
+        match provider.corrupted_value(0) {
+            Value::Uuid(bytes) => assert_eq!(bytes[6] >> 4, 0),
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    #[test]
+    fn given_same_seed_should_return_same_uuids() {
+        let provider = generate_provider("name: id");
+        let generate = || {
+            rng::isolated(|| {
+                (0..10)
+                    .map(|i| string_value(provider.value(i)))
+                    .collect::<Vec<String>>()
+            })
+        };
+        assert_eq!(generate(), generate());
+    }
+}
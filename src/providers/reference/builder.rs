@@ -0,0 +1,58 @@
+use crate::errors::FakeLakeError;
+use crate::providers::provider::{unknown_provider, Provider};
+
+use super::column;
+
+use yaml_rust::Yaml;
+
+const AVAILABLE: &[&str] = &["reference.column"];
+
+pub fn get_corresponding_provider(
+    mut provider_split: std::str::Split<'_, char>,
+    column: &Yaml,
+) -> Result<Box<dyn Provider>, FakeLakeError> {
+    match provider_split.next() {
+        Some("column") => column::new_from_yaml(column),
+        other => Err(unknown_provider("reference", other, AVAILABLE)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::get_corresponding_provider;
+    use crate::providers::provider::Value;
+    use crate::providers::reference::registry;
+
+    use yaml_rust::YamlLoader;
+
+    #[test]
+    fn given_column_should_return_provider() {
+        registry::publish("builder_parent", "id", vec![Value::Int32(1)]);
+
+        let provider_name = "column";
+        let yaml_str = format!(
+            "name: parent_id{}provider: {}{}table: builder_parent{}column: id",
+            '\n', provider_name, '\n', '\n'
+        );
+        let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+        let provider_split = provider_name.split('.');
+        match get_corresponding_provider(provider_split, column) {
+            Ok(_) => (),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn given_wrong_provider_should_return_error() {
+        let provider_name = "not_a_provider";
+        let yaml_str = format!("name: parent_id{}provider: {}", '\n', provider_name);
+        let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+        let provider_split = provider_name.split('.');
+        match get_corresponding_provider(provider_split, column) {
+            Err(_) => (),
+            _ => panic!(),
+        }
+    }
+}
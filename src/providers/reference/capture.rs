@@ -0,0 +1,105 @@
+use crate::errors::FakeLakeError;
+use crate::providers::provider::{ColumnType, Provider, Value};
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// Keeps every value handed out by the wrapped provider, so that other tables can
+/// reference the generated column afterwards. Clones share the same values.
+pub struct CapturingProvider {
+    pub provider: Box<dyn Provider>,
+    pub values: Arc<Mutex<Vec<Value>>>,
+}
+
+impl Clone for CapturingProvider {
+    fn clone(&self) -> Self {
+        CapturingProvider {
+            provider: self.provider.clone_box(),
+            values: self.values.clone(),
+        }
+    }
+}
+
+impl Provider for CapturingProvider {
+    fn value(&self, index: u32) -> Value {
+        let value = self.provider.value(index);
+        self.values.lock().unwrap().push(value.clone());
+        value
+    }
+    fn corrupted_value(&self, index: u32) -> Value {
+        self.provider.corrupted_value(index)
+    }
+    fn sample_value(&self) -> Value {
+        self.provider.sample_value()
+    }
+    fn dependencies(&self) -> Vec<String> {
+        self.provider.dependencies()
+    }
+    fn bind(&mut self, columns: &HashMap<String, ColumnType>) -> Result<(), FakeLakeError> {
+        self.provider.bind(columns)
+    }
+    fn can_be_null(&self) -> bool {
+        self.provider.can_be_null()
+    }
+    fn expected_rows(&self) -> Option<u32> {
+        self.provider.expected_rows()
+    }
+    fn max_rows(&self) -> Option<u32> {
+        self.provider.max_rows()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CapturingProvider;
+    use crate::providers::increment::integer::IncrementIntegerProvider;
+    use crate::providers::provider::{Provider, Value};
+
+    use std::sync::{Arc, Mutex};
+
+    fn generate_provider() -> CapturingProvider {
+        CapturingProvider {
+            provider: Box::new(IncrementIntegerProvider {
+                start: 0,
+                step: 1,
+                cycle_after: None,
+            }),
+            values: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    #[test]
+    fn given_values_should_capture_them_in_order() {
+        let provider = generate_provider();
+        for i in 0..5 {
+            assert_eq!(provider.value(i), Value::Int32(i as i32));
+        }
+
+        assert_eq!(
+            *provider.values.lock().unwrap(),
+            (0..5).map(Value::Int32).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn given_clone_should_share_captured_values() {
+        let provider = generate_provider();
+        let cloned = provider.clone();
+
+        provider.value(0);
+        cloned.value(1);
+
+        assert_eq!(provider.values.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn given_sample_value_should_not_capture() {
+        let provider = generate_provider();
+        match provider.sample_value() {
+            Value::Int32(_) => (),
+            _ => panic!("Wrong type"),
+        }
+
+        assert!(provider.values.lock().unwrap().is_empty());
+    }
+}
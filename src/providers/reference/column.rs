@@ -0,0 +1,323 @@
+use crate::errors::FakeLakeError;
+use crate::providers::parameters::get_column_name;
+use crate::providers::parameters::urange::URangeParameter;
+use crate::providers::provider::{Provider, Value};
+use crate::providers::utils::string::random_alphanumeric;
+use crate::rng;
+
+use super::registry;
+
+use std::sync::Arc;
+use yaml_rust::Yaml;
+
+const DEFAULT_CARDINALITY: u32 = 1;
+
+#[derive(Clone)]
+pub struct ReferenceColumnProvider {
+    pub values: Arc<Vec<Value>>,
+    /// Index in values of each row, when a cardinality is asked
+    pub sequence: Option<Arc<Vec<u32>>>,
+}
+
+impl ReferenceColumnProvider {
+    /// Finite float between the min and the max of the referenced floats, widened by
+    /// their spread on each side
+    fn float_around_values(&self) -> f64 {
+        let (min, max) = self
+            .values
+            .iter()
+            .filter_map(|value| match value {
+                Value::Float64(value) if value.is_finite() => Some(*value),
+                _ => None,
+            })
+            .fold((f64::MAX, f64::MIN), |(min, max), value| {
+                (min.min(value), max.max(value))
+            });
+        let (min, max) = match max >= min {
+            true => (min, max),
+            // no finite float referenced
+            false => (0.0, 0.0),
+        };
+        let margin = (max - min).max(1.0);
+        let low = (min - margin).max(f64::MIN / 2.0);
+        let high = (max + margin).min(f64::MAX / 2.0);
+        rng::f64_range(low..high)
+    }
+}
+
+impl Provider for ReferenceColumnProvider {
+    fn value(&self, index: u32) -> Value {
+        let position = match &self.sequence {
+            Some(sequence) => sequence[index as usize % sequence.len()] as usize,
+            None => rng::usize(..self.values.len()),
+        };
+        self.values[position].clone()
+    }
+    fn corrupted_value(&self, index: u32) -> Value {
+        // return a value of the same type, not taken from the referenced column
+        loop {
+            let value = match &self.values[0] {
+                Value::Int32(_) => Value::Int32(rng::i32(i32::MIN..i32::MAX)),
+                Value::Float64(_) => Value::Float64(self.float_around_values()),
+                Value::String(_) => Value::String(random_alphanumeric(10)),
+                _ => return self.value(index),
+            };
+            if !self.values.contains(&value) {
+                return value;
+            }
+        }
+    }
+    fn expected_rows(&self) -> Option<u32> {
+        self.sequence.as_ref().map(|sequence| sequence.len() as u32)
+    }
+}
+
+/// Repeat each referenced value between min (inclusive) and max (exclusive) times, then
+/// shuffle the rows. The draws are derived from the root seed of the table so they are reproducible.
+fn build_sequence(values_len: usize, min: u32, max: u32, root_seed: u64) -> Vec<u32> {
+    let mut local_rng = fastrand::Rng::with_seed(rng::derive_seed(
+        root_seed,
+        rng::DOMAIN_REFERENCE,
+        &[values_len as u64, min as u64, max as u64],
+    ));
+
+    let mut sequence = Vec::new();
+    for position in 0..values_len as u32 {
+        let repeat = local_rng.u32(min..max);
+        sequence.extend(std::iter::repeat_n(position, repeat as usize));
+    }
+    local_rng.shuffle(&mut sequence);
+    sequence
+}
+
+pub fn new_from_yaml(column: &Yaml) -> Result<Box<dyn Provider>, FakeLakeError> {
+    let column_name = get_column_name(column);
+
+    let (table, referenced) = match (column["table"].as_str(), column["column"].as_str()) {
+        (Some(table), Some(referenced)) => (table, referenced),
+        _ => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} should specify the referenced table and column.",
+                column_name
+            )))
+        }
+    };
+
+    let values = match registry::get(table, referenced) {
+        Some(values) if !values.is_empty() => values,
+        _ => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} references {}.{} which has not been generated. The table {} should be generated first and the column should have values.",
+                column_name, table, referenced, table
+            )))
+        }
+    };
+
+    let sequence = match column["cardinality"] {
+        Yaml::BadValue => None,
+        _ => {
+            let cardinality = URangeParameter::new(column, "cardinality", DEFAULT_CARDINALITY);
+            let root_seed = column["table_seed"].as_i64().unwrap_or_default() as u64;
+            let sequence =
+                build_sequence(values.len(), cardinality.min, cardinality.max, root_seed);
+            if sequence.is_empty() {
+                return Err(FakeLakeError::BadYAMLFormat(format!(
+                    "Column {} cardinality does not produce any row.",
+                    column_name
+                )));
+            }
+            Some(Arc::new(sequence))
+        }
+    };
+
+    Ok(Box::new(ReferenceColumnProvider { values, sequence }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_sequence, new_from_yaml, ReferenceColumnProvider};
+    use crate::providers::provider::{Provider, Value};
+    use crate::providers::reference::registry;
+
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use yaml_rust::YamlLoader;
+
+    fn parent_values() -> Vec<Value> {
+        (0..10).map(Value::Int32).collect()
+    }
+
+    fn generate_provider(params: &str) -> Box<dyn Provider> {
+        let yaml_str = format!("name: parent_id{}", params);
+        let yaml = YamlLoader::load_from_str(yaml_str.as_str()).unwrap();
+        match new_from_yaml(&yaml[0]) {
+            Ok(provider) => provider,
+            Err(_) => panic!("Provider should be created"),
+        }
+    }
+
+    fn expecting_err(params: &str) {
+        let yaml_str = format!("name: parent_id{}", params);
+        let yaml = YamlLoader::load_from_str(yaml_str.as_str()).unwrap();
+        if new_from_yaml(&yaml[0]).is_ok() {
+            panic!("Provider should not be created")
+        }
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_no_table_or_column_should_return_err() {
+        registry::publish("column_missing_params", "id", parent_values());
+        expecting_err("");
+        expecting_err("\ntable: column_missing_params");
+        expecting_err("\ncolumn: id");
+    }
+
+    #[test]
+    fn given_not_generated_table_should_return_err() {
+        expecting_err("\ntable: column_never_generated\ncolumn: id");
+    }
+
+    #[test]
+    fn given_empty_referenced_column_should_return_err() {
+        registry::publish("column_empty", "id", Vec::new());
+        expecting_err("\ntable: column_empty\ncolumn: id");
+    }
+
+    #[test]
+    fn given_zero_cardinality_should_return_err() {
+        registry::publish("column_zero_cardinality", "id", parent_values());
+        expecting_err("\ntable: column_zero_cardinality\ncolumn: id\ncardinality: 0");
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_referenced_column_should_return_only_existing_values() {
+        registry::publish("column_existing_values", "id", parent_values());
+        let provider = generate_provider("\ntable: column_existing_values\ncolumn: id");
+
+        for i in 0..100 {
+            assert!(parent_values().contains(&provider.value(i)));
+        }
+    }
+
+    #[test]
+    fn given_cardinality_should_repeat_each_value_within_range() {
+        registry::publish("column_cardinality", "id", parent_values());
+        let provider =
+            generate_provider("\ntable: column_cardinality\ncolumn: id\ncardinality: 1..5");
+
+        let sequence_len = build_sequence(10, 1, 5, 0).len() as u32;
+        assert_eq!(provider.expected_rows(), Some(sequence_len));
+        let mut counts: HashMap<i32, u32> = HashMap::new();
+        for i in 0..sequence_len {
+            match provider.value(i) {
+                Value::Int32(value) => *counts.entry(value).or_default() += 1,
+                _ => panic!("Wrong type"),
+            }
+        }
+
+        assert_eq!(counts.len(), 10);
+        assert!(counts.values().all(|count| (1..5).contains(count)));
+    }
+
+    #[test]
+    fn given_same_inputs_should_build_same_sequence() {
+        assert_eq!(build_sequence(100, 1, 5, 42), build_sequence(100, 1, 5, 42));
+    }
+
+    #[test]
+    fn given_other_table_seed_should_build_other_sequence() {
+        assert_ne!(build_sequence(100, 1, 5, 42), build_sequence(100, 1, 5, 43));
+
+        registry::publish("column_table_seed", "id", parent_values());
+        let params = "\ntable: column_table_seed\ncolumn: id\ncardinality: 1..5";
+        let provider = generate_provider(&format!("{}\ntable_seed: 42", params));
+        let values: Vec<Value> = (0..20).map(|i| provider.value(i)).collect();
+        let sequence = build_sequence(10, 1, 5, 42);
+        for (value, position) in values.iter().zip(sequence.iter()) {
+            assert_eq!(*value, Value::Int32(*position as i32));
+        }
+    }
+
+    #[test]
+    fn given_no_cardinality_should_not_expect_rows() {
+        registry::publish("column_no_cardinality", "id", parent_values());
+        let provider = generate_provider("\ntable: column_no_cardinality\ncolumn: id");
+        assert_eq!(provider.expected_rows(), None);
+    }
+
+    #[test]
+    fn given_index_after_sequence_should_wrap_around() {
+        let provider = ReferenceColumnProvider {
+            values: Arc::new(parent_values()),
+            sequence: Some(Arc::new(vec![3, 1, 2])),
+        };
+
+        assert_eq!(provider.value(0), Value::Int32(3));
+        assert_eq!(provider.value(4), Value::Int32(1));
+    }
+
+    #[test]
+    fn given_int_values_should_corrupted_return_orphan_int() {
+        let provider = ReferenceColumnProvider {
+            values: Arc::new(parent_values()),
+            sequence: None,
+        };
+
+        let mut count_orphans = 0;
+        for i in 0..100 {
+            match provider.corrupted_value(i) {
+                Value::Int32(value) if !(0..10).contains(&value) => count_orphans += 1,
+                Value::Int32(_) => (),
+                _ => panic!("Wrong type"),
+            }
+        }
+        assert!(count_orphans >= 99);
+    }
+
+    #[test]
+    fn given_float_values_should_corrupted_return_finite_orphan_float() {
+        let values: Vec<Value> = (0..10).map(|i| Value::Float64(i as f64 / 2.0)).collect();
+        let provider = ReferenceColumnProvider {
+            values: Arc::new(values.clone()),
+            sequence: None,
+        };
+
+        for i in 0..1000 {
+            let value = provider.corrupted_value(i);
+            match value {
+                Value::Float64(float) => assert!(float.is_finite(), "{}", float),
+                _ => panic!("Wrong type"),
+            }
+            assert!(!values.contains(&value));
+        }
+
+        // a single value, or values at the limits of floats, still give finite orphans
+        for values in [
+            vec![Value::Float64(1.5)],
+            vec![Value::Float64(f64::MIN), Value::Float64(f64::MAX)],
+        ] {
+            let provider = ReferenceColumnProvider {
+                values: Arc::new(values.clone()),
+                sequence: None,
+            };
+            let value = provider.corrupted_value(0);
+            assert!(matches!(value, Value::Float64(float) if float.is_finite()));
+            assert!(!values.contains(&value));
+        }
+    }
+
+    #[test]
+    fn given_string_values_should_corrupted_return_string() {
+        let provider = ReferenceColumnProvider {
+            values: Arc::new(vec![Value::String("a".to_string())]),
+            sequence: None,
+        };
+
+        match provider.corrupted_value(0) {
+            Value::String(value) => assert_ne!(value, "a"),
+            _ => panic!("Wrong type"),
+        }
+    }
+}
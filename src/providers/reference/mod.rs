@@ -0,0 +1,5 @@
+pub mod builder;
+
+pub mod capture;
+pub mod column;
+pub mod registry;
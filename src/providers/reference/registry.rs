@@ -0,0 +1,128 @@
+use crate::providers::provider::Value;
+
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use yaml_rust::Yaml;
+
+type TableValues = HashMap<String, Arc<Vec<Value>>>;
+
+/// Values generated for the referenced columns, grouped by table name
+static TABLES: Lazy<Mutex<HashMap<String, TableValues>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+pub fn publish(table: &str, column: &str, values: Vec<Value>) {
+    TABLES
+        .lock()
+        .unwrap()
+        .entry(table.to_string())
+        .or_default()
+        .insert(column.to_string(), Arc::new(values));
+}
+
+pub fn get(table: &str, column: &str) -> Option<Arc<Vec<Value>>> {
+    TABLES
+        .lock()
+        .unwrap()
+        .get(table)
+        .and_then(|columns| columns.get(column))
+        .cloned()
+}
+
+/// List the (table, column) pairs targeted by the reference providers of a table definition,
+/// so that the values of those columns can be kept while their table is generated.
+pub fn referenced_columns(definition: &Yaml) -> Vec<(String, String)> {
+    let yaml_columns = match definition["columns"].as_vec() {
+        Some(value) => value,
+        None => return Vec::new(),
+    };
+
+    yaml_columns
+        .iter()
+        .filter(|column| is_reference(column))
+        .filter_map(
+            |column| match (column["table"].as_str(), column["column"].as_str()) {
+                (Some(table), Some(name)) => Some((table.to_string(), name.to_string())),
+                _ => None,
+            },
+        )
+        .collect()
+}
+
+fn is_reference(column: &Yaml) -> bool {
+    match column["provider"].as_str() {
+        Some(provider) => provider.to_lowercase().starts_with("reference."),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{get, publish, referenced_columns};
+    use crate::providers::provider::Value;
+
+    use yaml_rust::YamlLoader;
+
+    fn referenced_columns_from_str(file_content: &str) -> Vec<(String, String)> {
+        match YamlLoader::load_from_str(file_content).unwrap().first() {
+            Some(definition) => referenced_columns(definition),
+            None => Vec::new(),
+        }
+    }
+
+    #[test]
+    fn given_published_values_should_get_them() {
+        publish(
+            "registry_table",
+            "id",
+            vec![Value::Int32(1), Value::Int32(2)],
+        );
+
+        let values = get("registry_table", "id").unwrap();
+        assert_eq!(*values, vec![Value::Int32(1), Value::Int32(2)]);
+    }
+
+    #[test]
+    fn given_unknown_table_or_column_should_get_none() {
+        publish("registry_known_table", "id", vec![Value::Int32(1)]);
+
+        assert!(get("registry_unknown_table", "id").is_none());
+        assert!(get("registry_known_table", "unknown_column").is_none());
+    }
+
+    #[test]
+    fn given_config_with_references_should_list_referenced_columns() {
+        let file_content = "
+        columns:
+            - name: id
+              provider: Increment.integer
+            - name: customer_id
+              provider: Reference.column
+              table: customers
+              column: id
+            - name: product_code
+              provider: reference.column
+              table: products
+              column: code
+        ";
+
+        assert_eq!(
+            referenced_columns_from_str(file_content),
+            vec![
+                ("customers".to_string(), "id".to_string()),
+                ("products".to_string(), "code".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn given_config_without_references_should_list_nothing() {
+        let values = [
+            "",
+            "something: else",
+            "columns:\n  - name: id\n    provider: Increment.integer",
+        ];
+        for value in values {
+            assert!(referenced_columns_from_str(value).is_empty());
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod builder;
+
+#[allow(clippy::module_inception)]
+pub mod template;
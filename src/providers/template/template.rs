@@ -0,0 +1,410 @@
+use crate::errors::FakeLakeError;
+use crate::providers::expression::evaluation::to_string;
+use crate::providers::parameters::get_column_name;
+use crate::providers::provider::{ColumnType, Provider, ProviderBuilder, Value};
+use crate::providers::utils::string::random_alphanumeric;
+use crate::rng;
+
+use std::collections::HashMap;
+use yaml_rust::Yaml;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Case {
+    Upper,
+    Lower,
+    Title,
+}
+
+/// How a value is written in the template: `upper`, `lower` or `title` for the case,
+/// a width like `8` to pad with spaces and `08` to pad with zeros
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Modifiers {
+    pub case: Option<Case>,
+    pub width: usize,
+    pub zeros: bool,
+}
+
+impl Modifiers {
+    fn parse(modifiers: &str) -> Result<Modifiers, String> {
+        let mut parsed = Modifiers::default();
+        for modifier in modifiers.split(',').map(str::trim) {
+            match modifier {
+                "upper" => parsed.case = Some(Case::Upper),
+                "lower" => parsed.case = Some(Case::Lower),
+                "title" => parsed.case = Some(Case::Title),
+                width => match width.parse::<usize>() {
+                    Ok(value) => {
+                        parsed.width = value;
+                        parsed.zeros = width.starts_with('0');
+                    }
+                    Err(_) => return Err(format!("modifier {} is unknown", modifier)),
+                },
+            }
+        }
+        Ok(parsed)
+    }
+
+    fn apply(&self, value: String) -> String {
+        let value = match self.case {
+            Some(Case::Upper) => value.to_uppercase(),
+            Some(Case::Lower) => value.to_lowercase(),
+            Some(Case::Title) => value
+                .split(' ')
+                .map(|word| {
+                    let mut chars = word.chars();
+                    match chars.next() {
+                        Some(first) => {
+                            first.to_uppercase().collect::<String>()
+                                + &chars.as_str().to_lowercase()
+                        }
+                        None => String::new(),
+                    }
+                })
+                .collect::<Vec<String>>()
+                .join(" "),
+            None => value,
+        };
+
+        let missing = self.width.saturating_sub(value.chars().count());
+        match (self.zeros, value.strip_prefix('-')) {
+            // zeros go after the sign of negative numbers
+            (true, Some(number)) => format!("-{}{}", "0".repeat(missing), number),
+            (true, None) => format!("{}{}", "0".repeat(missing), value),
+            (false, _) => format!("{}{}", " ".repeat(missing), value),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Part {
+    Text(String),
+    /// Provider name and modifiers of a reference like `{increment.integer:06}`
+    Reference(String, Modifiers),
+}
+
+/// Parts of a template, `{{` and `}}` being braces of the text
+pub fn parse_template(template: &str) -> Result<Vec<Part>, String> {
+    let mut parts = Vec::new();
+    let mut text = String::new();
+    let mut chars = template.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                text.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                text.push('}');
+            }
+            '{' => {
+                let mut reference = String::new();
+                let mut closed = false;
+                for c in chars.by_ref() {
+                    if c == '}' {
+                        closed = true;
+                        break;
+                    }
+                    reference.push(c);
+                }
+                if !closed {
+                    return Err("a { is not closed".to_string());
+                }
+                let (provider, modifiers) = match reference.split_once(':') {
+                    Some((provider, modifiers)) => (provider, Modifiers::parse(modifiers)?),
+                    None => (reference.as_str(), Modifiers::default()),
+                };
+                let provider = provider.trim();
+                if provider.is_empty() {
+                    return Err("a reference has no provider".to_string());
+                }
+                if !text.is_empty() {
+                    parts.push(Part::Text(std::mem::take(&mut text)));
+                }
+                parts.push(Part::Reference(provider.to_string(), modifiers));
+            }
+            '}' => return Err("a } is not opened".to_string()),
+            c => text.push(c),
+        }
+    }
+    if !text.is_empty() {
+        parts.push(Part::Text(text));
+    }
+    Ok(parts)
+}
+
+pub struct Reference {
+    pub provider: Box<dyn Provider>,
+    pub modifiers: Modifiers,
+}
+
+impl Clone for Reference {
+    fn clone(&self) -> Self {
+        Reference {
+            provider: self.provider.clone_box(),
+            modifiers: self.modifiers.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub enum TemplatePart {
+    Text(String),
+    Reference(Reference),
+}
+
+#[derive(Clone)]
+pub struct TemplateProvider {
+    pub parts: Vec<TemplatePart>,
+}
+
+impl TemplateProvider {
+    /// Template filled with the values of the references, the one at position corrupted
+    /// being a corrupted value
+    fn fill(&self, index: u32, corrupted: Option<usize>) -> String {
+        let mut position = 0;
+        self.parts
+            .iter()
+            .map(|part| match part {
+                TemplatePart::Text(text) => text.clone(),
+                TemplatePart::Reference(reference) => {
+                    let value = match corrupted == Some(position) {
+                        true => reference.provider.corrupted_value(index),
+                        false => reference.provider.value(index),
+                    };
+                    position += 1;
+                    reference.modifiers.apply(to_string(&value))
+                }
+            })
+            .collect()
+    }
+
+    fn references(&self) -> impl Iterator<Item = &Reference> {
+        self.parts.iter().filter_map(|part| match part {
+            TemplatePart::Reference(reference) => Some(reference),
+            TemplatePart::Text(_) => None,
+        })
+    }
+}
+
+impl Provider for TemplateProvider {
+    fn value(&self, index: u32) -> Value {
+        Value::String(self.fill(index, None))
+    }
+    fn corrupted_value(&self, index: u32) -> Value {
+        // one of the references gives a corrupted value
+        match self.references().count() {
+            0 => Value::String(random_alphanumeric(
+                self.fill(index, None).chars().count() as u32
+            )),
+            count => Value::String(self.fill(index, Some(rng::usize(..count)))),
+        }
+    }
+    fn dependencies(&self) -> Vec<String> {
+        let mut dependencies: Vec<String> = Vec::new();
+        for column in self
+            .references()
+            .flat_map(|reference| reference.provider.dependencies())
+        {
+            if !dependencies.contains(&column) {
+                dependencies.push(column);
+            }
+        }
+        dependencies
+    }
+    fn bind(&mut self, columns: &HashMap<String, ColumnType>) -> Result<(), FakeLakeError> {
+        for part in self.parts.iter_mut() {
+            if let TemplatePart::Reference(reference) = part {
+                reference.provider.bind(columns)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml) -> Result<Box<dyn Provider>, FakeLakeError> {
+    let column_name = get_column_name(column);
+
+    let template = match column["template"].as_str() {
+        Some(value) => value,
+        None => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} should specify a template string.",
+                column_name
+            )))
+        }
+    };
+    let parts = match parse_template(template) {
+        Ok(value) => value,
+        Err(e) => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Column {} has a wrong template: {}",
+                column_name, e
+            )))
+        }
+    };
+
+    let mut template_parts = Vec::new();
+    for part in parts {
+        template_parts.push(match part {
+            Part::Text(text) => TemplatePart::Text(text),
+            Part::Reference(provider, _) if provider.to_lowercase().starts_with("template") => {
+                return Err(FakeLakeError::BadYAMLFormat(format!(
+                    "Column {} template can't reference another template.",
+                    column_name
+                )))
+            }
+            // the providers of the template read the parameters of the column
+            Part::Reference(provider, modifiers) => TemplatePart::Reference(Reference {
+                provider: ProviderBuilder::get_corresponding_provider(&provider, column)?,
+                modifiers,
+            }),
+        });
+    }
+
+    Ok(Box::new(TemplateProvider {
+        parts: template_parts,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_from_yaml, parse_template, Case, Modifiers, Part};
+    use crate::providers::provider::{Provider, Value};
+    use crate::row;
+
+    use regex::Regex;
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider(yaml_str: &str) -> Box<dyn Provider> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        new_from_yaml(&yaml[0]).unwrap()
+    }
+
+    fn string_value(value: Value) -> String {
+        match value {
+            Value::String(value) => value,
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_template_should_return_parts() {
+        assert_eq!(
+            parse_template("INV-{increment.integer:06}/{{x}}").unwrap(),
+            vec![
+                Part::Text("INV-".to_string()),
+                Part::Reference(
+                    "increment.integer".to_string(),
+                    Modifiers {
+                        case: None,
+                        width: 6,
+                        zeros: true
+                    }
+                ),
+                Part::Text("/{x}".to_string()),
+            ]
+        );
+        assert_eq!(
+            parse_template("{person.lname:upper, 10}").unwrap(),
+            vec![Part::Reference(
+                "person.lname".to_string(),
+                Modifiers {
+                    case: Some(Case::Upper),
+                    width: 10,
+                    zeros: false
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn given_wrong_template_should_return_err() {
+        for template in [
+            "{person.fname",
+            "person.fname}",
+            "{}",
+            "{:upper}",
+            "{a:bold}",
+        ] {
+            assert!(
+                parse_template(template).is_err(),
+                "{} should be wrong",
+                template
+            );
+        }
+
+        for params in [
+            "",
+            "template: [a]",
+            "template: '{person.unknown}'",
+            "template: '{template}'",
+        ] {
+            let yaml = YamlLoader::load_from_str(&format!("name: a\n{}", params)).unwrap();
+            assert!(
+                new_from_yaml(&yaml[0]).is_err(),
+                "{} should be wrong",
+                params
+            );
+        }
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_modifiers_should_format_values() {
+        let modifiers = |value: &str| Modifiers::parse(value).unwrap();
+        assert_eq!(modifiers("05").apply("42".to_string()), "00042");
+        assert_eq!(modifiers("05").apply("-42".to_string()), "-0042");
+        assert_eq!(modifiers("5").apply("42".to_string()), "   42");
+        assert_eq!(modifiers("2").apply("12345".to_string()), "12345");
+        assert_eq!(modifiers("lower").apply("DUPONT".to_string()), "dupont");
+        assert_eq!(
+            modifiers("title").apply("jean-luc DE la fontaine".to_string()),
+            "Jean-luc De La Fontaine"
+        );
+    }
+
+    #[test]
+    fn given_template_should_fill_it_with_providers() {
+        let provider =
+            generate_provider("name: invoice\ntemplate: 'INV-{increment.integer:06}'\nstart: 42");
+        assert_eq!(string_value(provider.value(0)), "INV-000042");
+        assert_eq!(string_value(provider.value(3)), "INV-000045");
+
+        let provider = generate_provider(
+            "name: email\ntemplate: '{person.fname:lower}.{person.lname:lower}@{internet.domain}'\nlocale: en_US",
+        );
+        let re = Regex::new(r"^[a-z' -]+\.[a-z' -]+@[a-z-]+\.[a-z.]+$").unwrap();
+        for i in 0..50 {
+            let value = string_value(provider.value(i));
+            assert!(re.is_match(&value), "{} is not an email", value);
+        }
+    }
+
+    #[test]
+    fn given_corrupted_should_corrupt_one_reference() {
+        let provider =
+            generate_provider("name: a\ntemplate: 'A-{random.string.pattern}'\nmask: '###'");
+        let re = Regex::new(r"^A-\d{3}$").unwrap();
+        for i in 0..50 {
+            assert!(re.is_match(&string_value(provider.value(i))));
+            assert!(!re.is_match(&string_value(provider.corrupted_value(i))));
+        }
+
+        let provider = generate_provider("name: a\ntemplate: 'constant'");
+        assert_eq!(string_value(provider.corrupted_value(0)).len(), 8);
+    }
+
+    #[test]
+    fn given_references_reading_columns_should_depend_on_them() {
+        let provider = generate_provider(
+            "name: a\ntemplate: '{person.fname:upper}'\nlocale: fr_FR\ngender_column: g",
+        );
+        assert_eq!(provider.dependencies(), vec!["g"]);
+
+        row::set("g", Some(Value::String("F".to_string())));
+        let value = string_value(provider.value(0));
+        assert_eq!(value, value.to_uppercase());
+    }
+}
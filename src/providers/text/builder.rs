@@ -0,0 +1,55 @@
+use crate::errors::FakeLakeError;
+use crate::providers::provider::{unknown_provider, Provider};
+
+use super::text::{self, Kind};
+
+use yaml_rust::Yaml;
+
+const AVAILABLE: &[&str] = &["text.lorem", "text.paragraph", "text.sentence", "text.word"];
+
+pub fn get_corresponding_provider(
+    mut provider_split: std::str::Split<'_, char>,
+    column: &Yaml,
+) -> Result<Box<dyn Provider>, FakeLakeError> {
+    match provider_split.next() {
+        Some("lorem") => Ok(text::new_from_yaml(column, Kind::Lorem)),
+        Some("paragraph") => Ok(text::new_from_yaml(column, Kind::Paragraph)),
+        Some("sentence") => Ok(text::new_from_yaml(column, Kind::Sentence)),
+        Some("word") => Ok(text::word_from_yaml(column)),
+        other => Err(unknown_provider("text", other, AVAILABLE)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::get_corresponding_provider;
+
+    use yaml_rust::YamlLoader;
+
+    #[test]
+    fn given_text_providers_should_return_provider() {
+        for provider_name in ["lorem", "paragraph", "sentence", "word"] {
+            let yaml_str = format!("name: comment{}provider: {}", '\n', provider_name);
+            let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+            let provider_split = provider_name.split('.');
+            match get_corresponding_provider(provider_split, column) {
+                Ok(_) => (),
+                _ => panic!("{} should be a provider", provider_name),
+            }
+        }
+    }
+
+    #[test]
+    fn given_wrong_provider_should_return_error() {
+        let provider_name = "not_a_provider";
+        let yaml_str = format!("name: comment{}provider: {}", '\n', provider_name);
+        let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+        let provider_split = provider_name.split('.');
+        match get_corresponding_provider(provider_split, column) {
+            Err(_) => (),
+            _ => panic!(),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod builder;
+
+#[allow(clippy::module_inception)]
+pub mod text;
+pub mod words;
@@ -0,0 +1,379 @@
+use crate::providers::parameters::locale::{Locale, LocaleParameter};
+use crate::providers::parameters::urange::URangeParameter;
+use crate::providers::provider::{Provider, Value};
+use crate::providers::utils::string::random_characters;
+use crate::rng;
+
+use super::words;
+
+use yaml_rust::Yaml;
+
+/// Words of a sentence and sentences of a paragraph, the max being excluded
+const DEFAULT_SENTENCE_WORDS: (u32, u32) = (4, 13);
+const DEFAULT_PARAGRAPH_SENTENCES: (u32, u32) = (3, 7);
+const DEFAULT_LOREM_WORDS: (u32, u32) = (10, 50);
+/// Number of characters used when the length parameter is wrong
+const DEFAULT_LENGTH: u32 = 100;
+/// One word out of COMMA_FREQUENCY is followed by a comma
+const COMMA_FREQUENCY: u32 = 8;
+
+/// Words the text is made of
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Dictionary {
+    Locale(Locale),
+    Lorem,
+}
+
+/// Separators of the words, the clauses and the sentences of a text
+struct Punctuation {
+    space: &'static str,
+    comma: &'static str,
+    period: &'static str,
+}
+
+impl Dictionary {
+    fn words(&self) -> &'static [String] {
+        match self {
+            Dictionary::Locale(locale) => words::words(*locale),
+            Dictionary::Lorem => words::lorem(),
+        }
+    }
+
+    fn word(&self) -> &'static str {
+        let words = self.words();
+        &words[rng::usize(..words.len())]
+    }
+
+    fn punctuation(&self) -> Punctuation {
+        match self {
+            Dictionary::Locale(Locale::JaJP) => Punctuation {
+                space: "",
+                comma: "、",
+                period: "。",
+            },
+            Dictionary::Locale(Locale::ZhCN) => Punctuation {
+                space: "",
+                comma: "，",
+                period: "。",
+            },
+            _ => Punctuation {
+                space: " ",
+                comma: ",",
+                period: ".",
+            },
+        }
+    }
+
+    /// Sentence of the number of words, starting with an uppercase letter
+    fn sentence(&self, words: u32) -> String {
+        let punctuation = self.punctuation();
+        let mut sentence = String::new();
+        for i in 0..words.max(1) {
+            if i > 0 {
+                if i + 1 < words && rng::u32(0..COMMA_FREQUENCY) == 0 {
+                    sentence.push_str(punctuation.comma);
+                }
+                sentence.push_str(punctuation.space);
+            }
+            sentence.push_str(self.word());
+        }
+        sentence.push_str(punctuation.period);
+
+        let mut chars = sentence.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => sentence,
+        }
+    }
+
+    /// Sentences of a random number of words until the number of words
+    fn sentences_of_words(&self, words: u32) -> Vec<String> {
+        let mut sentences = Vec::new();
+        let mut remaining = words;
+        while remaining > 0 {
+            let (min, max) = DEFAULT_SENTENCE_WORDS;
+            let words = rng::u32(min..max).min(remaining);
+            sentences.push(self.sentence(words));
+            remaining -= words;
+        }
+        sentences
+    }
+
+    fn join(&self, sentences: Vec<String>) -> String {
+        sentences.join(self.punctuation().space)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Kind {
+    /// Words of a sentence
+    Sentence,
+    /// Sentences of a paragraph
+    Paragraph,
+    /// Words of a lorem ipsum text
+    Lorem,
+}
+
+#[derive(Clone)]
+pub struct WordProvider {
+    pub dictionary: Dictionary,
+}
+
+impl Provider for WordProvider {
+    fn value(&self, _: u32) -> Value {
+        Value::String(self.dictionary.word().to_string())
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        Value::String(random_characters(
+            self.dictionary.word().chars().count() as u32
+        ))
+    }
+}
+
+#[derive(Clone)]
+pub struct TextProvider {
+    pub kind: Kind,
+    pub dictionary: Dictionary,
+    /// Range of words or sentences, depending on the kind, the max being excluded
+    pub count: (u32, u32),
+    /// Range of the number of characters, used instead of the count when it is given
+    pub length: Option<(u32, u32)>,
+}
+
+impl TextProvider {
+    fn text(&self, count: u32) -> String {
+        match self.kind {
+            Kind::Sentence => self.dictionary.sentence(count),
+            Kind::Paragraph => {
+                let (min, max) = DEFAULT_SENTENCE_WORDS;
+                self.dictionary.join(
+                    (0..count)
+                        .map(|_| self.dictionary.sentence(rng::u32(min..max)))
+                        .collect(),
+                )
+            }
+            Kind::Lorem => self
+                .dictionary
+                .join(self.dictionary.sentences_of_words(count)),
+        }
+    }
+
+    /// Text cut at the number of characters
+    fn text_of_length(&self, length: u32) -> String {
+        let mut text = String::new();
+        while text.chars().count() < length as usize {
+            if !text.is_empty() {
+                text.push_str(self.dictionary.punctuation().space);
+            }
+            text.push_str(&self.text(self.count.0.max(1)));
+        }
+        let mut text: String = text.chars().take(length as usize).collect();
+        // the text doesn't end with a space
+        if text.ends_with(' ') {
+            text.pop();
+            text.push('.');
+        }
+        text
+    }
+}
+
+impl Provider for TextProvider {
+    fn value(&self, _: u32) -> Value {
+        let text = match self.length {
+            Some((min, max)) => self.text_of_length(rng::u32(min..max)),
+            None => self.text(rng::u32(self.count.0..self.count.1)),
+        };
+        Value::String(text)
+    }
+    fn corrupted_value(&self, index: u32) -> Value {
+        // random characters instead of words
+        match self.value(index) {
+            Value::String(value) => Value::String(random_characters(value.chars().count() as u32)),
+            _ => panic!("Texts should be strings"),
+        }
+    }
+}
+
+pub fn word_from_yaml(column: &Yaml) -> Box<WordProvider> {
+    Box::new(WordProvider {
+        dictionary: Dictionary::Locale(LocaleParameter::new(column).value),
+    })
+}
+
+pub fn new_from_yaml(column: &Yaml, kind: Kind) -> Box<TextProvider> {
+    let (dictionary, count) = match kind {
+        Kind::Sentence => (
+            Dictionary::Locale(LocaleParameter::new(column).value),
+            URangeParameter::with_default_range(column, "words", DEFAULT_SENTENCE_WORDS).range(),
+        ),
+        Kind::Paragraph => (
+            Dictionary::Locale(LocaleParameter::new(column).value),
+            URangeParameter::with_default_range(column, "sentences", DEFAULT_PARAGRAPH_SENTENCES)
+                .range(),
+        ),
+        Kind::Lorem => (
+            Dictionary::Lorem,
+            URangeParameter::with_default_range(column, "words", DEFAULT_LOREM_WORDS).range(),
+        ),
+    };
+    let length = match &column["length"] {
+        Yaml::BadValue => None,
+        _ => Some(
+            URangeParameter::with_default_range(
+                column,
+                "length",
+                (DEFAULT_LENGTH, DEFAULT_LENGTH + 1),
+            )
+            .range(),
+        ),
+    };
+
+    Box::new(TextProvider {
+        kind,
+        dictionary,
+        count,
+        length,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        new_from_yaml, word_from_yaml, Dictionary, Kind, TextProvider, DEFAULT_LOREM_WORDS,
+        DEFAULT_PARAGRAPH_SENTENCES, DEFAULT_SENTENCE_WORDS,
+    };
+    use crate::providers::parameters::locale::Locale;
+    use crate::providers::provider::{Provider, Value};
+    use crate::providers::text::words;
+
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider(yaml_str: &str, kind: Kind) -> Box<TextProvider> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        new_from_yaml(&yaml[0], kind)
+    }
+
+    fn string_value(value: Value) -> String {
+        match value {
+            Value::String(value) => value,
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    fn words_of(text: &str) -> Vec<String> {
+        text.split(' ')
+            .map(|word| word.trim_end_matches([',', '.']).to_lowercase())
+            .collect()
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_no_params_should_use_defaults() {
+        let provider = generate_provider("name: a\nlocale: de_DE", Kind::Sentence);
+        assert_eq!(provider.dictionary, Dictionary::Locale(Locale::DeDE));
+        assert_eq!(provider.count, DEFAULT_SENTENCE_WORDS);
+        assert_eq!(provider.length, None);
+
+        let provider = generate_provider("name: a", Kind::Paragraph);
+        assert_eq!(provider.count, DEFAULT_PARAGRAPH_SENTENCES);
+
+        let provider = generate_provider("name: a\nlocale: de_DE", Kind::Lorem);
+        assert_eq!(provider.dictionary, Dictionary::Lorem);
+        assert_eq!(provider.count, DEFAULT_LOREM_WORDS);
+    }
+
+    #[test]
+    fn given_params_should_use_them() {
+        let provider = generate_provider("name: a\nwords: 2..5\nlength: 100..200", Kind::Sentence);
+        assert_eq!(provider.count, (2, 5));
+        assert_eq!(provider.length, Some((100, 200)));
+
+        let provider = generate_provider("name: a\nsentences: 2", Kind::Paragraph);
+        assert_eq!(provider.count, (2, 3));
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_locale_should_return_word_of_dictionary() {
+        let yaml = YamlLoader::load_from_str("name: a\nlocale: it_IT").unwrap();
+        let provider = word_from_yaml(&yaml[0]);
+        for i in 0..50 {
+            let value = string_value(provider.value(i));
+            assert!(words::words(Locale::ItIT).contains(&value));
+        }
+    }
+
+    #[test]
+    fn given_words_should_return_sentences_of_these_words() {
+        let provider = generate_provider("name: a\nlocale: en_US\nwords: 3..6", Kind::Sentence);
+        let dictionary = words::words(Locale::EnUS);
+        for i in 0..50 {
+            let value = string_value(provider.value(i));
+            assert!(value.ends_with('.'));
+            assert!(value.chars().next().unwrap().is_uppercase());
+            let words = words_of(&value);
+            assert!((3..6).contains(&words.len()), "{}", value);
+            assert!(words.iter().all(|word| dictionary.contains(word)));
+        }
+    }
+
+    #[test]
+    fn given_sentences_should_return_paragraphs_of_these_sentences() {
+        let provider = generate_provider("name: a\nlocale: fr_FR\nsentences: 4", Kind::Paragraph);
+        for i in 0..50 {
+            let value = string_value(provider.value(i));
+            assert_eq!(value.matches('.').count(), 4, "{}", value);
+        }
+    }
+
+    #[test]
+    fn given_words_should_return_lorem_of_these_words() {
+        let provider = generate_provider("name: a\nwords: 30", Kind::Lorem);
+        for i in 0..50 {
+            let value = string_value(provider.value(i));
+            let words = words_of(&value);
+            assert_eq!(words.len(), 30);
+            assert!(words.iter().all(|word| words::lorem().contains(word)));
+        }
+    }
+
+    #[test]
+    fn given_length_should_return_texts_of_this_length() {
+        for (params, kind) in [
+            ("length: 50..80", Kind::Sentence),
+            ("length: 200..300\nlocale: ja_JP", Kind::Paragraph),
+            ("length: 1000", Kind::Lorem),
+        ] {
+            let provider = generate_provider(&format!("name: a\n{}", params), kind);
+            let (min, max) = provider.length.unwrap();
+            for i in 0..20 {
+                let value = string_value(provider.value(i));
+                let length = value.chars().count() as u32;
+                assert!(
+                    (min..max).contains(&length),
+                    "{} has {} characters",
+                    value,
+                    length
+                );
+                assert!(!value.ends_with(' '));
+            }
+        }
+    }
+
+    #[test]
+    fn given_japanese_locale_should_return_sentences_without_spaces() {
+        let provider = generate_provider("name: a\nlocale: ja_JP", Kind::Sentence);
+        let value = string_value(provider.value(0));
+        assert!(!value.contains(' '));
+        assert!(value.ends_with('。'));
+    }
+
+    #[test]
+    fn given_corrupted_should_return_random_characters() {
+        let provider = generate_provider("name: a\nlength: 40", Kind::Sentence);
+        for i in 0..20 {
+            let value = string_value(provider.corrupted_value(i));
+            assert_eq!(value.chars().count(), 40);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use crate::providers::parameters::locale::{Locale, AVAILABLE_LOCALES};
+use crate::providers::utils::string::lines;
+
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+
+/// Common words of the language of a locale
+fn file(locale: Locale) -> &'static str {
+    match locale {
+        Locale::DeDE => include_str!("../../../static/words_de.txt"),
+        Locale::EnGB | Locale::EnUS => include_str!("../../../static/words_en.txt"),
+        Locale::EsES => include_str!("../../../static/words_es.txt"),
+        Locale::FrFR => include_str!("../../../static/words_fr.txt"),
+        Locale::ItIT => include_str!("../../../static/words_it.txt"),
+        Locale::JaJP => include_str!("../../../static/words_ja.txt"),
+        Locale::PtBR => include_str!("../../../static/words_pt.txt"),
+        Locale::ZhCN => include_str!("../../../static/words_zh.txt"),
+    }
+}
+
+static WORDS: Lazy<HashMap<Locale, Vec<String>>> = Lazy::new(|| {
+    AVAILABLE_LOCALES
+        .iter()
+        .filter_map(|code| Locale::from_code(code))
+        .map(|locale| (locale, lines(file(locale))))
+        .collect()
+});
+
+static LOREM: Lazy<Vec<String>> = Lazy::new(|| lines(include_str!("../../../static/lorem.txt")));
+
+pub fn words(locale: Locale) -> &'static [String] {
+    &WORDS[&locale]
+}
+
+pub fn lorem() -> &'static [String] {
+    &LOREM
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lorem, words};
+    use crate::providers::parameters::locale::{Locale, AVAILABLE_LOCALES};
+
+    #[test]
+    fn given_locale_should_have_words() {
+        for code in AVAILABLE_LOCALES {
+            let locale = Locale::from_code(code).unwrap();
+            assert!(words(locale).len() > 100, "{} has too few words", code);
+            assert!(words(locale).iter().all(|word| !word.is_empty()));
+        }
+        assert_eq!(&lorem()[..2], ["lorem", "ipsum"]);
+    }
+}
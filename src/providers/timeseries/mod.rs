@@ -0,0 +1,3 @@
+pub mod builder;
+
+pub mod value;
@@ -0,0 +1,457 @@
+use crate::errors::FakeLakeError;
+use crate::providers::parameters::f64::F64Parameter;
+use crate::providers::parameters::get_column_name;
+use crate::providers::provider::{ColumnType, Provider, Value};
+use crate::providers::utils::normal;
+use crate::row;
+
+use std::collections::HashMap;
+use std::f64::consts::TAU;
+use std::sync::Mutex;
+use yaml_rust::Yaml;
+
+const DEFAULT_BASE: f64 = 0.0;
+const DEFAULT_TREND: f64 = 0.0;
+const DEFAULT_AMPLITUDE: f64 = 1.0;
+const DEFAULT_PERIOD: f64 = 24.0;
+const DEFAULT_NOISE: f64 = 0.1;
+const DEFAULT_PHI: f64 = 0.8;
+
+/// How the noise of a step depends on the noise of the previous one
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Mode {
+    /// Independent noise at each step
+    WhiteNoise,
+    /// Noise added to the one of the previous step
+    RandomWalk,
+    /// Noise pulled back towards 0 by phi at each step
+    Ar1 { phi: f64 },
+}
+
+impl Mode {
+    /// Noise after some steps, knowing the noise before them
+    fn advance(&self, previous: f64, steps: u32, noise: f64) -> f64 {
+        let steps = steps as f64;
+        match self {
+            Mode::WhiteNoise => noise * normal::sample(),
+            // the sum of independent normal draws is a normal draw
+            Mode::RandomWalk => previous + noise * steps.sqrt() * normal::sample(),
+            Mode::Ar1 { phi } => {
+                let decay = phi.powf(steps);
+                let deviation = noise * ((1.0 - decay * decay) / (1.0 - phi * phi)).sqrt();
+                decay * previous + deviation * normal::sample()
+            }
+        }
+    }
+}
+
+/// Seconds of the unit of time of a companion column
+fn unit_seconds(unit: &str) -> Option<f64> {
+    match unit {
+        "second" => Some(1.0),
+        "minute" => Some(60.0),
+        "hour" => Some(3600.0),
+        "day" => Some(86400.0),
+        _ => None,
+    }
+}
+
+/// Series read from a companion column, time being counted in units since the Unix epoch
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimeColumn {
+    pub name: String,
+    pub unit_seconds: f64,
+}
+
+impl TimeColumn {
+    fn time(&self) -> Option<f64> {
+        let seconds = match row::get(&self.name)? {
+            Value::Timestamp(value, ..) => value.timestamp_micros() as f64 / 1e6,
+            Value::Date(value, _) => value.and_hms_opt(0, 0, 0)?.and_utc().timestamp() as f64,
+            _ => return None,
+        };
+        Some(seconds / self.unit_seconds)
+    }
+}
+
+/// Where the series is after the steps already generated
+#[derive(Clone, Debug)]
+struct State {
+    steps: u32,
+    noise: f64,
+    /// Time of the first row, from which the trend is counted
+    origin: Option<f64>,
+}
+
+impl State {
+    fn new() -> State {
+        State {
+            steps: 0,
+            noise: 0.0,
+            origin: None,
+        }
+    }
+}
+
+pub struct TimeseriesProvider {
+    pub base: f64,
+    /// Change of the value per step
+    pub trend: f64,
+    pub amplitude: f64,
+    /// Steps of a cycle of the seasonality
+    pub period: f64,
+    /// Standard deviation of the noise of a step
+    pub noise: f64,
+    pub mode: Mode,
+    pub time_column: Option<TimeColumn>,
+    state: Mutex<State>,
+}
+
+impl Clone for TimeseriesProvider {
+    fn clone(&self) -> Self {
+        TimeseriesProvider {
+            base: self.base,
+            trend: self.trend,
+            amplitude: self.amplitude,
+            period: self.period,
+            noise: self.noise,
+            mode: self.mode,
+            time_column: self.time_column.clone(),
+            state: Mutex::new(State::new()),
+        }
+    }
+}
+
+impl TimeseriesProvider {
+    fn series_value(&self, index: u32) -> f64 {
+        let mut state = self.state.lock().unwrap();
+        // rows are generated in order, going back means a new file
+        if index < state.steps {
+            *state = State::new();
+        }
+        state.noise = self
+            .mode
+            .advance(state.noise, index + 1 - state.steps, self.noise);
+        state.steps = index + 1;
+
+        let time = match self.time_column.as_ref().and_then(|column| column.time()) {
+            Some(time) => time,
+            None => index as f64,
+        };
+        let origin = *state.origin.get_or_insert(time);
+
+        self.base
+            + self.trend * (time - origin)
+            + self.amplitude * (TAU * time / self.period).sin()
+            + state.noise
+    }
+}
+
+impl Provider for TimeseriesProvider {
+    fn value(&self, index: u32) -> Value {
+        Value::Float64(self.series_value(index))
+    }
+    fn corrupted_value(&self, index: u32) -> Value {
+        // a spike far from the series, which goes on from the value it should have had
+        let scale = self.amplitude.abs().max(self.noise).max(1.0);
+        let spike = scale * crate::rng::f64_range(5.0..10.0);
+        Value::Float64(match crate::rng::bool() {
+            true => self.series_value(index) + spike,
+            false => self.series_value(index) - spike,
+        })
+    }
+    fn dependencies(&self) -> Vec<String> {
+        match &self.time_column {
+            Some(column) => vec![column.name.clone()],
+            None => Vec::new(),
+        }
+    }
+    fn bind(&mut self, columns: &HashMap<String, ColumnType>) -> Result<(), FakeLakeError> {
+        match &self.time_column {
+            Some(column) => match columns.get(&column.name).map(|column| &column.sample) {
+                Some(Value::Timestamp(..)) | Some(Value::Date(..)) => Ok(()),
+                _ => Err(FakeLakeError::BadYAMLFormat(format!(
+                    "time_column {} should be a date or a timestamp column.",
+                    column.name
+                ))),
+            },
+            None => Ok(()),
+        }
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml) -> Result<Box<TimeseriesProvider>, FakeLakeError> {
+    let column_name = get_column_name(column);
+    let wrong_param = |message: &str| {
+        FakeLakeError::BadYAMLFormat(format!("Column {} param {}", column_name, message))
+    };
+
+    let period = F64Parameter::from_yaml(column, "period", DEFAULT_PERIOD)?.value;
+    if period <= 0.0 {
+        return Err(wrong_param("period should be a positive number."));
+    }
+    let noise = F64Parameter::from_yaml(column, "noise", DEFAULT_NOISE)?.value;
+    if noise < 0.0 {
+        return Err(wrong_param("noise should be a positive number or 0."));
+    }
+
+    let mode = match column["mode"].as_str() {
+        None if column["mode"].is_badvalue() => Mode::WhiteNoise,
+        Some("white_noise") => Mode::WhiteNoise,
+        Some("random_walk") => Mode::RandomWalk,
+        Some("ar1") => {
+            let phi = F64Parameter::from_yaml(column, "phi", DEFAULT_PHI)?.value;
+            if phi.abs() >= 1.0 {
+                return Err(wrong_param(
+                    "phi should be between -1 and 1, both excluded.",
+                ));
+            }
+            Mode::Ar1 { phi }
+        }
+        _ => {
+            return Err(wrong_param(
+                "mode should be white_noise, random_walk or ar1.",
+            ))
+        }
+    };
+
+    let time_column = match &column["time_column"] {
+        Yaml::BadValue => None,
+        Yaml::String(name) => {
+            let unit = column["time_unit"].as_str().unwrap_or("hour");
+            match unit_seconds(unit) {
+                Some(unit_seconds) => Some(TimeColumn {
+                    name: name.clone(),
+                    unit_seconds,
+                }),
+                None => {
+                    return Err(wrong_param(
+                        "time_unit should be second, minute, hour or day.",
+                    ))
+                }
+            }
+        }
+        _ => return Err(wrong_param("time_column should be the name of a column.")),
+    };
+
+    Ok(Box::new(TimeseriesProvider {
+        base: F64Parameter::from_yaml(column, "base", DEFAULT_BASE)?.value,
+        trend: F64Parameter::from_yaml(column, "trend", DEFAULT_TREND)?.value,
+        amplitude: F64Parameter::from_yaml(column, "amplitude", DEFAULT_AMPLITUDE)?.value,
+        period,
+        noise,
+        mode,
+        time_column,
+        state: Mutex::new(State::new()),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_from_yaml, Mode, TimeColumn, TimeseriesProvider};
+    use crate::providers::parameters::timestamp::TimestampType;
+    use crate::providers::provider::{ColumnType, Provider, Value};
+    use crate::row;
+
+    use chrono::{DateTime, NaiveDate};
+    use std::collections::HashMap;
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider(yaml_str: &str) -> Box<TimeseriesProvider> {
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        new_from_yaml(&yaml[0]).unwrap()
+    }
+
+    fn float_value(value: Value) -> f64 {
+        match value {
+            Value::Float64(value) => value,
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    fn values(provider: &TimeseriesProvider, rows: u32) -> Vec<f64> {
+        (0..rows).map(|i| float_value(provider.value(i))).collect()
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_no_params_should_use_defaults() {
+        let provider = generate_provider("name: a");
+        assert_eq!(
+            (
+                provider.base,
+                provider.trend,
+                provider.amplitude,
+                provider.period,
+                provider.noise
+            ),
+            (0.0, 0.0, 1.0, 24.0, 0.1)
+        );
+        assert_eq!(provider.mode, Mode::WhiteNoise);
+        assert_eq!(provider.time_column, None);
+    }
+
+    #[test]
+    fn given_params_should_use_them() {
+        let provider = generate_provider(
+            "name: a\nbase: 20\ntrend: 0.5\namplitude: 3.5\nperiod: 7\nnoise: 0\nmode: ar1\nphi: 0.5\ntime_column: ts\ntime_unit: day",
+        );
+        assert_eq!(
+            (
+                provider.base,
+                provider.trend,
+                provider.amplitude,
+                provider.period,
+                provider.noise
+            ),
+            (20.0, 0.5, 3.5, 7.0, 0.0)
+        );
+        assert_eq!(provider.mode, Mode::Ar1 { phi: 0.5 });
+        assert_eq!(
+            provider.time_column,
+            Some(TimeColumn {
+                name: "ts".to_string(),
+                unit_seconds: 86400.0
+            })
+        );
+        assert_eq!(
+            generate_provider("name: a\nmode: random_walk").mode,
+            Mode::RandomWalk
+        );
+    }
+
+    #[test]
+    fn given_wrong_params_should_return_err() {
+        for params in [
+            "base: a",
+            "period: 0",
+            "noise: -1",
+            "mode: linear",
+            "mode: ar1\nphi: 1",
+            "time_column: [a]",
+            "time_column: ts\ntime_unit: week",
+        ] {
+            let yaml = YamlLoader::load_from_str(&format!("name: a\n{}", params)).unwrap();
+            assert!(
+                new_from_yaml(&yaml[0]).is_err(),
+                "{} should be wrong",
+                params
+            );
+        }
+    }
+
+    #[test]
+    fn given_time_column_should_bind_to_dates_and_timestamps() {
+        let mut provider = generate_provider("name: a\ntime_column: ts");
+        assert_eq!(provider.dependencies(), vec!["ts".to_string()]);
+
+        let mut columns = HashMap::new();
+        columns.insert(
+            "ts".to_string(),
+            ColumnType {
+                sample: Value::Int32(1),
+                nullable: false,
+            },
+        );
+        assert!(provider.bind(&columns).is_err());
+
+        columns.insert(
+            "ts".to_string(),
+            ColumnType {
+                sample: Value::Date(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(), String::new()),
+                nullable: false,
+            },
+        );
+        assert!(provider.bind(&columns).is_ok());
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_no_noise_should_return_trend_and_seasonality() {
+        let provider =
+            generate_provider("name: a\nbase: 10\ntrend: 2\namplitude: 5\nperiod: 4\nnoise: 0");
+        let values = values(&provider, 8);
+        for (i, expected) in [10.0, 17.0, 14.0, 11.0, 18.0, 25.0, 22.0, 19.0]
+            .iter()
+            .enumerate()
+        {
+            assert!((values[i] - expected).abs() < 1e-9, "{:?}", values);
+        }
+    }
+
+    #[test]
+    fn given_white_noise_should_stay_around_signal() {
+        let provider = generate_provider("name: a\nbase: 100\namplitude: 0\nnoise: 1");
+        let values = values(&provider, 1000);
+        let mean = values.iter().sum::<f64>() / 1000.0;
+        assert!((mean - 100.0).abs() < 0.2, "{}", mean);
+        assert!(values.iter().all(|value| (value - 100.0).abs() < 6.0));
+    }
+
+    #[test]
+    fn given_random_walk_should_change_little_between_rows() {
+        let provider = generate_provider("name: a\namplitude: 0\nnoise: 0.5\nmode: random_walk");
+        let values = values(&provider, 2000);
+        for pair in values.windows(2) {
+            assert!((pair[1] - pair[0]).abs() < 3.0);
+        }
+        // the walk drifts away much more than its steps
+        let spread = values.iter().cloned().fold(f64::MIN, f64::max)
+            - values.iter().cloned().fold(f64::MAX, f64::min);
+        assert!(spread > 3.0, "{}", spread);
+    }
+
+    #[test]
+    fn given_ar1_should_correlate_successive_rows() {
+        let provider = generate_provider("name: a\namplitude: 0\nnoise: 1\nmode: ar1\nphi: 0.9");
+        let values = values(&provider, 5000);
+        let mean = values.iter().sum::<f64>() / 5000.0;
+        let variance: f64 = values.iter().map(|v| (v - mean).powi(2)).sum();
+        let covariance: f64 = values
+            .windows(2)
+            .map(|pair| (pair[0] - mean) * (pair[1] - mean))
+            .sum();
+        let correlation = covariance / variance;
+        assert!((correlation - 0.9).abs() < 0.05, "{}", correlation);
+    }
+
+    #[test]
+    fn given_new_file_should_restart_series() {
+        let provider = generate_provider("name: a\ntrend: 1\namplitude: 0\nnoise: 0");
+        assert_eq!(values(&provider, 5), vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(values(&provider, 3), vec![0.0, 1.0, 2.0]);
+        // missing rows are skipped
+        assert_eq!(float_value(provider.value(10)), 10.0);
+    }
+
+    #[test]
+    fn given_time_column_should_follow_its_time() {
+        let provider = generate_provider(
+            "name: a\ntrend: 1\namplitude: 10\nperiod: 24\nnoise: 0\ntime_column: ts_timeseries",
+        );
+        let start = DateTime::from_timestamp(1_700_000_000 - 1_700_000_000 % 86400, 0).unwrap();
+        for (i, hours) in [0, 6, 12, 30].iter().enumerate() {
+            let timestamp = start + chrono::Duration::hours(*hours);
+            row::set(
+                "ts_timeseries",
+                Some(Value::Timestamp(
+                    timestamp,
+                    String::new(),
+                    TimestampType::default(),
+                )),
+            );
+            let value = float_value(provider.value(i as u32));
+            let expected =
+                *hours as f64 + 10.0 * (std::f64::consts::TAU * (*hours % 24) as f64 / 24.0).sin();
+            assert!((value - expected).abs() < 1e-6, "{} {}", value, expected);
+        }
+    }
+
+    #[test]
+    fn given_corrupted_should_return_spikes() {
+        let provider = generate_provider("name: a\nbase: 50\namplitude: 0\nnoise: 0");
+        for i in 0..50 {
+            assert!((float_value(provider.corrupted_value(i)) - 50.0).abs() >= 5.0);
+        }
+    }
+}
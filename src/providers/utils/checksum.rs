@@ -0,0 +1,149 @@
+use crate::rng;
+
+/// Digit appended to the digits so that the number passes the Luhn check
+pub fn luhn_digit(digits: &str) -> u32 {
+    let sum: u32 = digits
+        .chars()
+        .rev()
+        .filter_map(|c| c.to_digit(10))
+        .enumerate()
+        .map(|(i, digit)| match i % 2 {
+            // the rightmost digit is doubled, the check digit coming after it
+            0 => match digit * 2 {
+                doubled if doubled > 9 => doubled - 9,
+                doubled => doubled,
+            },
+            _ => digit,
+        })
+        .sum();
+    (10 - sum % 10) % 10
+}
+
+/// Whether the last digit of the number is its Luhn check digit
+#[cfg(test)]
+pub fn is_luhn_valid(number: &str) -> bool {
+    let digits: String = number.chars().filter(char::is_ascii_digit).collect();
+    match digits.chars().last().and_then(|c| c.to_digit(10)) {
+        Some(check) => luhn_digit(&digits[..digits.len() - 1]) == check,
+        None => false,
+    }
+}
+
+/// Remainder of the division by 97 of the number, letters being read as 10 for A to 35 for Z
+pub fn mod97(value: &str) -> u32 {
+    value.chars().fold(0, |remainder, c| match c.to_digit(36) {
+        Some(number) if number > 9 => (remainder * 100 + number) % 97,
+        Some(number) => (remainder * 10 + number) % 97,
+        None => remainder,
+    })
+}
+
+/// Check digit of a GTIN like an EAN-13 or a UPC, the rightmost digit having a weight of 3
+pub fn gtin_digit(digits: &str) -> u32 {
+    let sum: u32 = digits
+        .chars()
+        .rev()
+        .filter_map(|c| c.to_digit(10))
+        .enumerate()
+        .map(|(i, digit)| match i % 2 {
+            0 => digit * 3,
+            _ => digit,
+        })
+        .sum();
+    (10 - sum % 10) % 10
+}
+
+/// Check digit of the ISO 7064 MOD 11,10 system
+pub fn mod11_10_digit(digits: &str) -> u32 {
+    let product = digits
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .fold(10, |product, digit| {
+            let sum = match (digit + product) % 10 {
+                0 => 10,
+                sum => sum,
+            };
+            (sum * 2) % 11
+        });
+    (11 - product) % 10
+}
+
+/// Digit different from the digit
+pub fn other_digit(digit: u32) -> u32 {
+    (digit + rng::u32(1..10)) % 10
+}
+
+/// Random string of a mask, where # is a digit, ? an uppercase letter and * either of them
+pub fn from_mask(mask: &str) -> String {
+    mask.chars()
+        .map(|c| match c {
+            '#' => char::from(b'0' + rng::u32(0..10) as u8),
+            '?' => char::from(b'A' + rng::u32(0..26) as u8),
+            '*' => match rng::u32(0..36) {
+                n if n < 10 => char::from(b'0' + n as u8),
+                n => char::from(b'A' + (n - 10) as u8),
+            },
+            c => c,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        from_mask, gtin_digit, is_luhn_valid, luhn_digit, mod11_10_digit, mod97, other_digit,
+    };
+
+    use regex::Regex;
+
+    #[test]
+    fn given_digits_should_return_luhn_digit() {
+        assert_eq!(luhn_digit("7992739871"), 3);
+        assert_eq!(luhn_digit("453201511283036"), 6);
+        assert!(is_luhn_valid("4532015112830366"));
+        assert!(is_luhn_valid("4532 0151 1283 0366"));
+        assert!(!is_luhn_valid("4532015112830367"));
+        assert!(!is_luhn_valid(""));
+    }
+
+    #[test]
+    fn given_value_should_return_mod97() {
+        // IBAN of the ISO 13616 example, moved and converted, has a remainder of 1
+        assert_eq!(mod97("WEST12345698765432GB82"), 1);
+        assert_eq!(mod97("3214282912345698765432161182"), 1);
+        assert_eq!(mod97("97"), 0);
+    }
+
+    #[test]
+    fn given_digits_should_return_gtin_digit() {
+        assert_eq!(gtin_digit("400638133393"), 1);
+        assert_eq!(gtin_digit("978030640615"), 7);
+        assert_eq!(gtin_digit("03600029145"), 2);
+    }
+
+    #[test]
+    fn given_digits_should_return_mod11_10_digit() {
+        assert_eq!(mod11_10_digit("8609574271"), 9);
+        assert_eq!(mod11_10_digit("6532087149"), 5);
+    }
+
+    #[test]
+    fn given_digit_should_return_other_digit() {
+        for digit in 0..10 {
+            for _ in 0..20 {
+                let other = other_digit(digit);
+                assert_ne!(other, digit);
+                assert!(other < 10);
+            }
+        }
+    }
+
+    #[test]
+    fn given_mask_should_return_matching_string() {
+        let re = Regex::new(r"^[0-9]{2}-[A-Z][0-9A-Z]$").unwrap();
+        for _ in 0..50 {
+            let value = from_mask("##-?*");
+            assert!(re.is_match(&value), "{}", value);
+        }
+    }
+}
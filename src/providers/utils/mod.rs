@@ -1 +1,3 @@
+pub mod checksum;
+pub mod normal;
 pub mod string;
@@ -23,9 +23,46 @@ pub fn random_characters(n: u32) -> String {
     .collect()
 }
 
+/// Lines of a text, like the static files of values
+pub fn lines(content: &str) -> Vec<String> {
+    content.lines().map(|line| line.to_string()).collect()
+}
+
+/// Random string among the values
+pub fn pick<T: AsRef<str>>(values: &[T]) -> &str {
+    values[crate::rng::usize(..values.len())].as_ref()
+}
+
+/// Lowercase ascii letters and digits of a value, accented letters losing their accent
+pub fn ascii_identifier(value: &str) -> String {
+    let mut identifier = String::new();
+    for c in value.to_lowercase().chars() {
+        let folded = match c {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => "a",
+            'æ' => "ae",
+            'ç' => "c",
+            'è' | 'é' | 'ê' | 'ë' => "e",
+            'ì' | 'í' | 'î' | 'ï' => "i",
+            'ñ' => "n",
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' => "o",
+            'œ' => "oe",
+            'ß' => "ss",
+            'ù' | 'ú' | 'û' | 'ü' => "u",
+            'ý' | 'ÿ' => "y",
+            c if c.is_ascii_alphanumeric() => {
+                identifier.push(c);
+                continue;
+            }
+            _ => continue,
+        };
+        identifier.push_str(folded);
+    }
+    identifier
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{random_alphanumeric, random_characters};
+    use super::{ascii_identifier, lines, pick, random_alphanumeric, random_characters};
     use regex::Regex;
     use std::convert::TryInto;
 
@@ -66,4 +103,30 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn given_accented_names_should_return_ascii_identifier() {
+        assert_eq!(ascii_identifier("Müller"), "muller");
+        assert_eq!(ascii_identifier("JEAN-PIERRE"), "jeanpierre");
+        assert_eq!(ascii_identifier("D'Angelo"), "dangelo");
+        assert_eq!(ascii_identifier("Groß Çağ"), "grossca");
+        assert_eq!(ascii_identifier("佐藤"), "");
+    }
+
+    #[test]
+    fn given_values_should_pick_one_of_them() {
+        let values = ["a", "b", "c"];
+        for _ in 0..100 {
+            assert!(values.contains(&pick(&values)));
+        }
+
+        let values = vec!["a".to_string()];
+        assert_eq!(pick(&values), "a");
+    }
+
+    #[test]
+    fn given_text_should_return_its_lines() {
+        assert_eq!(lines("a\nb c\r\n\nd"), vec!["a", "b c", "", "d"]);
+        assert!(lines("").is_empty());
+    }
 }
@@ -11,6 +11,9 @@ pub const DOMAIN_FILE: u64 = 0;
 /// Domain tag used when deriving sub-seeds for provider draws.
 pub const DOMAIN_PROVIDER: u64 = 1;
 
+/// Domain tag used when deriving sub-seeds for referenced column cardinalities.
+pub const DOMAIN_REFERENCE: u64 = 2;
+
 /// Initialize the global RNG with an optional seed.
 /// Kept for backwards compatibility with existing tests; production code should
 /// use [`scoped_seeded`] instead so the thread-local is scoped to a known task.
@@ -64,6 +67,18 @@ pub fn u32(range: std::ops::Range<u32>) -> u32 {
     with_rng(|rng| rng.u32(range))
 }
 
+/// Generate a random u128
+pub fn u128() -> u128 {
+    with_rng(|rng| rng.u128(..))
+}
+
+/// Generate random bytes of the given length
+pub fn bytes(length: usize) -> Vec<u8> {
+    let mut bytes = vec![0; length];
+    with_rng(|rng| rng.fill(&mut bytes));
+    bytes
+}
+
 /// Generate a random usize in the given range
 pub fn usize(range: std::ops::RangeTo<usize>) -> usize {
     with_rng(|rng| rng.usize(range))
@@ -139,6 +154,16 @@ pub fn scoped_seeded(seed: u64) -> RngScope {
     RngScope { _private: () }
 }
 
+/// Run `f` with its own RNG seeded with 0, and give back to the current thread
+/// the RNG it had before, if any. Used for draws that are not part of the generated
+/// data, like the sample values used to infer column types.
+pub fn isolated<T>(f: impl FnOnce() -> T) -> T {
+    let previous = RNG.with(|rng| rng.borrow_mut().replace(Rng::with_seed(0)));
+    let result = f();
+    RNG.with(|rng| *rng.borrow_mut() = previous);
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -256,4 +281,23 @@ mod tests {
             "unexpected panic message: {msg}"
         );
     }
+
+    #[test]
+    fn test_isolated_does_not_change_thread_rng() {
+        let _scope = scoped_seeded(7);
+        let expected = {
+            let mut rng = Rng::with_seed(7);
+            rng.u32(0..1000)
+        };
+
+        let isolated_value = isolated(|| u32(0..1000));
+        assert_eq!(isolated_value, isolated(|| u32(0..1000)));
+        assert_eq!(u32(0..1000), expected);
+    }
+
+    #[test]
+    fn test_isolated_without_rng_leaves_none() {
+        isolated(|| u32(0..1000));
+        let _scope = scoped_seeded(7);
+    }
 }
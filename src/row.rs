@@ -0,0 +1,67 @@
+use crate::providers::provider::Value;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+thread_local! {
+    static ROW: RefCell<HashMap<String, Value>> = RefCell::new(HashMap::new());
+}
+
+/// Keep the value of a column for the row being generated, so that the next columns
+/// of the row can read it. None means the column is null in this row.
+pub fn set(column: &str, value: Option<Value>) {
+    ROW.with(|row| {
+        let mut row = row.borrow_mut();
+        match value {
+            Some(value) => match row.get_mut(column) {
+                Some(current) => *current = value,
+                None => {
+                    row.insert(column.to_string(), value);
+                }
+            },
+            None => {
+                row.remove(column);
+            }
+        }
+    });
+}
+
+/// Value of a column in the row being generated. None if the column is null in this row
+/// or has not been set.
+pub fn get(column: &str) -> Option<Value> {
+    ROW.with(|row| row.borrow().get(column).cloned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{get, set};
+    use crate::providers::provider::Value;
+
+    #[test]
+    fn given_set_value_should_get_it() {
+        set("row_set", Some(Value::Int32(1)));
+        assert_eq!(get("row_set"), Some(Value::Int32(1)));
+
+        set("row_set", Some(Value::Int32(2)));
+        assert_eq!(get("row_set"), Some(Value::Int32(2)));
+    }
+
+    #[test]
+    fn given_null_value_should_get_none() {
+        set("row_null", Some(Value::Int32(1)));
+        set("row_null", None);
+        assert_eq!(get("row_null"), None);
+    }
+
+    #[test]
+    fn given_unknown_column_should_get_none() {
+        assert_eq!(get("row_unknown"), None);
+    }
+
+    #[test]
+    fn given_other_thread_should_not_share_row() {
+        set("row_thread", Some(Value::Bool(true)));
+        let value = std::thread::spawn(|| get("row_thread")).join().unwrap();
+        assert_eq!(value, None);
+    }
+}
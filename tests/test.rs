@@ -47,6 +47,10 @@ mod tests {
             fs::remove_file(format!("target/multifile_naming_{i}.csv")).ok();
             fs::remove_file(format!("target/multifile_naming_{i}.json")).ok();
         }
+        fs::remove_file("target/reference_customers.yaml").ok();
+        fs::remove_file("target/reference_customers.csv").ok();
+        fs::remove_file("target/reference_orders.yaml").ok();
+        fs::remove_file("target/reference_orders.csv").ok();
     }
 
     #[test]
@@ -510,6 +514,368 @@ info:
         Ok(())
     }
 
+    #[test]
+    fn given_referenced_table_should_only_generate_existing_keys(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let customers_path = Path::new("target/reference_customers.yaml");
+        let orders_path = Path::new("target/reference_orders.yaml");
+
+        let customers_config = r#"
+columns:
+  - name: id
+    provider: Increment.integer
+    start: 1000
+
+info:
+  output_name: target/reference_customers
+  output_format: csv
+  rows: 10
+  seed: 42
+"#;
+        let orders_config = r#"
+columns:
+  - name: order_id
+    provider: Increment.integer
+  - name: customer_id
+    provider: Reference.column
+    table: reference_customers
+    column: id
+    cardinality: 1..4
+
+info:
+  output_name: target/reference_orders
+  output_format: csv
+  rows: 30
+  seed: 42
+"#;
+        fs::write(customers_path, customers_config)?;
+        fs::write(orders_path, orders_config)?;
+
+        Command::cargo_bin("fakelake")?
+            .arg("generate")
+            .arg(customers_path)
+            .arg(orders_path)
+            .assert()
+            .success();
+
+        let customers = fs::read_to_string("target/reference_customers.csv")?;
+        let customer_ids: Vec<&str> = customers.lines().skip(1).collect();
+        let orders = fs::read_to_string("target/reference_orders.csv")?;
+        for line in orders.lines().skip(1) {
+            let customer_id = line.split(',').nth(1).unwrap();
+            assert!(
+                customer_ids.contains(&customer_id),
+                "Unknown customer {}",
+                customer_id
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_tables_in_one_file_should_generate_referenced_tables_first(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Command::cargo_bin("fakelake")?
+            .arg("generate")
+            .arg(Path::new("tests/star_schema.yaml"))
+            .assert()
+            .success();
+
+        let customers = fs::read_to_string("target/test_generated/star_schema_customers.csv")?;
+        let products = fs::read_to_string("target/test_generated/star_schema_products.csv")?;
+        let sales = fs::read_to_string("target/test_generated/star_schema_sales.csv")?;
+
+        let customer_ids: Vec<&str> = customers.lines().skip(1).collect();
+        let product_ids: Vec<&str> = products.lines().skip(1).collect();
+        assert_eq!(sales.lines().count(), 101);
+        for line in sales.lines().skip(1) {
+            let values: Vec<&str> = line.split(',').collect();
+            assert!(customer_ids.contains(&values[1]));
+            assert!(product_ids.contains(&values[2]));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_expression_columns_should_compute_them_from_the_row(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Command::cargo_bin("fakelake")?
+            .arg("generate")
+            .arg(Path::new("tests/expression_columns.yaml"))
+            .assert()
+            .success();
+
+        let content = fs::read_to_string("target/test_generated/expression_columns.csv")?;
+        let mut lines = content.lines();
+        assert_eq!(lines.next(), Some("total,price,quantity,first_name,email"));
+        for line in lines {
+            let values: Vec<&str> = line.split(',').collect();
+            let total: i32 = values[0].parse()?;
+            let price: i32 = values[1].parse()?;
+            let quantity: i32 = values[2].parse()?;
+            assert_eq!(total, price * quantity);
+            assert_eq!(values[4], format!("{}@corp.com", values[3].to_lowercase()));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_conditional_columns_should_follow_the_conditions(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Command::cargo_bin("fakelake")?
+            .arg("generate")
+            .arg(Path::new("tests/conditional_columns.yaml"))
+            .assert()
+            .success();
+
+        let content = fs::read_to_string("target/test_generated/conditional_columns.csv")?;
+        let mut lines = content.lines();
+        assert_eq!(
+            lines.next(),
+            Some("refund_amount,status,phone_prefix,country_code")
+        );
+        for line in lines {
+            let values: Vec<&str> = line.split(',').collect();
+            assert_eq!(values[0].is_empty(), values[1] != "refunded");
+            let expected_prefix = if values[3] == "FR" { "+33" } else { "+1" };
+            assert_eq!(values[2], expected_prefix);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_correlations_should_generate_correlated_columns(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Command::cargo_bin("fakelake")?
+            .arg("generate")
+            .arg(Path::new("tests/correlated_columns.yaml"))
+            .assert()
+            .success();
+
+        let content = fs::read_to_string("target/test_generated/correlated_columns.csv")?;
+        let mut pairs: Vec<(f64, f64)> = Vec::new();
+        for line in content.lines().skip(1) {
+            let values: Vec<&str> = line.split(',').collect();
+            pairs.push((values[0].parse()?, values[1].parse()?));
+        }
+
+        let n = pairs.len() as f64;
+        let mean_x = pairs.iter().map(|p| p.0).sum::<f64>() / n;
+        let mean_y = pairs.iter().map(|p| p.1).sum::<f64>() / n;
+        let covariance: f64 = pairs.iter().map(|p| (p.0 - mean_x) * (p.1 - mean_y)).sum();
+        let variance_x: f64 = pairs.iter().map(|p| (p.0 - mean_x).powi(2)).sum();
+        let variance_y: f64 = pairs.iter().map(|p| (p.1 - mean_y).powi(2)).sum();
+        let correlation = covariance / (variance_x * variance_y).sqrt();
+        assert!(correlation > 0.7, "correlation is {}", correlation);
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_gender_column_should_generate_names_of_this_gender(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Command::cargo_bin("fakelake")?
+            .arg("generate")
+            .arg(Path::new("tests/person_columns.yaml"))
+            .assert()
+            .success();
+
+        let female_names = fs::read_to_string("static/first_name_female_en_US.txt")?;
+        let male_names = fs::read_to_string("static/first_name_male_en_US.txt")?;
+        let content = fs::read_to_string("target/test_generated/person_columns.csv")?;
+        let mut lines = content.lines();
+        assert_eq!(lines.next(), Some("gender,full_name,username,age"));
+        for line in lines {
+            let values: Vec<&str> = line.split(',').collect();
+            let full_name: Vec<&str> = values[1].split(' ').collect();
+            let first_names = match values[0] {
+                "female" => &female_names,
+                "male" => &male_names,
+                other => panic!("Unexpected gender {}", other),
+            };
+            assert!(first_names.lines().any(|name| name == full_name[1]));
+            let age: i32 = values[3].parse()?;
+            assert!((20..30).contains(&age));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_address_columns_should_generate_consistent_addresses(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Command::cargo_bin("fakelake")?
+            .arg("generate")
+            .arg(Path::new("tests/address_columns.yaml"))
+            .assert()
+            .success();
+
+        let places = fs::read_to_string("static/address_en_US.txt")?;
+        let content = fs::read_to_string("target/test_generated/address_columns.csv")?;
+        let mut lines = content.lines();
+        assert_eq!(lines.next(), Some("city,state,postcode,country_code"));
+        for line in lines {
+            let values: Vec<&str> = line.split(',').collect();
+            let place: Vec<&str> = places
+                .lines()
+                .map(|place| place.split(';').collect::<Vec<&str>>())
+                .find(|place| place[0] == values[0])
+                .unwrap();
+            assert_eq!(place[1], values[1]);
+            assert!(values[2].starts_with(place[2].trim_end_matches('#')));
+            assert_eq!(values[3], "US");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_template_columns_should_fill_templates() -> Result<(), Box<dyn std::error::Error>> {
+        Command::cargo_bin("fakelake")?
+            .arg("generate")
+            .arg(Path::new("tests/template_columns.yaml"))
+            .assert()
+            .success();
+
+        let content = fs::read_to_string("target/test_generated/template_columns.csv")?;
+        let mut lines = content.lines();
+        assert_eq!(lines.next(), Some("invoice_number,login"));
+        for (i, line) in lines.enumerate() {
+            let values: Vec<&str> = line.split(',').collect();
+            assert_eq!(values[0], format!("INV-{:06}", 1000 + i));
+            let (name, domain) = values[1].split_once('@').unwrap();
+            let (first_name, last_name) = name.split_once('.').unwrap();
+            assert_eq!(first_name, first_name.to_lowercase());
+            assert_eq!(last_name, last_name.to_uppercase());
+            assert!(domain.contains('.'));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_geo_columns_should_write_geojson_points() -> Result<(), Box<dyn std::error::Error>> {
+        Command::cargo_bin("fakelake")?
+            .arg("generate")
+            .arg(Path::new("tests/geo_columns.yaml"))
+            .assert()
+            .success();
+
+        let content = fs::read_to_string("target/test_generated/geo_columns.json")?;
+        for line in content.lines() {
+            let row: serde_json::Value = serde_json::from_str(line)?;
+            assert_eq!(row["pickup"]["type"], "Point");
+            let longitude = row["pickup"]["coordinates"][0].as_f64().unwrap();
+            let latitude = row["pickup"]["coordinates"][1].as_f64().unwrap();
+            assert!((45.7..48.9).contains(&latitude), "{}", latitude);
+            assert!((2.2..4.9).contains(&longitude), "{}", longitude);
+            assert!(row["zone"].as_str().unwrap().starts_with("POLYGON ((2."));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_timeseries_columns_should_follow_the_series() -> Result<(), Box<dyn std::error::Error>>
+    {
+        Command::cargo_bin("fakelake")?
+            .arg("generate")
+            .arg(Path::new("tests/timeseries_columns.yaml"))
+            .assert()
+            .success();
+
+        let content = fs::read_to_string("target/test_generated/timeseries_columns.csv")?;
+        let mut lines = content.lines();
+        assert_eq!(lines.next(), Some("step,temperature,measured_at"));
+        let mut previous = String::new();
+        for (i, line) in lines.enumerate() {
+            let values: Vec<&str> = line.split(',').collect();
+            assert_eq!(values[0].parse::<f64>()?, 100.0 + i as f64);
+            let temperature: f64 = values[1].parse()?;
+            assert!((10.0..30.0).contains(&temperature), "{}", temperature);
+            // incremented timestamps keep the rows in order
+            assert!(values[2] > previous.as_str(), "{}", values[2]);
+            previous = values[2].to_string();
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_timestamp_columns_should_write_precision_and_offset(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Command::cargo_bin("fakelake")?
+            .arg("generate")
+            .arg(Path::new("tests/timestamp_columns.yaml"))
+            .assert()
+            .success();
+
+        let produced_at = regex::Regex::new(r"^2024-01-01 \d{2}:\d{2}:\d{2}\.\d{6}\+00:00$")?;
+        let content = fs::read_to_string("target/test_generated/timestamp_columns.json")?;
+        for (i, line) in content.lines().enumerate() {
+            let row: serde_json::Value = serde_json::from_str(line)?;
+            let value = row["produced_at"].as_str().unwrap();
+            assert!(produced_at.is_match(value), "{}", value);
+            assert_eq!(
+                row["local_time"].as_str().unwrap(),
+                format!("2024-01-01 08:{:02}:{:02}.000+02:00", i / 60, i % 60)
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_bytes_columns_should_write_base64_and_matching_hashes(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use base64::engine::general_purpose::STANDARD;
+        use base64::Engine;
+        use sha2::{Digest, Sha256};
+
+        Command::cargo_bin("fakelake")?
+            .arg("generate")
+            .arg(Path::new("tests/bytes_columns.yaml"))
+            .assert()
+            .success();
+
+        let hex = regex::Regex::new(r"^[0-9a-f]{8}$")?;
+        let sha256 = regex::Regex::new(r"^[0-9a-f]{64}$")?;
+        let content = fs::read_to_string("target/test_generated/bytes_columns.json")?;
+        for line in content.lines() {
+            let row: serde_json::Value = serde_json::from_str(line)?;
+            let payload = STANDARD.decode(row["payload"].as_str().unwrap())?;
+            assert!((16..32).contains(&payload.len()));
+            assert!(hex.is_match(row["payload_hex"].as_str().unwrap()));
+            assert_eq!(
+                row["payload_md5"].as_str().unwrap(),
+                format!("{:x}", md5::compute(&payload))
+            );
+            assert_eq!(
+                row["payload_sha256"].as_str().unwrap(),
+                STANDARD.encode(Sha256::digest(&payload))
+            );
+            assert!(sha256.is_match(row["random_sha256"].as_str().unwrap()));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_reference_to_table_not_yet_generated_should_fail(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Command::cargo_bin("fakelake")?
+            .arg("generate")
+            .arg(Path::new("tests/reference_unknown_table.yaml"))
+            .assert()
+            .failure();
+
+        Ok(())
+    }
+
     #[test]
     fn given_no_seed_printed_seed_should_allow_replay() -> Result<(), Box<dyn std::error::Error>> {
         // Run without a seed and capture the stderr output, which should contain